@@ -80,8 +80,8 @@ fn dpc_testnet1_integration_test() {
 
     let previous_ledger_root = ledger.latest_ledger_root();
     let timestamp = OffsetDateTime::now_utc().unix_timestamp();
-    let difficulty_target =
-        Blocks::<Testnet1>::compute_difficulty_target(previous_block.header(), timestamp, block_height);
+    let recent_blocks = [previous_block.header().into()];
+    let difficulty_target = Blocks::<Testnet1>::compute_difficulty_target(&recent_blocks, timestamp, block_height);
     let cumulative_weight = previous_block.cumulative_weight().saturating_add((u64::MAX / difficulty_target) as u128);
 
     // Construct the block template.
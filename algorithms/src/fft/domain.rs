@@ -77,12 +77,13 @@ const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2, or, for mixed-radix domains, of size `2^k * b^m` where `b` is
+/// the field's `SMALL_SUBGROUP_BASE`.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2(self.size)`, rounded up for mixed-radix domains.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -145,6 +146,44 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Construct a mixed-radix domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// The domain has the smallest size of the form `2^k * b^m` that is at least `num_coeffs`,
+    /// where `b` is the field's `SMALL_SUBGROUP_BASE`. If the field does not define a small
+    /// subgroup, this is the same as `EvaluationDomain::new`.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_mixed_radix_domain(num_coeffs)?;
+        if size.is_power_of_two() {
+            return Self::new(size);
+        }
+
+        let group_gen = F::get_root_of_unity(size)?;
+        debug_assert_eq!(group_gen.pow([size as u64]), F::one());
+
+        let size_as_field_element = F::from(size as u64);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(EvaluationDomain {
+            size: size as u64,
+            log_size_of_group: log2(size),
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+
+    /// Construct the smallest domain that contains `subdomain` as a subgroup and is large enough
+    /// for evaluations of a polynomial having `num_coeffs` coefficients.
+    pub fn new_containing(num_coeffs: usize, subdomain: &Self) -> Option<Self> {
+        let odd_factor = subdomain.size() >> subdomain.size().trailing_zeros();
+        let num_coeffs = num_coeffs.max(subdomain.size());
+        let two_part = ((num_coeffs + odd_factor - 1) / odd_factor).next_power_of_two();
+        Self::new_mixed_radix(two_part.checked_mul(odd_factor)?)
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -152,6 +191,38 @@ impl<F: FftField> EvaluationDomain<F> {
         if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY { Some(size) } else { None }
     }
 
+    /// Return the size of a mixed-radix domain that is large enough for evaluations of a
+    /// polynomial having `num_coeffs` coefficients.
+    pub fn compute_size_of_mixed_radix_domain(num_coeffs: usize) -> Option<usize> {
+        let mut best = Self::compute_size_of_domain(num_coeffs);
+        if let (Some(base), Some(adicity)) =
+            (F::FftParameters::SMALL_SUBGROUP_BASE, F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY)
+        {
+            let mut odd_part = 1usize;
+            for _ in 0..adicity {
+                odd_part = match odd_part.checked_mul(base as usize) {
+                    Some(odd_part) => odd_part,
+                    None => break,
+                };
+                let two_part = ((num_coeffs + odd_part - 1) / odd_part).next_power_of_two();
+                if two_part.trailing_zeros() > F::FftParameters::TWO_ADICITY {
+                    continue;
+                }
+                if let Some(size) = two_part.checked_mul(odd_part) {
+                    if best.map_or(true, |best| size < best) {
+                        best = Some(size);
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    /// Returns `true` if the size of `self` is a power of two.
+    pub fn is_radix_2(&self) -> bool {
+        self.size.is_power_of_two()
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -358,18 +429,32 @@ impl<F: FftField> EvaluationDomain<F> {
 impl<F: FftField> EvaluationDomain<F> {
     pub fn precompute_fft(&self) -> FFTPrecomputation<F> {
         execute_with_max_available_threads(|| FFTPrecomputation {
-            roots: self.roots_of_unity(self.group_gen),
+            roots: self.precomputed_roots(self.group_gen),
             domain: *self,
         })
     }
 
     pub fn precompute_ifft(&self) -> IFFTPrecomputation<F> {
         execute_with_max_available_threads(|| IFFTPrecomputation {
-            inverse_roots: self.roots_of_unity(self.group_gen_inv),
+            inverse_roots: self.precomputed_roots(self.group_gen_inv),
             domain: *self,
         })
     }
 
+    /// Returns the powers of `root` needed to (I)FFT over `self`: the first half of the
+    /// domain for radix-2 domains, and the entire domain for mixed-radix domains.
+    fn precomputed_roots(&self, root: F) -> Vec<F> {
+        if self.is_radix_2() {
+            self.roots_of_unity(root)
+        } else {
+            #[cfg(feature = "parallel")]
+            let roots = compute_powers(self.size(), root);
+            #[cfg(not(feature = "parallel"))]
+            let roots = compute_powers_serial(self.size(), root);
+            roots
+        }
+    }
+
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         let pc = self.precompute_fft();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
@@ -443,6 +528,10 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        if !self.is_radix_2() {
+            return self.mixed_radix_helper_with_roots(x_s, &pc.roots);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == OI {
@@ -468,6 +557,10 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        if !self.is_radix_2() {
+            return self.mixed_radix_helper_with_roots(x_s, &pc.inverse_roots);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == II {
@@ -481,6 +574,51 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Computes a DFT over a mixed-radix domain of size `n = n_1 * n_2`, where `n_2` is the
+    /// largest power of two dividing `n`, given all `n` powers of the domain generator.
+    /// Both the input and the output are in-order.
+    ///
+    /// Writing `j = n_2 * j_1 + j_2` and `k = k_1 + n_1 * k_2`, the DFT splits into `n_1`
+    /// naive DFTs of length `n_1` on the strided inputs, each followed by a radix-2 FFT of
+    /// length `n_2` over the generator `w^{n_1}`.
+    fn mixed_radix_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
+        let n = xi.len();
+        assert_eq!(roots.len(), n);
+        let n_2 = 1 << n.trailing_zeros();
+        let n_1 = n / n_2;
+
+        let radix_2_roots = roots.iter().step_by(n_1).take(n_2 / 2).copied().collect::<Vec<_>>();
+        let log_n_2 = log2(n_2);
+
+        let rows = cfg_into_iter!(0..n_1)
+            .map(|k_1| {
+                let mut row = (0..n_2)
+                    .map(|j_2| {
+                        let mut sum = T::zero();
+                        for j_1 in 0..n_1 {
+                            let j = n_2 * j_1 + j_2;
+                            let mut term = xi[j];
+                            term *= roots[(k_1 * j) % n];
+                            sum += term;
+                        }
+                        sum
+                    })
+                    .collect::<Vec<_>>();
+                if n_2 > 1 {
+                    self.io_helper_with_roots(&mut row, &radix_2_roots);
+                    derange_helper(&mut row, log_n_2);
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+
+        for (k_1, row) in rows.into_iter().enumerate() {
+            for (k_2, value) in row.into_iter().enumerate() {
+                xi[k_1 + n_1 * k_2] = value;
+            }
+        }
+    }
+
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
     /// e.g. for the domain [1, g, g^2, ..., g^{n - 1}], it computes
     // [1, g, g^2, ..., g^{(n/2) - 1}]
@@ -797,9 +935,9 @@ impl<F: FftField> FFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let roots = self.roots.iter().step_by(size_ratio).copied().collect();
+            let roots = self.roots.iter().step_by(size_ratio).take(num_roots(domain)).copied().collect();
             Some(Cow::Owned(Self { roots, domain: *domain }))
         } else {
             None
@@ -821,9 +959,10 @@ impl<F: FftField> IFFTPrecomputation<F> {
         }
         if &self.domain == domain {
             Some(Cow::Borrowed(self))
-        } else if domain.size() < self.domain.size() {
+        } else if domain.size() < self.domain.size() && self.domain.size() % domain.size() == 0 {
             let size_ratio = self.domain.size() / domain.size();
-            let inverse_roots = self.inverse_roots.iter().step_by(size_ratio).copied().collect();
+            let inverse_roots =
+                self.inverse_roots.iter().step_by(size_ratio).take(num_roots(domain)).copied().collect();
             Some(Cow::Owned(Self { inverse_roots, domain: *domain }))
         } else {
             None
@@ -831,6 +970,11 @@ impl<F: FftField> IFFTPrecomputation<F> {
     }
 }
 
+/// Returns the number of roots of unity stored in a precomputation for `domain`.
+fn num_roots<F: FftField>(domain: &EvaluationDomain<F>) -> usize {
+    if domain.is_radix_2() { domain.size() / 2 } else { domain.size() }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain};
@@ -993,6 +1137,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mixed_radix_domain_size() {
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1), Some(1));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(3), Some(3));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(5), Some(6));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(8), Some(8));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(9), Some(12));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1000), Some(1024));
+        assert_eq!(EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(1025), Some(1536));
+
+        let domain = EvaluationDomain::<Fr>::new_mixed_radix(1025).unwrap();
+        assert_eq!(domain.size(), 1536);
+        assert!(!domain.is_radix_2());
+        assert_eq!(domain.group_gen.pow([1536]), Fr::one());
+        assert_eq!(domain.elements().count(), 1536);
+        assert_eq!(EvaluationDomain::<Fr>::new_mixed_radix(1000), EvaluationDomain::<Fr>::new(1000));
+    }
+
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let mut rng = thread_rng();
+        for domain_size in [3, 6, 12, 24, 48, 96, 384] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(domain_size).unwrap();
+            assert_eq!(domain.size(), domain_size);
+
+            let random_polynomial = DensePolynomial::<Fr>::rand(domain_size - 1, &mut rng);
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+            }
+
+            let random_polynomial_from_subgroup =
+                DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations));
+            let random_polynomial_from_coset =
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations));
+            assert_eq!(random_polynomial, random_polynomial_from_subgroup, "domain size = {}", domain_size);
+            assert_eq!(random_polynomial, random_polynomial_from_coset, "domain size = {}", domain_size);
+        }
+    }
+
+    /// Tests that mixed-radix precomputations restrict to both mixed-radix and radix-2 subdomains.
+    #[test]
+    fn test_mixed_radix_precomputation() {
+        let big_domain = EvaluationDomain::<Fr>::new_mixed_radix(3 << 6).unwrap();
+        let fft_pc = big_domain.precompute_fft();
+        let ifft_pc = big_domain.precompute_ifft();
+        assert_eq!(fft_pc.to_ifft_precomputation(), ifft_pc);
+
+        for log_size in 0..6 {
+            for small_domain in [
+                EvaluationDomain::<Fr>::new(1 << log_size).unwrap(),
+                EvaluationDomain::<Fr>::new_mixed_radix(3 << log_size).unwrap(),
+            ] {
+                let small_fft_pc = fft_pc.precomputation_for_subdomain(&small_domain).unwrap();
+                assert_eq!(small_fft_pc.as_ref(), &small_domain.precompute_fft());
+                let small_ifft_pc = ifft_pc.precomputation_for_subdomain(&small_domain).unwrap();
+                assert_eq!(small_ifft_pc.as_ref(), &small_domain.precompute_ifft());
+
+                let mut rng = thread_rng();
+                let coeffs = (0..small_domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+                let mut evals = coeffs.clone();
+                small_domain.in_order_fft_in_place_with_pc(&mut evals, &fft_pc);
+                assert_eq!(evals, small_domain.fft(&coeffs));
+                small_domain.in_order_ifft_in_place_with_pc(&mut evals, &ifft_pc);
+                assert_eq!(evals, coeffs);
+            }
+        }
+
+        // A radix-2 domain cannot be restricted to a subdomain whose size does not divide its own.
+        let radix_2_pc = EvaluationDomain::<Fr>::new(1 << 6).unwrap().precompute_fft();
+        assert!(radix_2_pc.precomputation_for_subdomain(&EvaluationDomain::new_mixed_radix(3).unwrap()).is_none());
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
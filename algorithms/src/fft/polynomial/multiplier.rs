@@ -98,7 +98,9 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
                     pool.add_job(move || {
                         let mut e = e.to_owned().into_owned().evaluations;
                         e.resize(domain.size(), F::zero());
-                        crate::fft::domain::derange(&mut e);
+                        if domain.is_radix_2() {
+                            crate::fft::domain::derange(&mut e);
+                        }
                         e
                     })
                 }
@@ -152,7 +154,10 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
             pool.add_job(move || {
                 let mut e = e.to_owned().into_owned().evaluations;
                 e.resize(domain.size(), F::zero());
-                crate::fft::domain::derange(&mut e);
+                // Mixed-radix FFTs are always in-order, so only radix-2 evaluations are permuted.
+                if domain.is_radix_2() {
+                    crate::fft::domain::derange(&mut e);
+                }
                 (l, e)
             })
         }
@@ -53,6 +53,9 @@ pub enum PCError {
     /// The hiding bound was not `None`, but the hiding bound was zero.
     HidingBoundIsZero,
 
+    /// The chunk size passed to a chunked `commit` was zero.
+    ChunkSizeIsZero,
+
     /// The hiding bound was too large for the given `Powers`.
     HidingBoundToolarge {
         /// The hiding bound
@@ -127,6 +130,7 @@ impl core::fmt::Display for PCError {
                 num_coefficients, num_powers
             ),
             PCError::HidingBoundIsZero => write!(f, "this scheme does not support non-`None` hiding bounds that are 0"),
+            PCError::ChunkSizeIsZero => write!(f, "chunked commitments require a non-zero chunk size"),
             PCError::HidingBoundToolarge { hiding_poly_degree, num_powers } => write!(
                 f,
                 "the degree of the hiding poly ({:?}) is not less than the maximum number of powers in `Powers` ({:?})",
@@ -27,7 +27,7 @@ use crate::{
     polycommit::PCError,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_fields::{FftParameters, Field, One, PrimeField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{cfg_iter, rand::Uniform, BitIteratorBE};
 
@@ -39,7 +39,7 @@ use core::{
 use itertools::Itertools;
 use parking_lot::RwLock;
 use rand_core::RngCore;
-use std::{collections::BTreeMap, sync::Arc};
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -66,21 +66,32 @@ impl KZG10DegreeBoundsConfig {
             KZG10DegreeBoundsConfig::ALL => (0..max_degree).collect(),
             KZG10DegreeBoundsConfig::MARLIN => {
                 // In Marlin, the degree bounds are all of the forms `domain_size - 2`.
-                // Consider that we are using radix-2 FFT,
-                // there are only a few possible domain sizes and therefore degree bounds.
-                //
-                // We do not consider mixed-radix FFT for simplicity, as the curves that we
-                // are using have very high two-arity.
-
-                let mut radix_2_possible_domain_sizes = vec![];
-
-                let mut cur = 2usize;
-                while cur - 2 <= max_degree {
-                    radix_2_possible_domain_sizes.push(cur - 2);
-                    cur *= 2;
+                // Domain sizes are either powers of two or, if the field defines a small subgroup
+                // of order `b`, mixed-radix sizes `2^k * b^j`, so there are only a few possible
+                // domain sizes and therefore degree bounds.
+                let mut odd_factors = vec![1usize];
+                if let (Some(base), Some(adicity)) =
+                    (F::FftParameters::SMALL_SUBGROUP_BASE, F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY)
+                {
+                    for _ in 0..adicity {
+                        match odd_factors.last().unwrap().checked_mul(base as usize) {
+                            Some(factor) if factor - 2 <= max_degree => odd_factors.push(factor),
+                            _ => break,
+                        }
+                    }
                 }
 
-                radix_2_possible_domain_sizes
+                let mut possible_domain_sizes = vec![];
+                for odd_factor in odd_factors {
+                    let mut cur = if odd_factor == 1 { 2 } else { odd_factor };
+                    while cur - 2 <= max_degree {
+                        possible_domain_sizes.push(cur - 2);
+                        cur *= 2;
+                    }
+                }
+                possible_domain_sizes.sort_unstable();
+
+                possible_domain_sizes
             }
             KZG10DegreeBoundsConfig::LIST(v) => v.clone(),
             KZG10DegreeBoundsConfig::NONE => vec![],
@@ -290,7 +301,10 @@ impl<E: PairingEngine> KZG10<E> {
         index: usize
     ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
         Self::check_degree_is_too_large(evaluations.len() - 1, lagrange_basis.size())?;
-        assert_eq!(evaluations.len().next_power_of_two(), lagrange_basis.size());
+        // Evaluations may be implicitly zero-padded, which for mixed-radix bases is covered by the check above.
+        if lagrange_basis.size().is_power_of_two() {
+            assert_eq!(evaluations.len().next_power_of_two(), lagrange_basis.size());
+        }
 
         let commit_time = start_timer!(|| format!(
             "Committing to polynomial of degree {} with hiding_bound: {:?}",
@@ -337,6 +351,141 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((Commitment(commitment.into()), randomness))
     }
 
+    /// Outputs a commitment to the polynomial with the given `coefficients`, streaming both the
+    /// coefficients and the powers of beta from `pp` in windows of `chunk_size` elements.
+    ///
+    /// Unlike `commit`, this does not require every power of beta to be resident in memory, which
+    /// makes it suitable for committing to very large polynomials. The powers must already be
+    /// available up to the degree of the polynomial (see `UniversalParams::download_up_to`).
+    pub fn commit_in_chunks(
+        pp: &UniversalParams<E>,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        chunk_size: usize,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+        index: usize,
+    ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
+        let commit_time = start_timer!(|| format!(
+            "Committing to polynomial in chunks of size {} with hiding_bound: {:?}",
+            chunk_size, hiding_bound,
+        ));
+
+        let num_powers = pp.max_degree() + 1;
+        let bases = |lower: usize, upper: usize| {
+            Self::check_degree_is_too_large(upper - 1, num_powers)?;
+            Ok(Cow::Owned(pp.powers_of_beta_g(lower, upper)))
+        };
+        let mut commitment = Self::msm_in_chunks(bases, coefficients, chunk_size, terminator, index)?;
+
+        let powers_of_beta_times_gamma_g = pp.get_powers_times_gamma_g();
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(hiding_bound, |i| powers_of_beta_times_gamma_g.get(&i).copied(), rng, index)?;
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PCError::Terminated);
+        }
+
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((Commitment(commitment.into()), randomness))
+    }
+
+    /// Outputs a commitment to the polynomial with the given `evaluations` over the domain of
+    /// `lagrange_basis`, consuming the evaluations in windows of `chunk_size` elements.
+    pub fn commit_lagrange_in_chunks(
+        lagrange_basis: &LagrangeBasis<E>,
+        evaluations: impl IntoIterator<Item = E::Fr>,
+        chunk_size: usize,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+        index: usize,
+    ) -> Result<(Commitment<E>, Randomness<E>), PCError> {
+        let commit_time = start_timer!(|| format!(
+            "Committing to evaluations in chunks of size {} with hiding_bound: {:?}",
+            chunk_size, hiding_bound,
+        ));
+
+        let bases = |lower: usize, upper: usize| {
+            Self::check_degree_is_too_large(upper - 1, lagrange_basis.size())?;
+            Ok(Cow::Borrowed(&lagrange_basis.lagrange_basis_at_beta_g[lower..upper]))
+        };
+        let mut commitment = Self::msm_in_chunks(bases, evaluations, chunk_size, terminator, index)?;
+
+        let powers_of_beta_times_gamma_g = &lagrange_basis.powers_of_beta_times_gamma_g;
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(hiding_bound, |i| powers_of_beta_times_gamma_g.get(i).copied(), rng, index)?;
+
+        if terminator.load(Ordering::Relaxed) {
+            return Err(PCError::Terminated);
+        }
+
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((Commitment(commitment.into()), randomness))
+    }
+
+    /// Computes the MSM of `scalars` with the bases returned by `bases(lower, upper)`,
+    /// processing `chunk_size` scalars at a time and accumulating the partial results.
+    fn msm_in_chunks<'a>(
+        mut bases: impl FnMut(usize, usize) -> Result<Cow<'a, [E::G1Affine]>, PCError>,
+        scalars: impl IntoIterator<Item = E::Fr>,
+        chunk_size: usize,
+        terminator: &AtomicBool,
+        index: usize,
+    ) -> Result<E::G1Projective, PCError> {
+        if chunk_size == 0 {
+            return Err(PCError::ChunkSizeIsZero);
+        }
+
+        let mut result = E::G1Projective::zero();
+        let mut lower = 0;
+        for chunk in &scalars.into_iter().chunks(chunk_size) {
+            let scalars = chunk.map(|s| s.to_repr()).collect::<Vec<_>>();
+            let upper = lower + scalars.len();
+
+            let msm_time = start_timer!(|| format!("MSM over powers {lower}..{upper}"));
+            result += VariableBase::msm(&bases(lower, upper)?, &scalars, index);
+            end_timer!(msm_time);
+
+            if terminator.load(Ordering::Relaxed) {
+                return Err(PCError::Terminated);
+            }
+            lower = upper;
+        }
+        Ok(result)
+    }
+
+    /// Samples a blinding polynomial for the given `hiding_bound` and commits to it
+    /// using the powers of beta times gamma returned by `power_of_beta_times_gamma_g(i)`.
+    fn commit_to_randomness(
+        hiding_bound: Option<usize>,
+        power_of_beta_times_gamma_g: impl FnMut(usize) -> Option<E::G1Affine>,
+        rng: Option<&mut dyn RngCore>,
+        index: usize,
+    ) -> Result<(E::G1Affine, Randomness<E>), PCError> {
+        let hiding_degree = match hiding_bound {
+            Some(hiding_degree) => hiding_degree,
+            None => return Ok((E::G1Affine::zero(), Randomness::empty())),
+        };
+        let mut rng = rng.ok_or(PCError::MissingRng)?;
+        let randomness = Randomness::<E>::rand(hiding_degree, false, &mut rng);
+
+        let hiding_poly_degree = randomness.blinding_polynomial.degree();
+        let powers = (0..=hiding_poly_degree).map_while(power_of_beta_times_gamma_g).collect::<Vec<_>>();
+        Self::check_hiding_bound(hiding_poly_degree, powers.len())?;
+
+        let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
+        let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
+        let random_commitment = VariableBase::msm(&powers, &random_ints, index).to_affine();
+        end_timer!(msm_time);
+
+        Ok((random_commitment, randomness))
+    }
+
     /// Compute witness polynomial.
     ///
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
@@ -575,6 +724,7 @@ mod tests {
     #![allow(non_camel_case_types)]
     #![allow(clippy::needless_borrow)]
     use super::*;
+    use crate::fft::EvaluationDomain;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::{rand::test_rng, FromBytes, ToBytes};
 
@@ -722,6 +872,82 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_commit_in_chunks() {
+        let rng = &mut test_rng();
+        let terminator = AtomicBool::new(false);
+
+        let degree = 100;
+        let pp = KZG_Bls12_377::setup(degree, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, vk) = KZG_Bls12_377::trim(&pp, degree);
+
+        for chunk_size in [1, 7, 64, degree + 1, 1000] {
+            // Without hiding, the chunked commitment must match the in-memory one.
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let (expected, _) = KZG_Bls12_377::commit(&powers, &(&p).into(), None, &terminator, None, 0).unwrap();
+            let (comm, _) =
+                KZG_Bls12_377::commit_in_chunks(&pp, p.coeffs.iter().copied(), chunk_size, None, &terminator, None, 0)
+                    .unwrap();
+            assert_eq!(comm, expected);
+
+            // With hiding, the chunked commitment must still open correctly.
+            let (comm, rand) = KZG_Bls12_377::commit_in_chunks(
+                &pp,
+                p.coeffs.iter().copied(),
+                chunk_size,
+                Some(1),
+                &terminator,
+                Some(rng),
+                0,
+            )
+            .unwrap();
+            let point = Fr::rand(rng);
+            let proof = KZG_Bls12_377::open(&powers, &p, point, &rand).unwrap();
+            assert!(KZG_Bls12_377::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+        }
+
+        // The chunked path rejects polynomials exceeding the supported degree, and empty chunks.
+        let p = DensePolynomial::<Fr>::rand(degree + 1, rng);
+        let result = KZG_Bls12_377::commit_in_chunks(&pp, p.coeffs.iter().copied(), 16, None, &terminator, None, 0);
+        assert!(matches!(result, Err(PCError::TooManyCoefficients { .. })));
+        let result = KZG_Bls12_377::commit_in_chunks(&pp, p.coeffs.iter().copied(), 0, None, &terminator, None, 0);
+        assert!(matches!(result, Err(PCError::ChunkSizeIsZero)));
+    }
+
+    #[test]
+    fn test_commit_lagrange_in_chunks() {
+        let rng = &mut test_rng();
+        let terminator = AtomicBool::new(false);
+
+        let degree = 63;
+        let pp = KZG_Bls12_377::setup(degree, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree);
+
+        let domain = EvaluationDomain::new(degree + 1).unwrap();
+        let lagrange_basis = LagrangeBasis {
+            lagrange_basis_at_beta_g: Cow::Owned(pp.lagrange_basis(domain)),
+            powers_of_beta_times_gamma_g: powers.powers_of_beta_times_gamma_g.clone(),
+            domain,
+        };
+
+        let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let (expected, _) =
+            KZG_Bls12_377::commit_lagrange(&lagrange_basis, &evaluations, None, &terminator, None, 0).unwrap();
+        for chunk_size in [1, 5, 32, 64, 100] {
+            let (comm, _) = KZG_Bls12_377::commit_lagrange_in_chunks(
+                &lagrange_basis,
+                evaluations.iter().copied(),
+                chunk_size,
+                None,
+                &terminator,
+                None,
+                0,
+            )
+            .unwrap();
+            assert_eq!(comm, expected);
+        }
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut test_rng();
@@ -499,6 +499,11 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Returns the evaluation proofs, one for each distinct query point.
+    pub fn proofs(&self) -> &[kzg10::Proof<E>] {
+        &self.0
+    }
 }
 
 impl<E: PairingEngine> BatchProof<E> {
@@ -799,3 +804,44 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// A deferred pairing check for one or more batches of evaluation proofs.
+///
+/// Each entry in `commitments` is paired with `h` if its degree bound is `None`, and with the
+/// shift power for its degree bound otherwise; `witness` is paired with `beta_h`. The openings
+/// it was built from are valid if the product of these pairings is one. Since the shift powers
+/// only depend on the universal parameters, accumulators built under different verifying keys
+/// from the same universal parameters can be combined.
+///
+/// Checking `self` takes one pairing per distinct degree bound on top of the two pairings
+/// with `h` and `beta_h`. The shift powers `beta^{degree_bound - max_degree} h` are only
+/// published in G2, so the degree-bounded commitments cannot be folded into the commitment
+/// paired with `h` without weakening the degree bounds they enforce.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KZGAccumulator<E: PairingEngine> {
+    /// The combined commitments, keyed by degree bound.
+    pub commitments: BTreeMap<Option<usize>, E::G1Affine>,
+    /// The combined witness.
+    pub witness: E::G1Affine,
+}
+
+impl<E: PairingEngine> KZGAccumulator<E> {
+    /// Returns the number of pairings needed to check `self`.
+    pub fn num_pairings(&self) -> usize {
+        self.commitments.len() + 1
+    }
+}
+
+impl<E: PairingEngine> FromBytes for KZGAccumulator<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize KZGAccumulator"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for KZGAccumulator<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize KZGAccumulator"))
+    }
+}
@@ -129,15 +129,15 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for size in supported_lagrange_sizes {
             let lagrange_time = start_timer!(|| format!("Constructing `lagrange_bases` of size {size}"));
-            if !size.is_power_of_two() {
-                return Err(PCError::LagrangeBasisSizeIsNotPowerOfTwo);
-            }
+            // Mixed-radix sizes are supported if the scalar field defines a small subgroup.
+            let domain = crate::fft::EvaluationDomain::new_mixed_radix(size)
+                .filter(|domain| domain.size() == size)
+                .ok_or(PCError::LagrangeBasisSizeIsNotPowerOfTwo)?;
             if size > pp.max_degree() + 1 {
                 return Err(PCError::LagrangeBasisSizeIsTooLarge);
             }
-            let domain = crate::fft::EvaluationDomain::new(size).unwrap();
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain);
-            assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
+            assert_eq!(lagrange_basis_at_beta_g.len(), size);
             lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
             end_timer!(lagrange_time);
         }
@@ -264,12 +264,11 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
                         let rng_ref = rng.as_mut().map(|s| s as _);
                         match p {
                             PolynomialWithBasis::Lagrange { evaluations } => {
-                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
+                                let domain = evaluations.domain();
                                 let lagrange_basis = ck
                                     .lagrange_basis(domain)
                                     .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
-                                assert!(domain.size().is_power_of_two());
-                                assert!(lagrange_basis.size().is_power_of_two());
+                                assert_eq!(domain.size(), lagrange_basis.size());
                                 kzg10::KZG10::commit_lagrange(
                                     &lagrange_basis,
                                     &evaluations.evaluations,
@@ -313,6 +312,32 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    /// Outputs a commitment to the polynomial labelled `label` with the given `coefficients`,
+    /// streaming the coefficients and the powers of beta from `pp` in windows of `chunk_size`.
+    ///
+    /// This avoids materializing a `CommitterKey` for the full degree of the polynomial,
+    /// and is intended for circuits whose polynomials do not fit in memory alongside their powers.
+    /// Degree bounds are not supported on this path.
+    pub fn commit_in_chunks(
+        pp: &UniversalParams<E>,
+        label: impl Into<String>,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        chunk_size: usize,
+        hiding_bound: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(LabeledCommitment<Commitment<E>>, Randomness<E>), PCError> {
+        let (comm, rand) = kzg10::KZG10::commit_in_chunks(
+            pp,
+            coefficients,
+            chunk_size,
+            hiding_bound,
+            &AtomicBool::new(false),
+            rng,
+            0,
+        )?;
+        Ok((LabeledCommitment::new(label.into(), comm, None), rand))
+    }
+
     pub fn combine_for_open<'a>(
         ck: &CommitterKey<E>,
         labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
@@ -409,6 +434,23 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let accumulator = Self::batch_accumulate(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_accumulator(vk, &accumulator)
+    }
+
+    /// Performs every step of [`Self::batch_check`] except the final pairing check,
+    /// which is returned as a [`KZGAccumulator`].
+    pub fn batch_accumulate<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<KZGAccumulator<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -465,7 +507,72 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             randomizer = batch_kzg_check_fs_rng.squeeze_short_nonnative_field_element()?;
         }
 
-        Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk)
+        Ok(Self::finalize_accumulator(combined_comms, combined_witness, combined_adjusted_witness))
+    }
+
+    /// Checks the deferred pairing equation in `accumulator`.
+    pub fn check_accumulator(vk: &VerifierKey<E>, accumulator: &KZGAccumulator<E>) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| format!("Checking accumulator with {} pairings", accumulator.num_pairings()));
+        let mut g1_prepared_elems = Vec::with_capacity(accumulator.num_pairings());
+        let mut g2_prepared_elems = Vec::with_capacity(accumulator.num_pairings());
+
+        for (degree_bound, comm) in accumulator.commitments.iter() {
+            let shift_power = if let Some(degree_bound) = degree_bound {
+                vk.get_prepared_shift_power(*degree_bound).ok_or(PCError::UnsupportedDegreeBound(*degree_bound))?
+            } else {
+                vk.vk.prepared_h.clone()
+            };
+
+            g1_prepared_elems.push(comm.prepare());
+            g2_prepared_elems.push(shift_power);
+        }
+
+        g1_prepared_elems.push((-accumulator.witness).prepare());
+        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+
+        let g1_g2_prepared = g1_prepared_elems.iter().zip_eq(g2_prepared_elems.iter());
+        let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
+        end_timer!(check_time);
+        Ok(is_one)
+    }
+
+    /// Combines `accumulators` into a single accumulator, using randomizers derived from
+    /// all of them. The result is valid only if every accumulator in `accumulators` is valid,
+    /// except with negligible probability.
+    pub fn combine_accumulators<'a>(
+        accumulators: impl IntoIterator<Item = &'a KZGAccumulator<E>>,
+    ) -> Result<KZGAccumulator<E>, PCError> {
+        let accumulators = accumulators.into_iter().collect::<Vec<_>>();
+        let combine_time = start_timer!(|| format!("Combining {} accumulators", accumulators.len()));
+
+        let mut combine_fs_rng = S::new();
+        combine_fs_rng.absorb_bytes(&(accumulators.len() as u64).to_le_bytes());
+        for accumulator in accumulators.iter() {
+            combine_fs_rng.absorb_bytes(&(accumulator.commitments.len() as u64).to_le_bytes());
+            for (degree_bound, comm) in accumulator.commitments.iter() {
+                combine_fs_rng.absorb_bytes(&degree_bound.map_or(u64::MAX, |d| d as u64).to_le_bytes());
+                combine_fs_rng.absorb_native_field_elements(&[kzg10::Commitment::<E>(*comm)]);
+            }
+            combine_fs_rng.absorb_native_field_elements(&[kzg10::Commitment::<E>(accumulator.witness)]);
+        }
+
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        for accumulator in accumulators {
+            let randomizer = combine_fs_rng.squeeze_short_nonnative_field_element()?;
+            for (degree_bound, comm) in accumulator.commitments.iter() {
+                *combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += comm.mul(randomizer);
+            }
+            combined_witness += accumulator.witness.mul(randomizer);
+        }
+
+        let (degree_bounds, mut comms): (Vec<_>, Vec<_>) = combined_comms.into_iter().unzip();
+        comms.push(combined_witness);
+        let mut comms = E::G1Projective::batch_normalization_into_affine(comms);
+        let witness = comms.pop().unwrap();
+        end_timer!(combine_time);
+
+        Ok(KZGAccumulator { commitments: degree_bounds.into_iter().zip_eq(comms).collect(), witness })
     }
 
     pub fn open_combinations<'a>(
@@ -557,6 +664,25 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let accumulator =
+            Self::accumulate_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?;
+        Self::check_accumulator(vk, &accumulator)
+    }
+
+    /// Performs every step of [`Self::check_combinations`] except the final pairing check,
+    /// which is returned as a [`KZGAccumulator`].
+    pub fn accumulate_combinations<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<KZGAccumulator<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -612,7 +738,7 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_accumulate(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -710,43 +836,20 @@ impl<E: PairingEngine, S: FiatShamirRng<E::Fr, E::Fq>> SonicKZG10<E, S> {
         end_timer!(acc_time);
     }
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    fn finalize_accumulator(
+        mut combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
         combined_witness: E::G1Projective,
         combined_adjusted_witness: E::G1Projective,
-        vk: &VerifierKey<E>,
-    ) -> Result<bool, PCError> {
-        let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
-
-        for (degree_bound, comm) in combined_comms.into_iter() {
-            let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
-            } else {
-                vk.vk.prepared_h.clone()
-            };
-
-            g1_projective_elems.push(comm);
-            g2_prepared_elems.push(shift_power);
-        }
-
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+    ) -> KZGAccumulator<E> {
+        // The adjusted witness is paired with `h`, as are the commitments without a degree bound.
+        *combined_comms.entry(None).or_insert_with(E::G1Projective::zero) -= &combined_adjusted_witness;
 
-        g1_projective_elems.push(-combined_witness);
-        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        let (degree_bounds, mut comms): (Vec<_>, Vec<_>) = combined_comms.into_iter().unzip();
+        comms.push(combined_witness);
+        let mut comms = E::G1Projective::batch_normalization_into_affine(comms);
+        let witness = comms.pop().unwrap();
 
-        let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
-            .into_iter()
-            .map(|a| a.prepare())
-            .collect::<Vec<_>>();
-
-        let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
-        let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
-        end_timer!(check_time);
-        Ok(is_one)
+        KZGAccumulator { commitments: degree_bounds.into_iter().zip_eq(comms).collect(), witness }
     }
 }
 
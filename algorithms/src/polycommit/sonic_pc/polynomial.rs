@@ -260,7 +260,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
             lagrange_polys
                 .into_iter()
                 .map(|(k, v)| {
-                    let domain = EvaluationDomain::new(k).unwrap();
+                    let domain = EvaluationDomain::new_mixed_radix(k).unwrap();
                     Lagrange { evaluations: Cow::Owned(EvaluationsOnDomain::from_vec_and_domain(v, domain)) }
                 })
                 .chain({
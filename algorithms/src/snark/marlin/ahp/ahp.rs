@@ -40,7 +40,7 @@ pub struct AHPForR1CS<F: Field, MM: MarlinMode> {
     mode: PhantomData<MM>,
 }
 
-pub(crate) fn witness_label(poly: &str, i: usize) -> String {
+pub fn witness_label(poly: &str, i: usize) -> String {
     format!("{poly}_{:0>8}", i)
 }
 
@@ -93,22 +93,71 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Get all the strict degree bounds enforced in the AHP.
     pub fn get_degree_bounds(info: &CircuitInfo<F>) -> [usize; 4] {
-        let num_constraints = info.num_constraints;
-        let num_non_zero_a = info.num_non_zero_a;
-        let num_non_zero_b = info.num_non_zero_b;
-        let num_non_zero_c = info.num_non_zero_c;
-        [
-            EvaluationDomain::<F>::compute_size_of_domain(num_constraints).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_a).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_b).unwrap() - 2,
-            EvaluationDomain::<F>::compute_size_of_domain(num_non_zero_c).unwrap() - 2,
-        ]
+        let constraint_domain_size = Self::constraint_domain_size(info).unwrap();
+        let [non_zero_a_size, non_zero_b_size, non_zero_c_size] = Self::non_zero_domain_sizes(info).unwrap();
+        [constraint_domain_size - 2, non_zero_a_size - 2, non_zero_b_size - 2, non_zero_c_size - 2]
+    }
+
+    /// Returns the size of the constraint domain `H`.
+    ///
+    /// If `MM::MIXED_RADIX` is set, this is the smallest mixed-radix size that fits the constraints,
+    /// as long as the (radix-2) input domain is still a subgroup of it.
+    pub fn constraint_domain_size(info: &CircuitInfo<F>) -> Option<usize> {
+        let radix_2_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints)?;
+        if MM::MIXED_RADIX {
+            let input_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_public_inputs)?;
+            let size = EvaluationDomain::<F>::compute_size_of_mixed_radix_domain(info.num_constraints)?;
+            if size % input_domain_size == 0 {
+                return Some(size);
+            }
+        }
+        Some(radix_2_size)
+    }
+
+    /// Returns the sizes of the non-zero domains `K_A`, `K_B`, and `K_C`.
+    ///
+    /// If `MM::MIXED_RADIX` is set, mixed-radix sizes are used, and any domain whose size
+    /// does not divide the largest one is enlarged to the largest one, so that every
+    /// non-zero domain remains a subgroup of the largest non-zero domain.
+    pub fn non_zero_domain_sizes(info: &CircuitInfo<F>) -> Option<[usize; 3]> {
+        let num_non_zero = [info.num_non_zero_a, info.num_non_zero_b, info.num_non_zero_c];
+        if MM::MIXED_RADIX {
+            let sizes = num_non_zero
+                .into_iter()
+                .map(EvaluationDomain::<F>::compute_size_of_mixed_radix_domain)
+                .collect::<Option<Vec<_>>>()?;
+            let largest = sizes.iter().copied().max()?;
+            let mut result = [0; 3];
+            for (result, size) in result.iter_mut().zip(sizes) {
+                *result = if largest % size == 0 { size } else { largest };
+            }
+            Some(result)
+        } else {
+            let mut result = [0; 3];
+            for (result, num_non_zero) in result.iter_mut().zip(num_non_zero) {
+                *result = EvaluationDomain::<F>::compute_size_of_domain(num_non_zero)?;
+            }
+            Some(result)
+        }
+    }
+
+    /// Returns the constraint domain `H`.
+    pub fn constraint_domain(info: &CircuitInfo<F>) -> Option<EvaluationDomain<F>> {
+        EvaluationDomain::new_mixed_radix(Self::constraint_domain_size(info)?)
+    }
+
+    /// Returns the non-zero domains `K_A`, `K_B`, and `K_C`.
+    pub fn non_zero_domains(info: &CircuitInfo<F>) -> Option<[EvaluationDomain<F>; 3]> {
+        let [a, b, c] = Self::non_zero_domain_sizes(info)?;
+        Some([
+            EvaluationDomain::new_mixed_radix(a)?,
+            EvaluationDomain::new_mixed_radix(b)?,
+            EvaluationDomain::new_mixed_radix(c)?,
+        ])
     }
 
     pub fn max_non_zero_domain(info: &CircuitInfo<F>) -> EvaluationDomain<F> {
-        let non_zero_a_domain = EvaluationDomain::new(info.num_non_zero_a).unwrap();
-        let non_zero_b_domain = EvaluationDomain::new(info.num_non_zero_b).unwrap();
-        let non_zero_c_domain = EvaluationDomain::new(info.num_non_zero_c).unwrap();
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] = Self::non_zero_domains(info).unwrap();
         Self::max_non_zero_domain_helper(non_zero_a_domain, non_zero_b_domain, non_zero_c_domain)
     }
 
@@ -126,6 +175,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         non_zero_b_domain_size: usize,
         non_zero_c_domain_size: usize,
     ) -> Option<(FFTPrecomputation<F>, IFFTPrecomputation<F>)> {
+        let domain_sizes =
+            [constraint_domain_size, non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size];
         let largest_domain_size = [
             3 * constraint_domain_size,
             non_zero_a_domain_size * 2,
@@ -134,7 +185,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         ]
         .into_iter()
         .max()?;
-        let largest_mul_domain = EvaluationDomain::new(largest_domain_size)?;
+        // For mixed-radix domains, the precomputation must also cover the largest odd factor,
+        // so that every domain used by the prover is a subgroup of `largest_mul_domain`.
+        let largest_odd_factor = domain_sizes.into_iter().map(|size| size >> size.trailing_zeros()).max()?;
+        let largest_mul_domain = EvaluationDomain::new_mixed_radix(
+            EvaluationDomain::<F>::compute_size_of_domain(largest_domain_size)?.checked_mul(largest_odd_factor)?,
+        )?;
 
         let fft_precomputation = largest_mul_domain.precompute_fft();
         let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
@@ -17,10 +17,7 @@
 use core::marker::PhantomData;
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        EvaluationDomain,
-    },
+    fft::domain::{FFTPrecomputation, IFFTPrecomputation},
     polycommit::sonic_pc::LabeledPolynomial,
     snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, MarlinMode, Matrix},
 };
@@ -65,7 +62,7 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
 
     /// The number of constraints in this R1CS instance.
     pub fn constraint_domain_size(&self) -> usize {
-        crate::fft::EvaluationDomain::<F>::new(self.index_info.num_constraints).unwrap().size()
+        AHPForR1CS::<F, MM>::constraint_domain_size(&self.index_info).unwrap()
     }
 
     /// Iterate over the indexed polynomials.
@@ -137,14 +134,10 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
         validate: Validate,
     ) -> Result<Self, SerializationError> {
         let index_info: CircuitInfo<F> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
-        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_constraints)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_a_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_a)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_b_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_b)
-            .ok_or(SerializationError::InvalidData)?;
-        let non_zero_c_domain_size = EvaluationDomain::<F>::compute_size_of_domain(index_info.num_non_zero_c)
-            .ok_or(SerializationError::InvalidData)?;
+        let constraint_domain_size =
+            AHPForR1CS::<F, MM>::constraint_domain_size(&index_info).ok_or(SerializationError::InvalidData)?;
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<F, MM>::non_zero_domain_sizes(&index_info).ok_or(SerializationError::InvalidData)?;
 
         let (fft_precomputation, ifft_precomputation) = AHPForR1CS::<F, MM>::fft_precomputation(
             constraint_domain_size,
@@ -20,7 +20,7 @@ mod circuit;
 pub(crate) use circuit::*;
 
 mod circuit_info;
-pub(crate) use circuit_info::*;
+pub use circuit_info::*;
 
 mod constraint_system;
 pub(crate) use constraint_system::*;
@@ -55,7 +55,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
     /// Output the degree bounds of oracles in the first round.
     pub fn second_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
+        let constraint_domain_size = Self::constraint_domain_size(info).unwrap();
         [
             PolynomialInfo::new("g_1".into(), Some(constraint_domain_size - 2), Self::zk_bound()),
             PolynomialInfo::new("h_1".into(), None, None),
@@ -137,8 +137,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         assert_eq!(MM::ZK, mask_poly.is_some());
 
         let mul_domain_size = (constraint_domain.size() + summed_z_m.coeffs.len()).max(t.coeffs.len() + z.len());
-        let mul_domain =
-            EvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
+        let mul_domain = EvaluationDomain::new_containing(mul_domain_size, &constraint_domain)
+            .expect("field is not smooth enough to construct domain");
         let mut multiplier = PolyMultiplier::new();
         multiplier.add_precomputation(state.fft_precomputation(), state.ifft_precomputation());
         multiplier.add_polynomial(summed_z_m, "summed_z_m");
@@ -26,6 +26,7 @@ use crate::{
     snark::marlin::{
         ahp::{indexer::Circuit, verifier},
         AHPError,
+        AHPForR1CS,
         MarlinMode,
     },
 };
@@ -97,14 +98,10 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
     ) -> Result<Self, AHPError> {
         let index_info = &index.index_info;
         let constraint_domain =
-            EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_a_domain =
-            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_b_domain =
-            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+            AHPForR1CS::<F, MM>::constraint_domain(index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            AHPForR1CS::<F, MM>::non_zero_domains(index_info).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let input_domain =
             EvaluationDomain::new(padded_public_input[0].len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
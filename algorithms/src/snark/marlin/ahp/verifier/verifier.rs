@@ -17,7 +17,6 @@
 use core::marker::PhantomData;
 
 use crate::{
-    fft::EvaluationDomain,
     snark::marlin::{
         ahp::{
             indexer::CircuitInfo,
@@ -44,16 +43,10 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
             return Err(AHPError::NonSquareMatrix);
         }
 
-        let constraint_domain =
-            EvaluationDomain::new(index_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let constraint_domain = Self::constraint_domain(&index_info).ok_or(AHPError::PolynomialDegreeTooLarge)?;
 
-        let non_zero_a_domain =
-            EvaluationDomain::new(index_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-
-        let non_zero_b_domain =
-            EvaluationDomain::new(index_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
-        let non_zero_c_domain =
-            EvaluationDomain::new(index_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            Self::non_zero_domains(&index_info).ok_or(AHPError::PolynomialDegreeTooLarge)?;
 
         let elems = fs_rng.squeeze_nonnative_field_elements(3 + batch_size - 1, OptimizationType::Weight)?;
         let (first, rest) = elems.split_at(3);
@@ -15,9 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fft::EvaluationDomain,
     polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
+    snark::marlin::{ahp::indexer::*, AHPForR1CS, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
     Prepare,
 };
 use snarkvm_curves::PairingEngine;
@@ -65,13 +64,9 @@ impl<E: PairingEngine, MM: MarlinMode> Prepare for CircuitVerifyingKey<E, MM> {
         let prepared_verifier_key = self.verifier_key.prepare();
 
         let constraint_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_constraints).unwrap() as u64;
-        let non_zero_a_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_a).unwrap() as u64;
-        let non_zero_b_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u64;
-        let non_zero_c_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u64;
+            AHPForR1CS::<E::Fr, MM>::constraint_domain_size(&self.circuit_info).unwrap() as u64;
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<E::Fr, MM>::non_zero_domain_sizes(&self.circuit_info).unwrap().map(|size| size as u64);
 
         PreparedCircuitVerifyingKey {
             constraint_domain_size,
@@ -105,18 +100,13 @@ impl<E: PairingEngine, MM: MarlinMode> From<PreparedCircuitVerifyingKey<E, MM>>
 
 impl<E: PairingEngine, MM: MarlinMode> ToMinimalBits for CircuitVerifyingKey<E, MM> {
     fn to_minimal_bits(&self) -> Vec<bool> {
-        let constraint_domain = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_constraints)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_a = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_a)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_b = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_b)
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
-        let non_zero_domain_c = EvaluationDomain::<E::Fr>::new(self.circuit_info.num_non_zero_c)
+        let constraint_domain = AHPForR1CS::<E::Fr, MM>::constraint_domain(&self.circuit_info)
             .ok_or(SynthesisError::PolynomialDegreeTooLarge)
             .unwrap();
+        let [non_zero_domain_a, non_zero_domain_b, non_zero_domain_c] =
+            AHPForR1CS::<E::Fr, MM>::non_zero_domains(&self.circuit_info)
+                .ok_or(SynthesisError::PolynomialDegreeTooLarge)
+                .unwrap();
 
         assert!(constraint_domain.size() < u64::MAX as usize);
         assert!(non_zero_domain_a.size() < u64::MAX as usize);
@@ -184,13 +174,9 @@ impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E, MM> {
 impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerifyingKey<E, MM> {
     fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
         let constraint_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_constraints).unwrap() as u128;
-        let non_zero_a_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_a).unwrap() as u128;
-        let non_zero_b_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_b).unwrap() as u128;
-        let non_zero_c_domain_size =
-            EvaluationDomain::<E::Fr>::compute_size_of_domain(self.circuit_info.num_non_zero_c).unwrap() as u128;
+            AHPForR1CS::<E::Fr, MM>::constraint_domain_size(&self.circuit_info).unwrap() as u128;
+        let [non_zero_a_domain_size, non_zero_b_domain_size, non_zero_c_domain_size] =
+            AHPForR1CS::<E::Fr, MM>::non_zero_domain_sizes(&self.circuit_info).unwrap().map(|size| size as u128);
 
         let mut res = Vec::new();
         res.append(&mut E::Fq::from(constraint_domain_size).to_field_elements()?);
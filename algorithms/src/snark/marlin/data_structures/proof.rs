@@ -241,6 +241,11 @@ impl<E: PairingEngine> Proof<E> {
     ) -> Self {
         Self { batch_size, commitments, evaluations, msg, pc_proof }
     }
+
+    /// Returns the number of instances being proven in this proof.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
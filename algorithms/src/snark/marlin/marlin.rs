@@ -16,7 +16,7 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{Commitment, Evaluations, KZGAccumulator, LabeledCommitment, Randomness, SonicKZG10},
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
         fiat_shamir::traits::FiatShamirRng,
@@ -144,6 +144,193 @@ impl<E: PairingEngine, FS: FiatShamirRng<E::Fr, E::Fq>, MM: MarlinMode, Input: T
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
+    /// Runs the verifier on `proof` up to the final pairing check of the polynomial commitment scheme,
+    /// and returns that check as a [`KZGAccumulator`], so it can be deferred and combined with the checks
+    /// of other proofs. Returns `None` if `proof` fails any check that comes before the pairing check.
+    pub fn accumulate<B: Borrow<Input>>(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+    ) -> Result<Option<KZGAccumulator<E>>, SNARKError> {
+        if public_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        let accumulate_time = start_timer!(|| "Marlin::Accumulate");
+
+        let comms = &proof.commitments;
+        let proof_has_correct_zk_mode = if MM::ZK {
+            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+        } else {
+            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+        };
+        if !proof_has_correct_zk_mode {
+            eprintln!(
+                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
+                proof.pc_proof.is_hiding()
+            );
+            end_timer!(accumulate_time);
+            return Ok(None);
+        }
+
+        let batch_size = public_inputs.len();
+
+        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
+        let mut first_commitments = comms
+            .witness_commitments
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                [
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                ]
+            })
+            .collect::<Vec<_>>();
+        if MM::ZK {
+            first_commitments.push(LabeledCommitment::new_with_info(
+                first_round_info.get("mask_poly").unwrap(),
+                comms.mask_poly.unwrap(),
+            ));
+        }
+
+        let second_round_info =
+            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+        let second_commitments = [
+            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+        ];
+        let third_round_info =
+            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
+        let third_commitments = [
+            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+        ];
+        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
+
+        let input_domain =
+            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
+            public_inputs
+                .iter()
+                .map(|input| {
+                    let input = input.borrow().to_field_elements().unwrap();
+                    let mut new_input = vec![E::Fr::one()];
+                    new_input.extend_from_slice(&input);
+                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                    if cfg!(debug_assertions) {
+                        println!("Number of padded public variables: {}", new_input.len());
+                    }
+                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                    (new_input, unformatted)
+                })
+                .unzip()
+        };
+
+        let mut sponge =
+            Self::init_sponge(batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs);
+
+        // --------------------------------------------------------------------
+        // First round
+        Self::absorb_labeled(&first_commitments, &mut sponge);
+        let (_, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        Self::absorb_labeled(&second_commitments, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // Collect degree bounds for commitments. Indexed polynomials have *no*
+        // degree bounds because we know the committed index polynomial has the
+        // correct degree.
+
+        // Gather commitments in one vector.
+        let commitments: Vec<_> = circuit_verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
+            .chain(fourth_commitments)
+            .collect();
+
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+
+        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
+
+        let mut evaluations = Evaluations::new();
+
+        for (label, (_point_name, q)) in query_set.to_set() {
+            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                evaluations.insert((label, q), E::Fr::zero());
+            } else {
+                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                evaluations.insert((label, q), eval);
+            }
+        }
+
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+            &public_inputs,
+            &evaluations,
+            &proof.msg,
+            &verifier_state,
+        )?;
+
+        let accumulator = SonicKZG10::<E, FS>::accumulate_combinations(
+            &circuit_verifying_key.verifier_key,
+            lc_s.values(),
+            &commitments,
+            &query_set.to_set(),
+            &evaluations,
+            &proof.pc_proof,
+            &mut sponge,
+        )?;
+        end_timer!(accumulate_time);
+
+        Ok(Some(accumulator))
+    }
+
+    /// Combines `accumulators` into a single accumulator that is checked with one product of pairings.
+    /// The accumulators may come from proofs for different circuits, as long as their verifying keys
+    /// were derived from the same universal SRS.
+    pub fn combine_accumulators<'a>(
+        accumulators: impl IntoIterator<Item = &'a KZGAccumulator<E>>,
+    ) -> Result<KZGAccumulator<E>, SNARKError> {
+        Ok(SonicKZG10::<E, FS>::combine_accumulators(accumulators)?)
+    }
+
+    /// Checks `accumulator`, using any verifying key derived from the universal SRS that the
+    /// accumulated proofs were created under.
+    pub fn verify_accumulator(
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        accumulator: &KZGAccumulator<E>,
+    ) -> Result<bool, SNARKError> {
+        let verify_time = start_timer!(|| "Marlin::VerifyAccumulator");
+        let result = SonicKZG10::<E, FS>::check_accumulator(&circuit_verifying_key.verifier_key, accumulator)?;
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
     fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
         if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
     }
@@ -477,158 +664,14 @@ where
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError> {
         let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
-            return Err(SNARKError::EmptyBatch);
-        }
         let verifier_time = start_timer!(|| "Marlin::Verify");
 
-        let comms = &proof.commitments;
-        let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
-        } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
-        };
-        if !proof_has_correct_zk_mode {
-            eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
-            );
-            return Ok(false);
-        }
-
-        let batch_size = public_inputs.len();
-
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
-            .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
-            .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
-        }
-
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
-                .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
-                })
-                .unzip()
-        };
-
-        let mut sponge =
-            Self::init_sponge(batch_size, &circuit_verifying_key.circuit_commitments, &padded_public_inputs);
-
-        // --------------------------------------------------------------------
-        // First round
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Second round
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Third round
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Fourth round
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
-
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
-
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements(), OptimizationType::Weight);
-
-        let mut evaluations = Evaluations::new();
-
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
+        let evaluations_are_correct = match Self::accumulate(circuit_verifying_key, public_inputs, proof)? {
+            Some(accumulator) => {
+                SonicKZG10::<E, FS>::check_accumulator(&circuit_verifying_key.verifier_key, &accumulator)?
             }
-        }
-
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
-
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
-            lc_s.values(),
-            &commitments,
-            &query_set.to_set(),
-            &evaluations,
-            &proof.pc_proof,
-            &mut sponge,
-        )?;
+            None => false,
+        };
 
         if !evaluations_are_correct {
             #[cfg(debug_assertions)]
@@ -636,9 +679,9 @@ where
         }
         end_timer!(verifier_time, || format!(
             " SonicKZG10::<E, FS>::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
+            evaluations_are_correct
         ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        Ok(evaluations_are_correct)
     }
 }
 
@@ -19,6 +19,10 @@ use core::fmt::Debug;
 /// A trait to specify the Marlin mode.
 pub trait MarlinMode: 'static + Copy + Clone + Debug + PartialEq + Eq + Sync + Send {
     const ZK: bool;
+
+    /// Whether the constraint and non-zero domains may be mixed-radix (of size `2^k * b^m`)
+    /// when the scalar field defines a small subgroup of order `b`.
+    const MIXED_RADIX: bool = false;
 }
 
 /// The Marlin hiding mode produces a hiding Marlin proof.
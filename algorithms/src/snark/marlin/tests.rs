@@ -72,6 +72,7 @@ mod marlin {
         AHPForR1CS,
         CircuitVerifyingKey,
         MarlinHidingMode,
+        MarlinMode,
         MarlinNonHidingMode,
         MarlinSNARK,
     };
@@ -81,11 +82,23 @@ mod marlin {
     use blake2::Blake2s256;
     use core::ops::MulAssign;
 
+    /// A hiding mode that lets the AHP pick mixed-radix constraint and non-zero domains.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct MarlinMixedRadixMode;
+
+    impl MarlinMode for MarlinMixedRadixMode {
+        const ZK: bool = true;
+        const MIXED_RADIX: bool = true;
+    }
+
     type MarlinSonicInst = MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinHidingMode, [Fr]>;
 
     type MarlinSonicPoswInst =
         MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinNonHidingMode, [Fr]>;
 
+    type MarlinSonicMixedRadixInst =
+        MarlinSNARK<Bls12_377, FiatShamirChaChaRng<Fr, Fq, Blake2s256>, MarlinMixedRadixMode, [Fr]>;
+
     macro_rules! impl_marlin_test {
         ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
             struct $test_struct {}
@@ -215,6 +228,7 @@ mod marlin {
 
     impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinHidingMode);
     impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinNonHidingMode);
+    impl_marlin_test!(SonicPCMixedRadixTest, MarlinSonicMixedRadixInst, MarlinMixedRadixMode);
 
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
@@ -290,12 +304,35 @@ mod marlin {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_verify_with_mixed_radix_domains() {
+        let num_constraints = 90;
+        let num_variables = 25;
+
+        let rng = &mut test_crypto_rng();
+        let max_degree = AHPForR1CS::<Fr, MarlinMixedRadixMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinSonicMixedRadixInst::universal_setup(&max_degree, rng).unwrap();
+        let circ = Circuit { a: Some(Fr::rand(rng)), b: Some(Fr::rand(rng)), num_constraints, num_variables };
+        let (_, index_vk) = MarlinSonicMixedRadixInst::circuit_setup(&universal_srs, &circ).unwrap();
+
+        // The constraint domain has size 3 * 2^5 rather than 2^7.
+        let constraint_domain = AHPForR1CS::<Fr, MarlinMixedRadixMode>::constraint_domain(&index_vk.circuit_info);
+        assert_eq!(constraint_domain.unwrap().size(), 96);
+        let constraint_domain = AHPForR1CS::<Fr, MarlinHidingMode>::constraint_domain(&index_vk.circuit_info);
+        assert_eq!(constraint_domain.unwrap().size(), 128);
+
+        SonicPCMixedRadixTest::test_circuit(num_constraints, num_variables);
+        SonicPCMixedRadixTest::test_serde_json(num_constraints, num_variables);
+        SonicPCMixedRadixTest::test_bincode(num_constraints, num_variables);
+    }
 }
 
 mod marlin_recursion {
     use super::*;
     use crate::{
         crypto_hash::PoseidonSponge,
+        polycommit::sonic_pc::KZGAccumulator,
         snark::marlin::{
             ahp::AHPForR1CS,
             fiat_shamir::FiatShamirAlgebraicSpongeRng,
@@ -312,7 +349,7 @@ mod marlin_recursion {
     };
 
     use core::ops::MulAssign;
-    use std::str::FromStr;
+    use std::{collections::BTreeSet, str::FromStr};
 
     type MarlinInst =
         MarlinSNARK<Bls12_377, FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>, MarlinHidingMode, [Fr]>;
@@ -439,6 +476,64 @@ mod marlin_recursion {
         test_bincode(num_constraints, num_variables);
     }
 
+    #[test]
+    fn accumulate_proofs_for_different_circuits() {
+        let rng = &mut test_crypto_rng();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+
+        let mut verifying_keys = Vec::new();
+        let mut accumulators = Vec::new();
+        let mut all_degree_bounds = BTreeSet::new();
+        for (num_constraints, num_variables) in [(25, 5), (100, 25), (25, 100)] {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let mut d = c;
+            d.mul_assign(&b);
+
+            let circuit = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+            let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+
+            let accumulator = MarlinInst::accumulate(&index_vk, &[[c, d]], &proof).unwrap().unwrap();
+            assert!(MarlinInst::verify_accumulator(&index_vk, &accumulator).unwrap());
+
+            // One pairing with `h`, one with `beta_h`, and one per distinct degree bound.
+            let degree_bounds = AHPForR1CS::<Fr, MarlinHidingMode>::get_degree_bounds(&index_vk.circuit_info);
+            all_degree_bounds.extend(degree_bounds);
+            let num_degree_bounds = degree_bounds.iter().collect::<BTreeSet<_>>().len();
+            assert_eq!(accumulator.num_pairings(), 2 + num_degree_bounds);
+
+            // An accumulator for the wrong public inputs should not verify.
+            let invalid = MarlinInst::accumulate(&index_vk, &[[a, a]], &proof).unwrap().unwrap();
+            assert!(!MarlinInst::verify_accumulator(&index_vk, &invalid).unwrap());
+
+            verifying_keys.push(index_vk);
+            accumulators.push((accumulator, invalid));
+        }
+
+        let combined = MarlinInst::combine_accumulators(accumulators.iter().map(|(valid, _)| valid)).unwrap();
+        let candidate = KZGAccumulator::<Bls12_377>::read_le(&combined.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(combined, candidate);
+        // Combining accumulators only adds pairings for degree bounds that none of the others use.
+        assert_eq!(combined.num_pairings(), 2 + all_degree_bounds.len());
+        for index_vk in &verifying_keys {
+            assert!(MarlinInst::verify_accumulator(index_vk, &candidate).unwrap());
+        }
+
+        // Replacing any one accumulator with an invalid one should invalidate the combination.
+        for i in 0..accumulators.len() {
+            let combined = MarlinInst::combine_accumulators(
+                accumulators.iter().enumerate().map(|(j, (valid, invalid))| if i == j { invalid } else { valid }),
+            )
+            .unwrap();
+            assert!(!MarlinInst::verify_accumulator(&verifying_keys[0], &combined).unwrap());
+        }
+    }
+
     // #[test]
     // /// Test on a constraint system that will trigger outlining.
     // fn prove_and_test_outlining() {
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> From<(Group<A>, Group<A>)> for ComputeKey<A> {
+    /// Derives the account compute key from a tuple `(pk_sig, pr_sig)`.
+    fn from((pk_sig, pr_sig): (Group<A>, Group<A>)) -> Self {
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from(mode: Mode) -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, compute_key, _view_key, _address) = generate_account()?;
+
+            // Inject the signature public key and signature public randomizer.
+            let pk_sig = Group::<Circuit>::new(mode, compute_key.pk_sig());
+            let pr_sig = Group::<Circuit>::new(mode, compute_key.pr_sig());
+
+            Circuit::scope(format!("From {mode}"), || {
+                let candidate = ComputeKey::from((pk_sig, pr_sig));
+                assert_eq!(compute_key, candidate.eject_value());
+                assert_eq!(compute_key.sk_prf(), candidate.sk_prf().eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_constant() -> Result<()> {
+        check_from(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_public() -> Result<()> {
+        check_from(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_private() -> Result<()> {
+        check_from(Mode::Private)
+    }
+}
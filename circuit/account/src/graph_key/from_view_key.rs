@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> GraphKey<A> {
+    /// Returns the account graph key for this account view key.
+    pub fn from_view_key(view_key: &ViewKey<A>) -> Self {
+        // Compute sk_tag := Hash(view_key).
+        Self { sk_tag: A::hash_psd2(&[A::graph_key_domain(), view_key.to_field()]) }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_view_key(mode: Mode) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, _compute_key, view_key, _address) = generate_account()?;
+            // Derive the expected graph key.
+            let graph_key = console::GraphKey::try_from(view_key)?;
+
+            // Initialize the view key.
+            let view_key = ViewKey::<Circuit>::new(mode, view_key);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = GraphKey::from_view_key(&view_key);
+                assert_eq!(graph_key, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_view_key_constant() -> Result<()> {
+        check_from_view_key(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_view_key_public() -> Result<()> {
+        check_from_view_key(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_view_key_private() -> Result<()> {
+        check_from_view_key(Mode::Private)
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Retrieve the scalar and field size in bits.
+        let (scalar_size, field_size) = Self::component_sizes_in_bits();
+
+        // Ensure the number of bits matches the signature size.
+        if bits_le.len() != 2 * scalar_size + 2 * field_size {
+            A::halt("Invalid signature size in bits")
+        }
+
+        // Split the bits into the challenge, response, and compute key components.
+        let (challenge, bits_le) = bits_le.split_at(scalar_size);
+        let (response, bits_le) = bits_le.split_at(scalar_size);
+        let (pk_sig, pr_sig) = bits_le.split_at(field_size);
+
+        // Recover the compute key from the affine x-coordinates of `pk_sig` and `pr_sig`.
+        let compute_key = ComputeKey::from((Group::from_bits_le(pk_sig), Group::from_bits_le(pr_sig)));
+
+        // Return the signature.
+        Self { challenge: Scalar::from_bits_le(challenge), response: Scalar::from_bits_le(response), compute_key }
+    }
+
+    /// Initializes a new signature from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Retrieve the scalar and field size in bits.
+        let (scalar_size, field_size) = Self::component_sizes_in_bits();
+
+        // Ensure the number of bits matches the signature size.
+        if bits_be.len() != 2 * scalar_size + 2 * field_size {
+            A::halt("Invalid signature size in bits")
+        }
+
+        // Split the bits into the challenge, response, and compute key components.
+        let (challenge, bits_be) = bits_be.split_at(scalar_size);
+        let (response, bits_be) = bits_be.split_at(scalar_size);
+        let (pk_sig, pr_sig) = bits_be.split_at(field_size);
+
+        // Recover the compute key from the affine x-coordinates of `pk_sig` and `pr_sig`.
+        let compute_key = ComputeKey::from((Group::from_bits_be(pk_sig), Group::from_bits_be(pr_sig)));
+
+        // Return the signature.
+        Self { challenge: Scalar::from_bits_be(challenge), response: Scalar::from_bits_be(response), compute_key }
+    }
+}
+
+impl<A: Aleo> Signature<A> {
+    /// Returns the size in bits of a scalar and of a base field element.
+    fn component_sizes_in_bits() -> (usize, usize) {
+        (console::Scalar::<A::Network>::size_in_bits(), console::Field::<A::Network>::size_in_bits())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{test_crypto_rng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Generate a private key, compute key, view key, and address.
+        let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+
+        for i in 0..ITERATIONS {
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = console::Signature::sign(&private_key, &message, rng)?;
+            let candidate = Signature::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate_le = Signature::from_bits_le(&candidate.to_bits_le());
+                assert_eq!(expected, candidate_le.eject_value());
+
+                let candidate_be = Signature::from_bits_be(&candidate.to_bits_be());
+                assert_eq!(expected, candidate_be.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ToFields for Signature<A> {
+    type Field = Field<A>;
+
+    /// Returns the signature as a list of base field elements.
+    fn to_fields(&self) -> Vec<Self::Field> {
+        vec![
+            self.challenge.to_field(),
+            self.response.to_field(),
+            self.compute_key.pk_sig().to_x_coordinate(),
+            self.compute_key.pr_sig().to_x_coordinate(),
+        ]
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{test_crypto_rng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_fields(mode: Mode) -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Generate a private key, compute key, view key, and address.
+        let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+
+        for i in 0..ITERATIONS {
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = console::Signature::sign(&private_key, &message, rng)?;
+            let candidate = Signature::<Circuit>::new(mode, signature);
+
+            // Compute the expected field elements.
+            let expected = [
+                console::Field::from_bits_le(&signature.challenge().to_bits_le())?,
+                console::Field::from_bits_le(&signature.response().to_bits_le())?,
+                signature.compute_key().pk_sig().to_x_coordinate(),
+                signature.compute_key().pr_sig().to_x_coordinate(),
+            ];
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = candidate.to_fields();
+                assert_eq!(expected.to_vec(), candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_fields_constant() -> Result<()> {
+        check_to_fields(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_fields_public() -> Result<()> {
+        check_to_fields(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_fields_private() -> Result<()> {
+        check_to_fields(Mode::Private)
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod nonnative;
+pub use nonnative::NonNativeField;
+
+mod verify;
+
+use snarkvm_circuit_types::prelude::*;
+use snarkvm_curves::{
+    secp256k1::{Fq, Fr, Secp256k1Affine},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::FieldParameters;
+use snarkvm_utilities::biginteger::{BigInteger, BigInteger256};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// An affine point on secp256k1, with coordinates in the non-native base field.
+type Point<E> = (NonNativeField<E, Fq>, NonNativeField<E, Fq>);
+
+/// ECDSA signature verification over the secp256k1 curve, as used by Ethereum and Bitcoin.
+///
+/// The public key, digest, and signature are read as big-endian byte strings, with each byte
+/// in little-endian bit order, such that verifying `bytes.to_bits_le()` for the 64-byte public key
+/// `x || y`, the 32-byte digest, and the 64-byte signature `r || s` follows the standard encoding.
+pub struct ECDSASecp256k1<E: Environment> {
+    /// The generator `G`.
+    generator: Point<E>,
+    /// The offset point `H`, which keeps the accumulator of the scalar multiplication away from zero.
+    offset: Point<E>,
+    /// The point `G + H`.
+    generator_plus_offset: Point<E>,
+    /// The point `-(2^256 - 1) * H`, which removes the offsets from the scalar multiplication.
+    correction: Point<E>,
+    /// The point `2 * G`, which is substituted for an invalid public key.
+    default_public_key: Point<E>,
+}
+
+impl<E: Environment> Inject for ECDSASecp256k1<E> {
+    type Primitive = console::ECDSASecp256k1;
+
+    /// Initializes a new instance of ECDSA over secp256k1 with the given offset point.
+    fn new(_mode: Mode, ecdsa: Self::Primitive) -> Self {
+        let generator = ecdsa.generator();
+        let offset = ecdsa.offset();
+
+        // Compute `G + H`.
+        let generator_plus_offset = (generator.to_projective() + offset.to_projective()).to_affine();
+        // Compute `-(2^256 - 1) * H` as `H - 2^256 * H`.
+        let mut shifted_offset = offset.to_projective();
+        (0..256).for_each(|_| shifted_offset.double_in_place());
+        let correction = (offset.to_projective() - shifted_offset).to_affine();
+        // Compute `2 * G`.
+        let default_public_key = generator.to_projective().double().to_affine();
+
+        // Ensure the constants avoid the exceptional cases of the incomplete addition.
+        if generator.x == offset.x || generator.x == correction.x {
+            E::halt("The offset point for ECDSA over secp256k1 is degenerate")
+        }
+
+        let constant = |point: Secp256k1Affine| (NonNativeField::constant(point.x), NonNativeField::constant(point.y));
+        Self {
+            generator: constant(generator),
+            offset: constant(offset),
+            generator_plus_offset: constant(generator_plus_offset),
+            correction: constant(correction),
+            default_public_key: constant(default_public_key),
+        }
+    }
+}
+
+/// Returns `first` if `condition` is `true`, otherwise returns `second`.
+fn ternary<E: Environment>(condition: &Boolean<E>, first: &Point<E>, second: &Point<E>) -> Point<E> {
+    (NonNativeField::ternary(condition, &first.0, &second.0), NonNativeField::ternary(condition, &first.1, &second.1))
+}
+
+/// Returns the sum of the given points, and enforces that their x-coordinates are distinct.
+fn add<E: Environment>((x1, y1): &Point<E>, (x2, y2): &Point<E>) -> Point<E> {
+    let one = NonNativeField::one();
+    let mode = witness_mode!(x1, y1, x2, y2);
+    let (x1_value, y1_value) = (x1.eject_value(), y1.eject_value());
+    let (x2_value, y2_value) = (x2.eject_value(), y2.eject_value());
+
+    // Ensure `x2 - x1` is nonzero, by enforcing `(x2 - x1) * inverse - 1 == 0`.
+    let inverse_value = (x2_value - x1_value).inverse().unwrap_or_else(Fq::zero);
+    let inverse = NonNativeField::new(mode, inverse_value);
+    NonNativeField::enforce_relation(&[(1, x2, &inverse), (-1, x1, &inverse), (-1, &one, &one)]);
+
+    // Compute `lambda := (y2 - y1) / (x2 - x1)`, and enforce `lambda * (x2 - x1) - (y2 - y1) == 0`.
+    let lambda_value = (y2_value - y1_value) * inverse_value;
+    let lambda = NonNativeField::new(mode, lambda_value);
+    NonNativeField::enforce_relation(&[(1, &lambda, x2), (-1, &lambda, x1), (-1, y2, &one), (1, y1, &one)]);
+
+    // Compute `x3 := lambda^2 - x1 - x2`, and enforce `lambda^2 - x1 - x2 - x3 == 0`.
+    let x3_value = lambda_value.square() - x1_value - x2_value;
+    let x3 = NonNativeField::new(mode, x3_value);
+    NonNativeField::enforce_relation(&[(1, &lambda, &lambda), (-1, x1, &one), (-1, x2, &one), (-1, &x3, &one)]);
+
+    // Compute `y3 := lambda * (x1 - x3) - y1`, and enforce `lambda * (x1 - x3) - y1 - y3 == 0`.
+    let y3 = NonNativeField::new(mode, lambda_value * (x1_value - x3_value) - y1_value);
+    NonNativeField::enforce_relation(&[(1, &lambda, x1), (-1, &lambda, &x3), (-1, y1, &one), (-1, &y3, &one)]);
+
+    (x3, y3)
+}
+
+/// Returns the double of the given point.
+/// Note: The y-coordinate must be nonzero, which holds for every point on secp256k1.
+fn double<E: Environment>((x, y): &Point<E>) -> Point<E> {
+    let one = NonNativeField::one();
+    let mode = witness_mode!(x, y);
+    let (x_value, y_value) = (x.eject_value(), y.eject_value());
+
+    // Compute `lambda := 3 * x^2 / (2 * y)`, and enforce `2 * lambda * y - 3 * x^2 == 0`.
+    let lambda_value = x_value.square().double() + x_value.square();
+    let lambda_value = lambda_value * y_value.double().inverse().unwrap_or_else(Fq::zero);
+    let lambda = NonNativeField::new(mode, lambda_value);
+    NonNativeField::enforce_relation(&[(2, &lambda, y), (-3, x, x)]);
+
+    // Compute `x3 := lambda^2 - 2 * x`, and enforce `lambda^2 - 2 * x - x3 == 0`.
+    let x3_value = lambda_value.square() - x_value.double();
+    let x3 = NonNativeField::new(mode, x3_value);
+    NonNativeField::enforce_relation(&[(1, &lambda, &lambda), (-2, x, &one), (-1, &x3, &one)]);
+
+    // Compute `y3 := lambda * (x - x3) - y`, and enforce `lambda * (x - x3) - y - y3 == 0`.
+    let y3 = NonNativeField::new(mode, lambda_value * (x_value - x3_value) - y_value);
+    NonNativeField::enforce_relation(&[(1, &lambda, x), (-1, &lambda, &x3), (-1, y, &one), (-1, &y3, &one)]);
+
+    (x3, y3)
+}
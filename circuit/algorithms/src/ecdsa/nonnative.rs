@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits in each limb of a non-native field element.
+const LIMB_SIZE_IN_BITS: usize = 64;
+/// The number of limbs in a non-native field element.
+const NUM_LIMBS: usize = 4;
+/// The maximum sum of the absolute values of the coefficients in a relation.
+const MAX_COEFFICIENT_SUM: u64 = 16;
+/// The number of bits in the offset quotient of a relation, which is nonnegative and less than `2^262`.
+const QUOTIENT_SIZE_IN_BITS: usize = 262;
+/// The number of bits in the offset carry of a relation, which is nonnegative and less than `2^74`.
+const CARRY_SIZE_IN_BITS: usize = 74;
+
+/// A field element of the prime field `F`, emulated in the base field of the environment.
+///
+/// The element is stored as four 64-bit limbs in little-endian order.
+/// Every element allocated by `new` is enforced to be less than the modulus of `F`,
+/// while an element from `from_bits_le` may represent any 256-bit integer.
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, F: PrimeField<BigInteger = BigInteger256>> {
+    /// The little-endian limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _phantom: PhantomData<F>,
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Inject for NonNativeField<E, F> {
+    type Primitive = F;
+
+    /// Initializes a non-native field element, and enforces that it is less than the modulus.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        // Allocate the little-endian bits of the value.
+        let repr = value.to_repr();
+        let bits_le =
+            (0..NUM_LIMBS * LIMB_SIZE_IN_BITS).map(|i| Boolean::new(mode, repr.get_bit(i))).collect::<Vec<_>>();
+        // Ensure the value is less than the modulus.
+        E::assert(Self::is_less_than_modulus(&bits_le));
+        // Construct the limbs from the bits.
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Eject for NonNativeField<E, F> {
+    type Primitive = F;
+
+    /// Ejects the mode of the non-native field element.
+    fn eject_mode(&self) -> Mode {
+        self.limbs.eject_mode()
+    }
+
+    /// Ejects the non-native field element, reduced by the modulus.
+    fn eject_value(&self) -> Self::Primitive {
+        let value = self.to_integer() % F::Parameters::MODULUS.to_biguint();
+        match F::from_repr(to_big_integer(&value)) {
+            Some(value) => value,
+            None => E::halt("Failed to eject a non-native field element"),
+        }
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Mul<&NonNativeField<E, F>> for &NonNativeField<E, F> {
+    type Output = NonNativeField<E, F>;
+
+    /// Returns `self * other`, reduced by the modulus.
+    fn mul(self, other: &NonNativeField<E, F>) -> Self::Output {
+        let output = NonNativeField::new(witness_mode!(self, other), self.eject_value() * other.eject_value());
+        // Ensure `self * other - output == 0`.
+        NonNativeField::enforce_relation(&[(1, self, other), (-1, &output, &NonNativeField::one())]);
+        output
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Inverse for NonNativeField<E, F> {
+    type Output = Self;
+
+    /// Returns the inverse of `self`, and enforces that `self` is nonzero.
+    fn inverse(&self) -> Self::Output {
+        let output = Self::new(witness_mode!(self), self.eject_value().inverse().unwrap_or_else(F::zero));
+        // Ensure `self * output - 1 == 0`.
+        let one = Self::one();
+        Self::enforce_relation(&[(1, self, &output), (-1, &one, &one)]);
+        output
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Equal<Self> for NonNativeField<E, F> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    /// Note: Both elements must be less than the modulus.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .fold(Boolean::constant(true), |is_equal, (this, that)| is_equal & this.is_equal(that))
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    /// Note: Both elements must be less than the modulus.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> Ternary for NonNativeField<E, F> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let limbs =
+            first.limbs.iter().zip_eq(&second.limbs).map(|(first, second)| Field::ternary(condition, first, second));
+        Self { limbs: limbs.collect(), _phantom: PhantomData }
+    }
+}
+
+impl<E: Environment, F: PrimeField<BigInteger = BigInteger256>> NonNativeField<E, F> {
+    /// Initializes a non-native field element from 256 little-endian bits.
+    /// Note: The element is *not* enforced to be less than the modulus.
+    pub fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is correct.
+        if bits_le.len() != NUM_LIMBS * LIMB_SIZE_IN_BITS {
+            E::halt(format!("Expected {} bits for a non-native field element", NUM_LIMBS * LIMB_SIZE_IN_BITS))
+        }
+        // Construct each limb as a linear combination of its bits.
+        let limbs = bits_le.chunks(LIMB_SIZE_IN_BITS).map(Field::from_bits_le).collect();
+        Self { limbs, _phantom: PhantomData }
+    }
+
+    /// Returns `true` if the given 256 little-endian bits encode an integer less than the modulus.
+    pub fn is_less_than_modulus(bits_le: &[Boolean<E>]) -> Boolean<E> {
+        // Compute `!((MODULUS - 1) < bits_le)`, which is equivalent to `bits_le < MODULUS`.
+        let mut modulus_minus_one = F::Parameters::MODULUS;
+        modulus_minus_one.sub_noborrow(&BigInteger256::from(1));
+        !(0..NUM_LIMBS * LIMB_SIZE_IN_BITS).zip_eq(bits_le).fold(Boolean::constant(false), |rest_is_less, (i, that)| {
+            if modulus_minus_one.get_bit(i) { that.bitand(&rest_is_less) } else { that.bitor(&rest_is_less) }
+        })
+    }
+
+    /// Returns the little-endian bits of the element.
+    pub fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect()
+    }
+
+    /// Returns a new element that is less than the modulus, and is congruent to `self`.
+    pub fn reduce(&self) -> Self {
+        let one = Self::one();
+        let output = Self::new(witness_mode!(self), self.eject_value());
+        // Ensure `self - output == 0`.
+        Self::enforce_relation(&[(1, self, &one), (-1, &output, &one)]);
+        output
+    }
+
+    /// Returns the constant one.
+    pub fn one() -> Self {
+        Self::constant(F::one())
+    }
+
+    ///
+    /// Enforces that `Σ coefficient * lhs * rhs` is congruent to zero modulo the modulus.
+    ///
+    /// The relation is checked over the integers by witnessing the quotient `q` of the sum by the modulus,
+    /// and enforcing `Σ coefficient * lhs * rhs - q * modulus == 0` with a carry chain over the limbs.
+    ///
+    pub fn enforce_relation(terms: &[(i64, &Self, &Self)]) {
+        // Ensure the coefficients are small enough for the quotient and carries to be within range.
+        if terms.iter().map(|(coefficient, _, _)| coefficient.unsigned_abs()).sum::<u64>() > MAX_COEFFICIENT_SUM {
+            E::halt(format!("The coefficients of a non-native relation must sum to at most {MAX_COEFFICIENT_SUM}"))
+        }
+
+        // Retrieve the modulus.
+        let modulus = F::Parameters::MODULUS.to_biguint();
+        // Set the quotient offset to `2^(QUOTIENT_SIZE_IN_BITS - 1)`, as `|q| < 2^261`.
+        let quotient_offset = BigUint::from(1u64) << (QUOTIENT_SIZE_IN_BITS - 1);
+
+        // Compute the quotient, offset to be nonnegative.
+        let sum = terms.iter().fold(BigInt::default(), |sum, (coefficient, lhs, rhs)| {
+            sum + BigInt::from(*coefficient) * BigInt::from(lhs.to_integer()) * BigInt::from(rhs.to_integer())
+        });
+        let quotient = (sum / BigInt::from(modulus.clone()) + BigInt::from(quotient_offset.clone()))
+            .to_biguint()
+            .unwrap_or_default();
+        let mut quotient_limbs = quotient.to_u64_digits();
+        quotient_limbs.resize((QUOTIENT_SIZE_IN_BITS + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS, 0);
+
+        // Determine the mode of the witnesses.
+        let mode = match terms.iter().all(|(_, lhs, rhs)| lhs.is_constant() && rhs.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Allocate the quotient limbs, and ensure each limb is within range.
+        let quotient = quotient_limbs
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let limb = Field::new(mode, console::Field::from_u64(*limb));
+                limb.to_lower_bits_le(LIMB_SIZE_IN_BITS.min(QUOTIENT_SIZE_IN_BITS - i * LIMB_SIZE_IN_BITS));
+                limb
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the columns of `Σ coefficient * lhs * rhs - (q - offset) * modulus`.
+        let modulus_limbs = to_big_integer(&modulus).0;
+        let offset_limbs = (quotient_offset * &modulus).to_u64_digits();
+        let num_columns = (quotient.len() + NUM_LIMBS - 1).max(2 * NUM_LIMBS - 1).max(offset_limbs.len());
+        let mut columns = vec![Field::<E>::zero(); num_columns];
+        for (coefficient, lhs, rhs) in terms {
+            let coefficient = match *coefficient < 0 {
+                true => -console::Field::from_u64(coefficient.unsigned_abs()),
+                false => console::Field::from_u64(coefficient.unsigned_abs()),
+            };
+            for (i, this) in lhs.limbs.iter().enumerate() {
+                for (j, that) in rhs.limbs.iter().enumerate() {
+                    columns[i + j] += (this * that) * Field::constant(coefficient);
+                }
+            }
+        }
+        for (i, limb) in quotient.iter().enumerate() {
+            for (j, modulus_limb) in modulus_limbs.iter().enumerate() {
+                columns[i + j] -= limb * Field::constant(console::Field::from_u64(*modulus_limb));
+            }
+        }
+        for (i, offset_limb) in offset_limbs.iter().enumerate() {
+            columns[i] += Field::constant(console::Field::from_u64(*offset_limb));
+        }
+
+        // Enforce that the columns sum to zero, propagating a signed carry between each pair of columns.
+        // Each column is less than `2^135` in absolute value, so each pair is less than `2^200`,
+        // and each carry is less than `2^73`, which leaves ample room in the base field.
+        let two_to_64 = Field::<E>::constant(console::Field::from_u128(1u128 << LIMB_SIZE_IN_BITS));
+        let shift_inverse = (&two_to_64 * &two_to_64).inverse();
+        let carry_offset = Field::<E>::constant(console::Field::from_u128(1u128 << (CARRY_SIZE_IN_BITS - 1)));
+        let num_pairs = (columns.len() + 1) / 2;
+        let mut carry = Field::zero();
+        for (i, pair) in columns.chunks(2).enumerate() {
+            let mut sum = &pair[0] + &carry;
+            if let Some(column) = pair.get(1) {
+                sum += column * &two_to_64;
+            }
+            match i + 1 == num_pairs {
+                // Ensure the final sum is zero.
+                true => E::assert_eq(&sum, Field::<E>::zero()),
+                // Ensure the carry is an integer within range.
+                false => {
+                    carry = sum * &shift_inverse;
+                    (&carry + &carry_offset).to_lower_bits_le(CARRY_SIZE_IN_BITS);
+                }
+            }
+        }
+    }
+
+    /// Returns the integer encoded by the limbs, which may not be less than the modulus.
+    fn to_integer(&self) -> BigUint {
+        self.limbs.iter().rev().fold(BigUint::default(), |integer, limb| {
+            let limb = limb
+                .eject_value()
+                .to_bits_le()
+                .iter()
+                .take(LIMB_SIZE_IN_BITS)
+                .rev()
+                .fold(0u64, |limb, bit| (limb << 1) | *bit as u64);
+            (integer << LIMB_SIZE_IN_BITS) + limb
+        })
+    }
+}
+
+/// Returns the given integer as a 256-bit integer, truncating any higher bits.
+fn to_big_integer(value: &BigUint) -> BigInteger256 {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(NUM_LIMBS, 0);
+    BigInteger256([limbs[0], limbs[1], limbs[2], limbs[3]])
+}
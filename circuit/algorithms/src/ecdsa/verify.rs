@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ECDSASecp256k1<E> {
+    /// Returns `true` if the signature is valid for the given public key and digest.
+    ///
+    /// Returns `false` if the public key is not a point on the curve,
+    /// or if either half of the signature is not in `[1, n - 1]`.
+    ///
+    /// The scalar multiplication uses incomplete addition, so the circuit is unsatisfiable in the rare
+    /// case that an intermediate sum doubles a point; this requires a relation to the offset point.
+    pub fn verify(&self, public_key: &[Boolean<E>], digest: &[Boolean<E>], signature: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected sizes.
+        if public_key.len() != 512 {
+            E::halt(format!("ECDSA public key must be 512 bits, found {} bits", public_key.len()))
+        }
+        if digest.len() != 256 {
+            E::halt(format!("ECDSA digest must be 256 bits, found {} bits", digest.len()))
+        }
+        if signature.len() != 512 {
+            E::halt(format!("ECDSA signature must be 512 bits, found {} bits", signature.len()))
+        }
+
+        // Parse the public key, and ensure it is on the curve.
+        let (x_bits, y_bits) = (to_bits_le(&public_key[..256]), to_bits_le(&public_key[256..]));
+        let (x, y) = (NonNativeField::from_bits_le(&x_bits), NonNativeField::from_bits_le(&y_bits));
+        let is_valid_public_key = NonNativeField::<E, Fq>::is_less_than_modulus(&x_bits)
+            & NonNativeField::<E, Fq>::is_less_than_modulus(&y_bits)
+            & is_on_curve(&x, &y);
+        // Substitute a valid public key, if the given public key is invalid.
+        let public_key = ternary(&is_valid_public_key, &(x, y), &self.default_public_key);
+
+        // Parse the signature, and ensure both halves are in `[1, n - 1]`.
+        let (r_bits, s_bits) = (to_bits_le(&signature[..256]), to_bits_le(&signature[256..]));
+        let is_valid_r = is_nonzero(&r_bits) & NonNativeField::<E, Fr>::is_less_than_modulus(&r_bits);
+        let is_valid_s = is_nonzero(&s_bits) & NonNativeField::<E, Fr>::is_less_than_modulus(&s_bits);
+        // Substitute a valid signature, if the given signature is invalid.
+        let one = NonNativeField::one();
+        let r = NonNativeField::ternary(&is_valid_r, &NonNativeField::from_bits_le(&r_bits), &one);
+        let s = NonNativeField::ternary(&is_valid_s, &NonNativeField::from_bits_le(&s_bits), &one);
+
+        // Reduce the digest into the scalar field.
+        let z = NonNativeField::<E, Fr>::from_bits_le(&to_bits_le(digest)).reduce();
+
+        // Compute `u1 := z / s` and `u2 := r / s`.
+        let s_inverse = s.inverse();
+        let u1 = (&z * &s_inverse).to_bits_le();
+        let u2 = (&r * &s_inverse).to_bits_le();
+
+        // Construct the table of `H + b1 * G + b2 * Q`, for each pair of bits `(b1, b2)`.
+        let offset_plus_public_key = add(&self.offset, &public_key);
+        let table = [
+            self.offset.clone(),
+            self.generator_plus_offset.clone(),
+            offset_plus_public_key,
+            add(&self.generator_plus_offset, &public_key),
+        ];
+        let select = |b1: &Boolean<E>, b2: &Boolean<E>| {
+            ternary(b2, &ternary(b1, &table[3], &table[2]), &ternary(b1, &table[1], &table[0]))
+        };
+
+        // Compute `(2^256 - 1) * H + u1 * G + u2 * Q` with Shamir's trick, from the most significant bit.
+        let mut accumulator = select(&u1[255], &u2[255]);
+        for i in (0..255).rev() {
+            accumulator = add(&double(&accumulator), &select(&u1[i], &u2[i]));
+        }
+
+        // Remove the offsets, to compute `R := u1 * G + u2 * Q`.
+        // If `R` is zero, substitute the accumulator with the generator, and reject the signature.
+        let is_zero = accumulator.0.is_equal(&self.correction.0);
+        let accumulator = ternary(&is_zero, &self.generator, &accumulator);
+        let (x, _) = add(&accumulator, &self.correction);
+
+        // Ensure the x-coordinate of `R`, reduced into the scalar field, is equal to `r`.
+        let is_valid_signature = NonNativeField::<E, Fr>::from_bits_le(&x.to_bits_le()).reduce().is_equal(&r);
+
+        is_valid_public_key & is_valid_r & is_valid_s & !is_zero & is_valid_signature
+    }
+}
+
+/// Returns the little-endian bits of the integer encoded by the given big-endian bytes,
+/// where each byte is in little-endian bit order.
+fn to_bits_le<E: Environment>(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    bits.chunks(8).rev().flatten().cloned().collect()
+}
+
+/// Returns `true` if any of the given bits are set.
+fn is_nonzero<E: Environment>(bits: &[Boolean<E>]) -> Boolean<E> {
+    bits.iter().fold(Boolean::constant(false), |is_nonzero, bit| is_nonzero | bit)
+}
+
+/// Returns `true` if the given coordinates satisfy `y^2 == x^3 + 7`.
+fn is_on_curve<E: Environment>(x: &NonNativeField<E, Fq>, y: &NonNativeField<E, Fq>) -> Boolean<E> {
+    let one = NonNativeField::one();
+    let seven = NonNativeField::constant(Fq::from(7u64));
+
+    // Compute `x^3 + 7`, and enforce `x * x^2 + 7 - rhs == 0`.
+    let x_squared = x * x;
+    let rhs = NonNativeField::new(witness_mode!(x), x_squared.eject_value() * x.eject_value() + Fq::from(7u64));
+    NonNativeField::enforce_relation(&[(1, x, &x_squared), (1, &seven, &one), (-1, &rhs, &one)]);
+
+    (y * y).is_equal(&rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, ToBytes, Uniform};
+
+    use anyhow::Result;
+
+    /// Returns the little-endian bits of the big-endian bytes of the given field element.
+    fn to_bits_be_bytes<F: PrimeField>(element: &F) -> Result<Vec<bool>> {
+        let bytes = element.to_repr().to_bytes_le()?;
+        Ok(bytes.iter().rev().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect())
+    }
+
+    /// Returns the public key, digest, and signature for a randomly sampled key and digest.
+    fn sample_signature() -> Result<(Vec<bool>, Vec<bool>, Vec<bool>)> {
+        let rng = &mut test_rng();
+        let private_key = Fr::rand(rng);
+        let nonce = Fr::rand(rng);
+        let digest = Fr::rand(rng);
+
+        // Compute the public key.
+        let public_key = (Secp256k1Affine::prime_subgroup_generator() * private_key).to_affine();
+        // Compute the signature as `r := (k * G).x` and `s := (z + r * d) / k`.
+        let r = Fr::from_bytes_le_mod_order(
+            &(Secp256k1Affine::prime_subgroup_generator() * nonce).to_affine().x.to_repr().to_bytes_le()?,
+        );
+        let s = (digest + r * private_key) * nonce.inverse().unwrap();
+
+        let public_key = [to_bits_be_bytes(&public_key.x)?, to_bits_be_bytes(&public_key.y)?].concat();
+        let signature = [to_bits_be_bytes(&r)?, to_bits_be_bytes(&s)?].concat();
+        Ok((public_key, to_bits_be_bytes(&digest)?, signature))
+    }
+
+    fn check_verify(mode: Mode, public_key: &[bool], digest: &[bool], signature: &[bool]) -> Result<()> {
+        // Initialize ECDSA.
+        let native = console::ECDSASecp256k1::setup("ECDSACircuit0");
+        let circuit = ECDSASecp256k1::<Circuit>::constant(native.clone());
+
+        // Compute the expected result.
+        let expected = native.verify(public_key, digest, signature)?;
+        // Prepare the circuit inputs.
+        let circuit_public_key: Vec<Boolean<_>> = Inject::new(mode, public_key.to_vec());
+        let circuit_digest: Vec<Boolean<_>> = Inject::new(mode, digest.to_vec());
+        let circuit_signature: Vec<Boolean<_>> = Inject::new(mode, signature.to_vec());
+
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate = circuit.verify(&circuit_public_key, &circuit_digest, &circuit_signature);
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode.is_constant(), candidate.is_constant());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    fn check_verify_all(mode: Mode) -> Result<()> {
+        let (public_key, digest, signature) = sample_signature()?;
+
+        // Ensure a valid signature verifies.
+        check_verify(mode, &public_key, &digest, &signature)?;
+
+        // Ensure a different digest fails to verify.
+        let mut candidate = digest.clone();
+        candidate[0] = !candidate[0];
+        check_verify(mode, &public_key, &candidate, &signature)?;
+
+        // Ensure a public key that is not on the curve fails to verify.
+        let mut candidate = public_key.clone();
+        candidate[0] = !candidate[0];
+        check_verify(mode, &candidate, &digest, &signature)?;
+
+        // Ensure a signature that is out of range fails to verify.
+        let candidate = [signature[..256].to_vec(), vec![true; 256]].concat();
+        check_verify(mode, &public_key, &digest, &candidate)
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify_all(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify_all(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify_all(Mode::Private)
+    }
+}
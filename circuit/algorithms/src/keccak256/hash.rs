@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Keccak256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the Keccak-256 digest of the given input, as 256 bits.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Pad the message with the `pad10*1` rule, to a multiple of the rate.
+        // As the message length is known, the padding bits are constants.
+        let mut message = input.to_vec();
+        message.push(Boolean::constant(true));
+        let num_padding_bits = (self.rate - 1 - message.len() % self.rate) % self.rate;
+        message.resize(message.len() + num_padding_bits, Boolean::constant(false));
+        message.push(Boolean::constant(true));
+
+        // Absorb each block of the message into the state.
+        let mut state = vec![U64::zero(); 25];
+        for block in message.chunks(self.rate) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane ^= U64::from_bits_le(bits);
+            }
+            state = self.permute(state);
+        }
+
+        // Squeeze the first 256 bits of the state, in little-endian bits per lane.
+        state.iter().take(4).flat_map(|lane| lane.to_bits_le()).collect()
+    }
+}
+
+impl<E: Environment> Keccak256<E> {
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(&self, mut state: Vec<U64<E>>) -> Vec<U64<E>> {
+        for round_constant in &self.round_constants {
+            // Theta
+            let c = (0..5).map(|x| (1..5).fold(state[x].clone(), |acc, y| acc ^ &state[x + 5 * y])).collect::<Vec<_>>();
+            let d = (0..5).map(|x| &c[(x + 4) % 5] ^ rotate_left(&c[(x + 1) % 5], 1)).collect::<Vec<_>>();
+            for (i, lane) in state.iter_mut().enumerate() {
+                *lane ^= &d[i % 5];
+            }
+
+            // Rho and Pi
+            let mut b = state.clone();
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = rotate_left(&state[x + 5 * y], self.rotation_offsets[x + 5 * y]);
+                }
+            }
+
+            // Chi
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = &b[x + 5 * y] ^ (!&b[(x + 1) % 5 + 5 * y] & &b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+
+            // Iota
+            state[0] ^= round_constant;
+        }
+        state
+    }
+}
+
+/// Returns the given lane rotated left by `n` bits.
+fn rotate_left<E: Environment>(lane: &U64<E>, n: usize) -> U64<E> {
+    let mut bits_le = lane.to_bits_le();
+    bits_le.rotate_right(n);
+    U64::from_bits_le(&bits_le)
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    fn check_hash(mode: Mode, num_bits: usize) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize Keccak-256.
+        let native = console::Keccak256;
+        let circuit = Keccak256::<Circuit>::constant(native);
+
+        // Sample a random input.
+        let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+        // Compute the expected hash.
+        let expected = native.hash(&input)?;
+        // Prepare the circuit input.
+        let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+        Circuit::scope(format!("Keccak-256 {mode} {num_bits}"), || {
+            // Perform the hash operation.
+            let candidate = circuit.hash(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the output is a constant if the input is a constant.
+            assert_eq!(mode.is_constant() || num_bits == 0, candidate.iter().all(|bit| bit.is_constant()));
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        for num_bits in [0, 8, 253, 1087, 1088, 2048] {
+            check_hash(Mode::Constant, num_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        for num_bits in [0, 8, 253, 1087, 1088, 2048] {
+            check_hash(Mode::Public, num_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        for num_bits in [0, 8, 253, 1087, 1088, 2048] {
+            check_hash(Mode::Private, num_bits)?;
+        }
+        Ok(())
+    }
+}
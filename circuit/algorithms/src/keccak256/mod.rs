@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+/// Keccak-256 is the Keccak sponge function with a 256-bit digest, as used in Ethereum.
+/// Note that this is the original Keccak padding, which differs from the NIST SHA3-256 standard.
+///
+/// The input bits are read as bytes in little-endian bit order, and the digest bytes are returned
+/// in the same order, such that hashing `bytes.to_bits_le()` returns `keccak256(bytes).to_bits_le()`.
+pub struct Keccak256<E: Environment> {
+    /// The round constants for the permutation.
+    round_constants: Vec<U64<E>>,
+    /// The rotation offsets for the permutation, indexed by `x + 5 * y`.
+    rotation_offsets: Vec<usize>,
+    /// The number of bits absorbed into the state per permutation.
+    rate: usize,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Keccak256<E> {
+    type Primitive = console::Keccak256;
+
+    /// Initializes a new instance of Keccak-256 with the given constants.
+    fn new(_mode: Mode, keccak: Self::Primitive) -> Self {
+        // Initialize the round constants.
+        let round_constants =
+            keccak.round_constants().iter().map(|constant| U64::constant(console::U64::new(*constant))).collect();
+        // Initialize the rotation offsets.
+        let rotation_offsets = keccak.rotation_offsets().iter().map(|offset| *offset as usize).collect();
+
+        Self { round_constants, rotation_offsets, rate: keccak.rate() }
+    }
+}
@@ -20,14 +20,25 @@
 pub mod bhp;
 pub use bhp::*;
 
+#[cfg(console)]
+pub mod ecdsa;
+#[cfg(console)]
+pub use ecdsa::*;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod keccak256;
+pub use keccak256::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 pub mod traits;
 pub use traits::*;
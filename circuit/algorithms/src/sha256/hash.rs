@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 digest of the given input, as 256 bits.
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Reorder the input bits into big-endian bits per byte, as SHA-256 is defined over big-endian bits.
+        let mut message = input.chunks(8).flat_map(|byte| byte.iter().rev().cloned()).collect::<Vec<_>>();
+
+        // Pad the message with a `1` bit, zero bits, and the 64-bit message length, to a multiple of 512 bits.
+        // As the message length is known, the padding bits are constants.
+        let num_bits = message.len() as u64;
+        message.push(Boolean::constant(true));
+        message.resize(message.len() + (448 + 512 - message.len() % 512) % 512, Boolean::constant(false));
+        message.extend((0..64).rev().map(|i| Boolean::constant((num_bits >> i) & 1 == 1)));
+
+        // Compress each 512-bit block into the state.
+        let mut state = self.initial_state.clone();
+        for block in message.chunks(512) {
+            // Construct the message schedule.
+            let mut schedule = block.chunks(32).map(U32::from_bits_be).collect::<Vec<_>>();
+            for t in 16..64 {
+                let s0 = rotate_right(&schedule[t - 15], 7)
+                    ^ rotate_right(&schedule[t - 15], 18)
+                    ^ shift_right(&schedule[t - 15], 3);
+                let s1 = rotate_right(&schedule[t - 2], 17)
+                    ^ rotate_right(&schedule[t - 2], 19)
+                    ^ shift_right(&schedule[t - 2], 10);
+                let word = add_many(&[schedule[t - 16].clone(), s0, schedule[t - 7].clone(), s1]);
+                schedule.push(word);
+            }
+
+            // Perform the rounds of the compression function.
+            let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+            let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+            for (round_constant, word) in self.round_constants.iter().zip_eq(&schedule) {
+                let s1 = rotate_right(&e, 6) ^ rotate_right(&e, 11) ^ rotate_right(&e, 25);
+                let ch = (&e & &f) ^ (!&e & &g);
+                let t1 = add_many(&[h, s1, ch, round_constant.clone(), word.clone()]);
+                let s0 = rotate_right(&a, 2) ^ rotate_right(&a, 13) ^ rotate_right(&a, 22);
+                let maj = (&a & &b) ^ (&a & &c) ^ (&b & &c);
+
+                h = g;
+                g = f;
+                f = e;
+                e = add_many(&[d, t1.clone()]);
+                d = c;
+                c = b;
+                b = a;
+                a = add_many(&[t1, s0, maj]);
+            }
+
+            // Add the compressed block into the state.
+            state = state
+                .iter()
+                .zip_eq([a, b, c, d, e, f, g, h])
+                .map(|(word, value)| add_many(&[word.clone(), value]))
+                .collect();
+        }
+
+        // Return the digest as bytes, in little-endian bits per byte.
+        state
+            .iter()
+            .flat_map(|word| {
+                let bits_le = word.to_bits_le();
+                (0..4).rev().flat_map(move |i| bits_le[8 * i..8 * (i + 1)].to_vec())
+            })
+            .collect()
+    }
+}
+
+/// Returns the given word rotated right by `n` bits.
+fn rotate_right<E: Environment>(word: &U32<E>, n: usize) -> U32<E> {
+    let mut bits_le = word.to_bits_le();
+    bits_le.rotate_left(n);
+    U32::from_bits_le(&bits_le)
+}
+
+/// Returns the given word shifted right by `n` bits.
+fn shift_right<E: Environment>(word: &U32<E>, n: usize) -> U32<E> {
+    let mut bits_le = word.to_bits_le().split_off(n);
+    bits_le.resize(32, Boolean::constant(false));
+    U32::from_bits_le(&bits_le)
+}
+
+/// Returns the sum of the given words, modulo 2^32.
+fn add_many<E: Environment>(words: &[U32<E>]) -> U32<E> {
+    // Sum the words as field elements, which is safe as the sum can not overflow the base field.
+    let sum = words.iter().fold(Field::zero(), |sum, word| sum + word.to_field());
+    // Extract the lower bits of the sum, with enough bits for the carry.
+    let num_carry_bits = (usize::BITS - words.len().saturating_sub(1).leading_zeros()) as usize;
+    let bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+    // Drop the carry bits, as the sum is wrapped.
+    U32::from_bits_le(&bits_le[..32])
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    fn check_hash(mode: Mode, num_bits: usize) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize SHA-256.
+        let native = console::Sha256;
+        let circuit = Sha256::<Circuit>::constant(native);
+
+        // Sample a random input.
+        let input = (0..num_bits).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+        // Compute the expected hash.
+        let expected = native.hash(&input)?;
+        // Prepare the circuit input.
+        let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+        Circuit::scope(format!("SHA-256 {mode} {num_bits}"), || {
+            // Perform the hash operation.
+            let candidate = circuit.hash(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the output is a constant if the input is a constant.
+            assert_eq!(mode.is_constant() || num_bits == 0, candidate.iter().all(|bit| bit.is_constant()));
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant() -> Result<()> {
+        for num_bits in [0, 8, 253, 512, 1024] {
+            check_hash(Mode::Constant, num_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_public() -> Result<()> {
+        for num_bits in [0, 8, 253, 512, 1024] {
+            check_hash(Mode::Public, num_bits)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_private() -> Result<()> {
+        for num_bits in [0, 8, 253, 512, 1024] {
+            check_hash(Mode::Private, num_bits)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_circuit_types::prelude::*;
+
+/// SHA-256 is the 256-bit variant of the SHA-2 hash function, as specified in FIPS 180-4.
+///
+/// The input bits are read as bytes in little-endian bit order, and the digest bytes are returned
+/// in the same order, such that hashing `bytes.to_bits_le()` returns `sha256(bytes).to_bits_le()`.
+pub struct Sha256<E: Environment> {
+    /// The round constants for the compression function.
+    round_constants: Vec<U32<E>>,
+    /// The initial state for the compression function.
+    initial_state: Vec<U32<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Sha256<E> {
+    type Primitive = console::Sha256;
+
+    /// Initializes a new instance of SHA-256 with the given constants.
+    fn new(_mode: Mode, sha256: Self::Primitive) -> Self {
+        // Initialize the round constants.
+        let round_constants =
+            sha256.round_constants().iter().map(|constant| U32::constant(console::U32::new(*constant))).collect();
+        // Initialize the initial state.
+        let initial_state =
+            sha256.initial_state().iter().map(|word| U32::constant(console::U32::new(*word))).collect();
+
+        Self { round_constants, initial_state }
+    }
+}
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_circuit_types::{
-    environment::{Eject, Inject, ScalarTrait, Ternary, ToBits},
+    environment::{Eject, Inject, ScalarTrait},
     GroupTrait,
 };
 
@@ -42,7 +42,7 @@ pub trait CommitUncompressed {
 /// A trait for a hash function.
 pub trait Hash {
     type Input: Inject + Eject + Clone;
-    type Output: Inject + Eject + Ternary<Output = Self::Output> + ToBits + Clone;
+    type Output: Inject + Eject + Clone;
 
     /// Returns the hash of the given input.
     fn hash(&self, input: &[Self::Input]) -> Self::Output;
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod leaf_hash;
+pub use leaf_hash::*;
+
+mod verify;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U64};
+
+pub struct IndexedMerklePath<E: Environment, const DEPTH: u8> {
+    /// The leaf index for the path.
+    leaf_index: U64<E>,
+    /// The key of the leaf.
+    leaf_key: Field<E>,
+    /// The next key of the leaf.
+    next_key: Field<E>,
+    /// The hash of the value of the leaf.
+    value_hash: Field<E>,
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Inject for IndexedMerklePath<E, DEPTH> {
+    type Primitive = console::indexed_merkle_tree::IndexedMerklePath<E::Network, DEPTH>;
+
+    /// Initializes an indexed Merkle path from the given mode and native indexed Merkle path.
+    fn new(mode: Mode, merkle_path: Self::Primitive) -> Self {
+        // Initialize the leaf index.
+        let leaf_index = U64::new(mode, merkle_path.leaf_index());
+        // Initialize the leaf key, next key, and value hash.
+        let leaf_key = Field::new(mode, merkle_path.leaf_key());
+        let next_key = Field::new(mode, merkle_path.next_key());
+        let value_hash = Field::new(mode, merkle_path.value_hash());
+        // Initialize the Merkle path siblings.
+        let siblings: Vec<_> = merkle_path.siblings().iter().map(|node| Field::new(mode, *node)).collect();
+        // Ensure the Merkle path is the correct depth.
+        match siblings.len() == DEPTH as usize {
+            // Return the Merkle path.
+            true => Self { leaf_index, leaf_key, next_key, value_hash, siblings },
+            false => E::halt("Indexed Merkle path is not the correct depth"),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Eject for IndexedMerklePath<E, DEPTH> {
+    type Primitive = console::indexed_merkle_tree::IndexedMerklePath<E::Network, DEPTH>;
+
+    /// Ejects the mode of the indexed Merkle path.
+    fn eject_mode(&self) -> Mode {
+        (&self.leaf_index, &self.leaf_key, &self.next_key, &self.value_hash, &self.siblings).eject_mode()
+    }
+
+    /// Ejects the indexed Merkle path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::try_from(
+            (&self.leaf_index, &self.leaf_key, &self.next_key, &self.value_hash, &self.siblings).eject_value(),
+        ) {
+            Ok(merkle_path) => merkle_path,
+            Err(error) => E::halt(format!("Failed to eject the indexed Merkle path: {error}")),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> IndexedMerklePath<E, DEPTH> {
+    /// Returns `true` if the indexed Merkle path proves the key holds the given value for the given root.
+    pub fn verify_membership<LH: IndexedLeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        value: &LH::Leaf,
+    ) -> Boolean<E> {
+        // Ensure the leaf is for the given key.
+        let is_leaf_key = self.leaf_key.is_equal(key);
+        // Compute the value hash, and check the path.
+        is_leaf_key & self.verify_leaf(leaf_hasher, path_hasher, root, &leaf_hasher.hash_leaf(value))
+    }
+
+    /// Returns `true` if the indexed Merkle path proves the key does not exist for the given root.
+    pub fn verify_non_membership<LH: IndexedLeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+    ) -> Boolean<E> {
+        // Ensure the leaf is the low leaf for the given key, meaning the key is strictly between
+        // the key of the leaf and its next key (or the leaf has the largest key in the tree).
+        let is_above_leaf_key = self.leaf_key.is_less_than(key);
+        let is_below_next_key = self.next_key.is_equal(&Field::zero()) | key.is_less_than(&self.next_key);
+        // Check the path.
+        is_above_leaf_key & is_below_next_key & self.verify_leaf(leaf_hasher, path_hasher, root, &self.value_hash)
+    }
+
+    /// Returns `true` if the indexed Merkle path is valid for the given root and value hash.
+    fn verify_leaf<LH: IndexedLeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        value_hash: &Field<E>,
+    ) -> Boolean<E> {
+        // Ensure the leaf index is within the tree depth.
+        if (*self.leaf_index.eject_value() as u128) >= (1u128 << DEPTH) {
+            E::halt("Found an out of bounds indexed Merkle leaf index")
+        }
+        // Ensure the path length matches the expected depth.
+        else if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect indexed Merkle path length")
+        }
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let mut current_hash = leaf_hasher.hash_indexed_leaf(&self.leaf_key, &self.next_key, value_hash);
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
+        // If the indicator bit is `false`, then the ordering is (sibling_hash, current_hash).
+        let indicators = self.leaf_index.to_bits_le().into_iter().take(DEPTH as usize).map(|b| !b);
+
+        // Check levels between leaf level and root.
+        for (indicator, sibling_hash) in indicators.zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let left = Field::ternary(&indicator, &current_hash, sibling_hash);
+            let right = Field::ternary(&indicator, sibling_hash, &current_hash);
+
+            // Update the current hash for the next level.
+            current_hash = path_hasher.hash_children(&left, &right);
+        }
+
+        // Ensure the final hash matches the given root.
+        root.is_equal(&current_hash)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 10;
+    const DOMAIN: &str = "IndexedMerkleCircuit0";
+
+    macro_rules! check_verify {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $num_inputs:expr) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            let create_value = || (0..$num_inputs).map(|_| Uniform::rand(&mut test_rng())).collect::<Vec<_>>();
+
+            // Construct the indexed Merkle tree.
+            let mut merkle_tree = console::indexed_merkle_tree::IndexedMerkleTree::<_, _, _, $depth>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+            )?;
+            let entries = (0..ITERATIONS).map(|_| (Uniform::rand(&mut test_rng()), create_value())).collect::<Vec<_>>();
+            for (key, value) in &entries[..ITERATIONS / 2] {
+                merkle_tree.insert(*key, value.clone())?;
+            }
+
+            // Initialize the Merkle root.
+            let root = Field::new(Mode::$mode, *merkle_tree.root());
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = root.clone() + Field::one();
+
+            // Check the keys that exist in the tree.
+            for (key, merkle_value) in &entries[..ITERATIONS / 2] {
+                // Compute the indexed Merkle path.
+                let merkle_path = merkle_tree.prove(key)?;
+
+                // Initialize the indexed Merkle path.
+                let path = IndexedMerklePath::<Circuit, $depth>::new(Mode::$mode, merkle_path.clone());
+                assert_eq!(merkle_path, path.eject_value());
+                // Initialize the key and value.
+                let key = Field::new(Mode::$mode, *key);
+                let value: Vec<_> = Inject::new(Mode::$mode, merkle_value.clone());
+
+                Circuit::scope(format!("Verify Membership {}", Mode::$mode), || {
+                    let candidate =
+                        path.verify_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key, &value);
+                    assert!(candidate.eject_value());
+                    let candidate = path.verify_membership(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &incorrect_root,
+                        &key,
+                        &value,
+                    );
+                    assert!(!candidate.eject_value());
+                    let candidate = path.verify_non_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+
+                // Initialize an incorrect value.
+                let mut incorrect_value = value.clone();
+                let mut incorrect_element = Uniform::rand(&mut test_rng());
+                while incorrect_element == incorrect_value[0].eject_value() {
+                    incorrect_element = Uniform::rand(&mut test_rng());
+                }
+                incorrect_value[0] = Inject::new(Mode::$mode, incorrect_element);
+
+                Circuit::scope(format!("Verify Membership (Incorrect Value) {}", Mode::$mode), || {
+                    let candidate = path.verify_membership(
+                        &circuit_leaf_hasher,
+                        &circuit_path_hasher,
+                        &root,
+                        &key,
+                        &incorrect_value,
+                    );
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+
+            // Check the keys that do not exist in the tree.
+            for (key, merkle_value) in &entries[ITERATIONS / 2..] {
+                // Compute the indexed Merkle path.
+                let merkle_path = merkle_tree.prove(key)?;
+
+                // Initialize the indexed Merkle path.
+                let path = IndexedMerklePath::<Circuit, $depth>::new(Mode::$mode, merkle_path.clone());
+                assert_eq!(merkle_path, path.eject_value());
+                // Initialize the key and value.
+                let key = Field::new(Mode::$mode, *key);
+                let value: Vec<_> = Inject::new(Mode::$mode, merkle_value.clone());
+
+                Circuit::scope(format!("Verify Non-Membership {}", Mode::$mode), || {
+                    let candidate = path.verify_non_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key);
+                    assert!(candidate.eject_value());
+                    let candidate =
+                        path.verify_non_membership(&circuit_leaf_hasher, &circuit_path_hasher, &incorrect_root, &key);
+                    assert!(!candidate.eject_value());
+                    let candidate =
+                        path.verify_membership(&circuit_leaf_hasher, &circuit_path_hasher, &root, &key, &value);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_verify_bhp512_constant() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Constant, 32, 1024)
+    }
+
+    #[test]
+    fn test_verify_bhp512_public() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Public, 32, 1024)
+    }
+
+    #[test]
+    fn test_verify_bhp512_private() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Private, 32, 1024)
+    }
+
+    #[test]
+    fn test_verify_poseidon2_constant() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Constant, 32, 4)
+    }
+
+    #[test]
+    fn test_verify_poseidon2_public() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Public, 32, 4)
+    }
+
+    #[test]
+    fn test_verify_poseidon2_private() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Private, 32, 4)
+    }
+}
@@ -17,4 +17,5 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod indexed_merkle_tree;
 pub mod merkle_tree;
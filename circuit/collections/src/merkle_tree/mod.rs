@@ -15,7 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
-use helpers::{LeafHash, PathHash};
+pub(crate) use helpers::{LeafHash, PathHash};
+
+mod multi_path;
+pub use multi_path::*;
 
 mod verify;
 
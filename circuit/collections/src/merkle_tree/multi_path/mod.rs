@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use super::*;
+
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in strictly increasing order.
+    /// As the leaf indices determine the shape of the circuit, they are always constants.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes that can not be computed from the leaves,
+    /// ordered by level from the leaves to the root, and by position within each level.
+    siblings: Vec<Field<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Inject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Initializes a Merkle multi-path from the given mode and native Merkle multi-path.
+    fn new(mode: Mode, merkle_path: Self::Primitive) -> Self {
+        // Initialize the leaf indices as constants.
+        let leaf_indices = merkle_path.leaf_indices().iter().map(|leaf_index| U64::constant(*leaf_index)).collect();
+        // Initialize the Merkle multi-path siblings.
+        let siblings = merkle_path.siblings().iter().map(|node| Field::new(mode, *node)).collect();
+        // Return the Merkle multi-path.
+        Self { leaf_indices, siblings }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const DEPTH: u8> Eject for MerkleMultiPath<E, DEPTH> {
+    type Primitive = console::merkle_tree::MerkleMultiPath<E::Network, DEPTH>;
+
+    /// Ejects the mode of the Merkle multi-path.
+    fn eject_mode(&self) -> Mode {
+        (&self.leaf_indices, &self.siblings).eject_mode()
+    }
+
+    /// Ejects the Merkle multi-path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::try_from((&self.leaf_indices, &self.siblings).eject_value()) {
+            Ok(merkle_path) => merkle_path,
+            Err(error) => E::halt(format!("Failed to eject the Merkle multi-path: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_network::AleoV0 as Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    fn check_new<const DEPTH: u8>(mode: Mode, num_leaves: usize, leaf_indices: &[usize]) -> Result<()> {
+        // Compute the leaves.
+        let leaves = (0..num_leaves)
+            .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut test_rng()).to_bits_le())
+            .collect::<Vec<_>>();
+        // Compute the Merkle tree.
+        let merkle_tree = <<Circuit as Environment>::Network as snarkvm_console_network::Network>::merkle_tree_bhp::<
+            DEPTH,
+        >(&leaves)?;
+
+        // Compute the Merkle multi-path.
+        let selected_leaves = leaf_indices.iter().map(|i| leaves[*i].clone()).collect::<Vec<_>>();
+        let merkle_path = merkle_tree.prove_multi(leaf_indices, &selected_leaves)?;
+
+        Circuit::scope(format!("New {mode}"), || {
+            let candidate = MerkleMultiPath::<Circuit, DEPTH>::new(mode, merkle_path.clone());
+            assert_eq!(merkle_path, candidate.eject_value());
+            assert!(candidate.leaf_indices.iter().all(|leaf_index| leaf_index.is_constant()));
+            assert_eq!(merkle_path.siblings().len(), candidate.siblings.len());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_constant() -> Result<()> {
+        check_new::<32>(Mode::Constant, 16, &[0, 3, 4, 5, 15])
+    }
+
+    #[test]
+    fn test_new_public() -> Result<()> {
+        check_new::<32>(Mode::Public, 16, &[0, 3, 4, 5, 15])
+    }
+
+    #[test]
+    fn test_new_private() -> Result<()> {
+        check_new::<32>(Mode::Private, 16, &[0, 3, 4, 5, 15])
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    pub fn verify<LH: LeafHash<E, Hash = PH::Hash>, PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> Boolean<E> {
+        // Ensure there is one leaf for each leaf index.
+        if leaves.len() != self.leaf_indices.len() {
+            E::halt("Found an incorrect number of Merkle leaves")
+        }
+
+        // Initialize the known nodes on the current level, by computing the leaf hashes to start.
+        // As the leaf indices are constants, the ordering of each node and its sibling is known,
+        // and the nodes that share a parent are hashed together without a sibling hash.
+        let mut nodes = self
+            .leaf_indices
+            .iter()
+            .map(|leaf_index| *leaf_index.eject_value())
+            .zip_eq(leaves.iter().map(|leaf| leaf_hasher.hash_leaf(leaf)))
+            .collect::<Vec<_>>();
+
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            let mut parents = Vec::with_capacity(nodes.len());
+            let mut i = 0;
+            while i < nodes.len() {
+                let position = nodes[i].0;
+                // Construct the ordering of the left & right child hash for this node.
+                let (left, right) = match nodes.get(i + 1) {
+                    // If the sibling is the next known node, hash the two nodes together.
+                    Some((next_position, next_hash)) if position % 2 == 0 && *next_position == position + 1 => {
+                        i += 1;
+                        (&nodes[i - 1].1, next_hash)
+                    }
+                    // Otherwise, use the next sibling hash in the path.
+                    _ => match siblings.next() {
+                        Some(sibling_hash) => match position % 2 == 0 {
+                            true => (&nodes[i].1, sibling_hash),
+                            false => (sibling_hash, &nodes[i].1),
+                        },
+                        None => E::halt("Found too few Merkle multi-path siblings"),
+                    },
+                };
+                i += 1;
+                // Compute the parent hash for the next level.
+                parents.push((position >> 1, path_hasher.hash_children(left, right)));
+            }
+            nodes = parents;
+        }
+
+        // Ensure every sibling hash was used.
+        if siblings.next().is_some() {
+            E::halt("Found too many Merkle multi-path siblings")
+        }
+
+        // Ensure the final hash matches the given root.
+        match nodes.as_slice() {
+            [(_, current_hash)] => root.is_equal(current_hash),
+            _ => E::halt("Found an invalid set of Merkle leaf indices"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{test_rng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "MerkleTreeCircuit0";
+
+    macro_rules! check_verify {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $num_inputs:expr, $num_leaves:expr, $leaf_indices:expr) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            // Compute the leaves.
+            let leaves = (0..$num_leaves)
+                .map(|_| (0..$num_inputs).map(|_| Uniform::rand(&mut test_rng())).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            // Compute the Merkle tree.
+            let merkle_tree = console::merkle_tree::MerkleTree::<_, _, _, $depth>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+                &leaves,
+            )?;
+
+            // Compute the Merkle multi-path.
+            let leaf_indices: &[usize] = $leaf_indices;
+            let merkle_leaves = leaf_indices.iter().map(|i| leaves[*i].clone()).collect::<Vec<_>>();
+            let merkle_path = merkle_tree.prove_multi(leaf_indices, &merkle_leaves)?;
+
+            // Initialize the Merkle multi-path.
+            let path = MerkleMultiPath::<Circuit, $depth>::new(Mode::$mode, merkle_path.clone());
+            assert_eq!(merkle_path, path.eject_value());
+            // Initialize the Merkle root.
+            let root = Field::new(Mode::$mode, *merkle_tree.root());
+            // Initialize the Merkle leaves.
+            let leaves: Vec<Vec<_>> = Inject::new(Mode::$mode, merkle_leaves.clone());
+
+            Circuit::scope(format!("Verify {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaves);
+                assert!(candidate.eject_value());
+            });
+            Circuit::reset();
+
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = root.clone() + Field::one();
+
+            Circuit::scope(format!("Verify (Incorrect Root) {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &incorrect_root, &leaves);
+                assert!(!candidate.eject_value());
+            });
+            Circuit::reset();
+
+            // Initialize an incorrect Merkle leaf.
+            let mut incorrect_leaves = leaves.clone();
+            let mut incorrect_value = Uniform::rand(&mut test_rng());
+            while incorrect_value == incorrect_leaves[0][0].eject_value() {
+                incorrect_value = Uniform::rand(&mut test_rng());
+            }
+            incorrect_leaves[0][0] = Inject::new(Mode::$mode, incorrect_value);
+
+            Circuit::scope(format!("Verify (Incorrect Leaf) {}", Mode::$mode), || {
+                let candidate = path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &incorrect_leaves);
+                assert!(!candidate.eject_value());
+            });
+            Circuit::reset();
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_verify_bhp512_constant() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Constant, 32, 1024, 16, &[0, 3, 4, 5, 15])
+    }
+
+    #[test]
+    fn test_verify_bhp512_public() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Public, 32, 1024, 16, &[0, 3, 4, 5, 15])
+    }
+
+    #[test]
+    fn test_verify_bhp512_private() -> Result<()> {
+        check_verify!(BHP1024, BHP512, Private, 32, 1024, 16, &[0, 3, 4, 5, 15])
+    }
+
+    #[test]
+    fn test_verify_poseidon2_constant() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Constant, 32, 4, 16, &(0..16).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_verify_poseidon2_public() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Public, 32, 4, 16, &(0..16).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_verify_poseidon2_private() -> Result<()> {
+        check_verify!(Poseidon4, Poseidon2, Private, 32, 4, 16, &(0..16).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_verify_shares_hashes() -> Result<()> {
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon4::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_leaf_hasher = Poseidon4::<Circuit>::constant(native_leaf_hasher.clone());
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        // Compute the 16 leaves of a transition.
+        let leaves = (0..16).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+        let merkle_tree =
+            console::merkle_tree::MerkleTree::<_, _, _, 32>::new(&native_leaf_hasher, &native_path_hasher, &leaves)?;
+        let root = Field::new(Mode::Private, *merkle_tree.root());
+
+        // Count the constraints for verifying each leaf with its own Merkle path.
+        Circuit::scope("Verify Paths", || {
+            for (index, leaf) in leaves.iter().enumerate() {
+                let path = MerklePath::<Circuit, 32>::new(Mode::Private, merkle_tree.prove(index, leaf).unwrap());
+                let leaf: Vec<_> = Inject::new(Mode::Private, leaf.clone());
+                assert!(path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaf).eject_value());
+            }
+        });
+        let num_constraints_for_paths = Circuit::num_constraints();
+        Circuit::reset();
+
+        // Count the constraints for verifying all leaves with one Merkle multi-path.
+        let root = Field::new(Mode::Private, *merkle_tree.root());
+        Circuit::scope("Verify Multi-Path", || {
+            let leaf_indices = (0..16).collect::<Vec<_>>();
+            let path = MerkleMultiPath::<Circuit, 32>::new(
+                Mode::Private,
+                merkle_tree.prove_multi(&leaf_indices, &leaves).unwrap(),
+            );
+            let leaves: Vec<Vec<_>> = Inject::new(Mode::Private, leaves.clone());
+            assert!(path.verify(&circuit_leaf_hasher, &circuit_path_hasher, &root, &leaves).eject_value());
+        });
+        let num_constraints_for_multi_path = Circuit::num_constraints();
+        Circuit::reset();
+
+        // Ensure the Merkle multi-path uses fewer constraints than the individual Merkle paths.
+        assert!(num_constraints_for_multi_path < num_constraints_for_paths);
+        Ok(())
+    }
+}
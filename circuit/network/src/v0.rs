@@ -22,11 +22,13 @@ use snarkvm_circuit_algorithms::{
     HashMany,
     HashToGroup,
     HashToScalar,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     BHP1024,
     BHP256,
     BHP512,
@@ -54,6 +56,8 @@ thread_local! {
     static BCM_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::bcm_domain());
     /// The encryption domain as a constant field element.
     static ENCRYPTION_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::encryption_domain());
+    /// The graph key domain as a constant field element.
+    static GRAPH_KEY_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::graph_key_domain());
     /// The MAC domain as a constant field element.
     static MAC_DOMAIN: Field<AleoV0> = Field::constant(<console::Testnet3 as console::Network>::mac_domain());
     /// The randomizer domain as a constant field element.
@@ -83,6 +87,11 @@ thread_local! {
     static POSEIDON_4: Poseidon4<AleoV0> = Poseidon4::<AleoV0>::constant(console::POSEIDON_4.clone());
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
+
+    /// The SHA-256 hash function.
+    static SHA_256: Sha256<AleoV0> = Sha256::<AleoV0>::constant(Default::default());
+    /// The Keccak-256 hash function.
+    static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::constant(Default::default());
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -102,6 +111,11 @@ impl Aleo for AleoV0 {
         ENCRYPTION_DOMAIN.with(|domain| domain.clone())
     }
 
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        GRAPH_KEY_DOMAIN.with(|domain| domain.clone())
+    }
+
     /// Returns the MAC domain as a constant field element.
     fn mac_domain() -> Field<Self> {
         MAC_DOMAIN.with(|domain| domain.clone())
@@ -193,6 +207,16 @@ impl Aleo for AleoV0 {
         PEDERSEN_128.with(|pedersen| pedersen.hash(input))
     }
 
+    /// Returns the SHA-256 hash of the given input, as 256 bits.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA_256.with(|sha256| sha256.hash(input))
+    }
+
+    /// Returns the Keccak-256 hash of the given input, as 256 bits.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        KECCAK_256.with(|keccak| keccak.hash(input))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash(input))
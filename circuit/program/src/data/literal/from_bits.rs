@@ -37,7 +37,8 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_le(literal)),
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
             15 => Literal::String(StringType::from_bits_le(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            16 => Literal::Signature(Box::new(Signature::from_bits_le(literal))),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -61,7 +62,8 @@ impl<A: Aleo> Literal<A> {
             13 => Literal::U128(U128::from_bits_be(literal)),
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
             15 => Literal::String(StringType::from_bits_be(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            16 => Literal::Signature(Box::new(Signature::from_bits_be(literal))),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
@@ -70,7 +72,7 @@ impl<A: Aleo> Literal<A> {
 mod tests {
     use super::*;
     use crate::Circuit;
-    use console::{test_rng, Rng, Uniform};
+    use console::{test_crypto_rng, test_rng, Rng, Uniform};
 
     const ITERATIONS: u32 = 1000;
 
@@ -129,6 +131,11 @@ mod tests {
             check_serialization(Literal::<Circuit>::U128(U128::new(mode, Uniform::rand(rng))));
             // Scalar
             check_serialization(Literal::<Circuit>::Scalar(Scalar::new(mode, Uniform::rand(rng))));
+            // Signature
+            let private_key = snarkvm_console_account::PrivateKey::new(&mut test_crypto_rng()).unwrap();
+            let message: Vec<_> = (0..rng.gen_range(0..16)).map(|_| Uniform::rand(rng)).collect();
+            let signature = console::Signature::sign(&private_key, &message, &mut test_crypto_rng()).unwrap();
+            check_serialization(Literal::<Circuit>::Signature(Box::new(Signature::new(mode, signature))));
             // String
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let range = 0..rng.gen_range(0..Circuit::NUM_STRING_BYTES / 4);
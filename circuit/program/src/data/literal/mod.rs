@@ -21,6 +21,7 @@ mod to_fields;
 mod to_type;
 mod variant;
 
+use snarkvm_circuit_account::Signature;
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::prelude::*;
 
@@ -59,6 +60,8 @@ pub enum Literal<A: Aleo> {
     Scalar(Scalar<A>),
     /// The string type.
     String(StringType<A>),
+    /// The signature type.
+    Signature(Box<Signature<A>>),
 }
 
 #[cfg(console)]
@@ -84,6 +87,7 @@ impl<A: Aleo> Inject for Literal<A> {
             Self::Primitive::U128(u128) => Self::U128(U128::new(mode, u128)),
             Self::Primitive::Scalar(scalar) => Self::Scalar(Scalar::new(mode, scalar)),
             Self::Primitive::String(string) => Self::String(StringType::new(mode, string)),
+            Self::Primitive::Signature(signature) => Self::Signature(Box::new(Signature::new(mode, *signature))),
         }
     }
 }
@@ -111,6 +115,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => literal.eject_mode(),
             Self::Scalar(literal) => literal.eject_mode(),
             Self::String(literal) => literal.eject_mode(),
+            Self::Signature(literal) => literal.eject_mode(),
         }
     }
 
@@ -133,6 +138,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => Self::Primitive::U128(literal.eject_value()),
             Self::Scalar(literal) => Self::Primitive::Scalar(literal.eject_value()),
             Self::String(literal) => Self::Primitive::String(literal.eject_value()),
+            Self::Signature(literal) => Self::Primitive::Signature(Box::new(literal.eject_value())),
         }
     }
 }
@@ -159,6 +165,7 @@ impl<A: Aleo> Parser for Literal<A> {
             map(U128::parse, |literal| Self::U128(literal)),
             map(Scalar::parse, |literal| Self::Scalar(literal)),
             map(StringType::parse, |literal| Self::String(literal)),
+            map(Signature::parse, |literal| Self::Signature(Box::new(literal))),
         ))(string)
     }
 }
@@ -203,6 +210,7 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => U128::<A>::type_name(),
             Self::Scalar(..) => Scalar::<A>::type_name(),
             Self::String(..) => StringType::<A>::type_name(),
+            Self::Signature(..) => Signature::<A>::type_name(),
         }
     }
 }
@@ -234,6 +242,7 @@ impl<A: Aleo> Display for Literal<A> {
             Self::U128(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::Signature(literal) => Display::fmt(literal, f),
         }
     }
 }
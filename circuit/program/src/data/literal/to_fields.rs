@@ -47,6 +47,7 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::U128(literal) => vec![literal.to_field()],
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::String(literal) => literal.to_fields(),
+            Literal::Signature(literal) => literal.to_fields(),
         }
     }
 }
@@ -84,8 +84,16 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
             index += num_randomizers as usize;
         }
 
+        // Decrypt the memo, if it exists.
+        let memo = self.memo.as_ref().map(|memo| {
+            // Retrieve the randomizers for the memo.
+            let randomizers = &randomizers[index..index + memo.size_in_fields() as usize];
+            // Decrypt the memo.
+            memo.decrypt_with_randomizers(randomizers)
+        });
+
         // Return the decrypted record.
-        Record { owner, balance, data: decrypted_data }
+        Record { owner, balance, data: decrypted_data, memo }
     }
 }
 
@@ -128,6 +136,7 @@ mod tests {
                 ]
                 .into_iter(),
             ),
+            memo: None,
         };
 
         // Encrypt the record.
@@ -136,6 +145,16 @@ mod tests {
         // Decrypt the record.
         let nonce = A::g_scalar_multiply(&randomizer);
         assert_eq!(record.eject(), ciphertext.decrypt(view_key, &nonce).eject());
+
+        // Attach a memo to the record.
+        let memo = Plaintext::from(Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut test_rng()))));
+        let record = record.with_memo(memo);
+
+        // Encrypt the record.
+        let ciphertext = record.encrypt(&randomizer);
+        assert!(ciphertext.memo().is_some());
+        // Decrypt the record.
+        assert_eq!(record.eject(), ciphertext.decrypt(view_key, &nonce).eject());
         Ok(())
     }
 
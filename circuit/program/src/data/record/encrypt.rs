@@ -84,7 +84,15 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
             index += num_randomizers as usize;
         }
 
+        // Encrypt the memo, if it exists.
+        let memo = self.memo.as_ref().map(|memo| {
+            // Retrieve the randomizers for the memo.
+            let randomizers = &randomizers[index..index + memo.size_in_fields() as usize];
+            // Encrypt the memo.
+            memo.encrypt_with_randomizers(randomizers)
+        });
+
         // Return the encrypted record.
-        Record { owner, balance, data: encrypted_data }
+        Record { owner, balance, data: encrypted_data, memo }
     }
 }
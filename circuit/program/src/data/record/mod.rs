@@ -44,6 +44,8 @@ pub struct Record<A: Aleo, Private: Visibility<A>> {
     balance: Balance<A, Private>,
     /// The program data.
     data: IndexMap<Identifier<A>, Entry<A, Private>>,
+    /// The optional memo, which is always encrypted to the record owner.
+    memo: Option<Private>,
 }
 
 #[cfg(console)]
@@ -56,6 +58,7 @@ impl<A: Aleo> Inject for Record<A, Plaintext<A>> {
             owner: Owner::new(Mode::Private, record.owner().clone()),
             balance: Balance::new(Mode::Private, record.balance().clone()),
             data: Inject::new(Mode::Private, record.data().clone()),
+            memo: record.memo().map(|memo| Inject::new(Mode::Private, memo.clone())),
         }
     }
 }
@@ -70,6 +73,7 @@ impl<A: Aleo> Inject for Record<A, Ciphertext<A>> {
             owner: Owner::new(Mode::Private, record.owner().clone()),
             balance: Balance::new(Mode::Private, record.balance().clone()),
             data: Inject::new(Mode::Private, record.data().clone()),
+            memo: record.memo().map(|memo| Inject::new(Mode::Private, memo.clone())),
         }
     }
 }
@@ -91,7 +95,7 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
             data.len()
         );
         // Return the record.
-        Ok(Self { owner, balance, data })
+        Ok(Self { owner, balance, data, memo: None })
     }
 }
 
@@ -112,7 +116,7 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
             data.len()
         );
         // Return the record.
-        Ok(Self { owner, balance, data })
+        Ok(Self { owner, balance, data, memo: None })
     }
 }
 
@@ -131,6 +135,21 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
     pub const fn data(&self) -> &IndexMap<Identifier<A>, Entry<A, Private>> {
         &self.data
     }
+
+    /// Returns the memo, if one is attached to the program record.
+    pub const fn memo(&self) -> Option<&Private> {
+        self.memo.as_ref()
+    }
+
+    /// Attaches the given memo to the program record.
+    pub fn with_memo(self, memo: Private) -> Self {
+        // Attach the memo.
+        let record = Self { memo: Some(memo), ..self };
+        // Ensure the record, including the memo, is within `A::MAX_DATA_SIZE_IN_FIELDS`.
+        record.num_randomizers();
+        // Return the record.
+        record
+    }
 }
 
 #[cfg(console)]
@@ -163,7 +182,15 @@ impl<A: Aleo> Eject for Record<A, Plaintext<A>> {
 
         let data = self.data.iter().map(|(_, entry)| entry.eject_mode()).collect::<Vec<_>>().eject_mode();
 
-        Mode::combine(owner, [balance, data])
+        let memo = match &self.memo {
+            Some(memo) => match memo.eject_mode() == Mode::Private {
+                true => Mode::Private,
+                false => A::halt("Record::<Plaintext>::eject_mode: memo is not private."),
+            },
+            None => Mode::Constant,
+        };
+
+        Mode::combine(owner, [balance, data, memo])
     }
 
     /// Ejects the record.
@@ -178,11 +205,16 @@ impl<A: Aleo> Eject for Record<A, Plaintext<A>> {
             Balance::Private(plaintext) => console::Balance::Private(plaintext.eject_value()),
         };
 
-        match Self::Primitive::from_plaintext(
+        let record = Self::Primitive::from_plaintext(
             owner,
             balance,
             self.data.iter().map(|(identifier, entry)| (identifier, entry).eject_value()).collect::<IndexMap<_, _>>(),
-        ) {
+        );
+
+        match record.and_then(|record| match &self.memo {
+            Some(memo) => record.with_memo(memo.eject_value()),
+            None => Ok(record),
+        }) {
             Ok(record) => record,
             Err(error) => A::halt(format!("Record::<Plaintext>::eject_value: {}", error)),
         }
@@ -219,7 +251,15 @@ impl<A: Aleo> Eject for Record<A, Ciphertext<A>> {
 
         let data = self.data.iter().map(|(_, entry)| entry.eject_mode()).collect::<Vec<_>>().eject_mode();
 
-        Mode::combine(owner, [balance, data])
+        let memo = match &self.memo {
+            Some(memo) => match memo.eject_mode() == Mode::Private {
+                true => Mode::Private,
+                false => A::halt("Record::<Ciphertext>::eject_mode: memo is not private."),
+            },
+            None => Mode::Constant,
+        };
+
+        Mode::combine(owner, [balance, data, memo])
     }
 
     /// Ejects the record.
@@ -234,11 +274,16 @@ impl<A: Aleo> Eject for Record<A, Ciphertext<A>> {
             Balance::Private(plaintext) => console::Balance::Private(plaintext.eject_value()),
         };
 
-        match Self::Primitive::from_ciphertext(
+        let record = Self::Primitive::from_ciphertext(
             owner,
             balance,
             self.data.iter().map(|(identifier, entry)| (identifier, entry).eject_value()).collect::<IndexMap<_, _>>(),
-        ) {
+        );
+
+        match record.and_then(|record| match &self.memo {
+            Some(memo) => record.with_memo(memo.eject_value()),
+            None => Ok(record),
+        }) {
             Ok(record) => record,
             Err(error) => A::halt(format!("Record::<Ciphertext>::eject_value: {}", error)),
         }
@@ -40,6 +40,14 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
             };
         }
 
+        // If there is a memo, increment the number of randomizers by the number of memo randomizers.
+        if let Some(memo) = &self.memo {
+            num_randomizers = match num_randomizers.checked_add(memo.size_in_fields()) {
+                Some(num_randomizers) => num_randomizers,
+                None => A::halt("Number of randomizers exceeds the maximum allowed size."),
+            };
+        }
+
         // Ensure the number of randomizers does not exceed the maximum allowed size.
         match num_randomizers as u32 <= A::MAX_DATA_SIZE_IN_FIELDS {
             true => num_randomizers,
@@ -34,6 +34,12 @@ impl<A: Aleo> ToBits for Record<A, Plaintext<A>> {
         bits_le.extend(self.balance.to_bits_le());
         bits_le.extend(U32::constant(console::U32::new(data_bits_le.len() as u32)).to_bits_le());
         bits_le.extend(data_bits_le);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_le = memo.to_bits_le();
+            bits_le.extend(U32::constant(console::U32::new(memo_bits_le.len() as u32)).to_bits_le());
+            bits_le.extend(memo_bits_le);
+        }
         bits_le
     }
 
@@ -52,6 +58,12 @@ impl<A: Aleo> ToBits for Record<A, Plaintext<A>> {
         bits_be.extend(self.balance.to_bits_be());
         bits_be.extend(U32::constant(console::U32::new(data_bits_be.len() as u32)).to_bits_le());
         bits_be.extend(data_bits_be);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_be = memo.to_bits_be();
+            bits_be.extend(U32::constant(console::U32::new(memo_bits_be.len() as u32)).to_bits_be());
+            bits_be.extend(memo_bits_be);
+        }
         bits_be
     }
 }
@@ -74,6 +86,12 @@ impl<A: Aleo> ToBits for Record<A, Ciphertext<A>> {
         bits_le.extend(self.balance.to_bits_le());
         bits_le.extend(U32::constant(console::U32::new(data_bits_le.len() as u32)).to_bits_le());
         bits_le.extend(data_bits_le);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_le = memo.to_bits_le();
+            bits_le.extend(U32::constant(console::U32::new(memo_bits_le.len() as u32)).to_bits_le());
+            bits_le.extend(memo_bits_le);
+        }
         bits_le
     }
 
@@ -92,6 +110,12 @@ impl<A: Aleo> ToBits for Record<A, Ciphertext<A>> {
         bits_be.extend(self.balance.to_bits_be());
         bits_be.extend(U32::constant(console::U32::new(data_bits_be.len() as u32)).to_bits_le());
         bits_be.extend(data_bits_be);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_be = memo.to_bits_be();
+            bits_be.extend(U32::constant(console::U32::new(memo_bits_be.len() as u32)).to_bits_be());
+            bits_be.extend(memo_bits_be);
+        }
         bits_be
     }
 }
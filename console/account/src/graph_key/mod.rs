@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod try_from;
+
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+use crate::ViewKey;
+#[cfg(feature = "signature")]
+use crate::{Address, Signature};
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The account graph key is used to recognize the tags of output records owned by the account.
+///
+/// A sender tags each output record with the tag public key `pk_tag` of its owner, which the owner
+/// signs with the account private key, so the output circuit can bind `pk_tag` to the record owner.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphKey<N: Network> {
+    /// The tag secret key `sk_tag` := Hash(view_key).
+    sk_tag: Field<N>,
+}
+
+impl<N: Network> GraphKey<N> {
+    /// Initializes the account graph key from a tag secret key.
+    pub const fn from_sk_tag(sk_tag: Field<N>) -> Self {
+        Self { sk_tag }
+    }
+
+    /// Returns the tag secret key.
+    pub const fn sk_tag(&self) -> Field<N> {
+        self.sk_tag
+    }
+
+    /// Returns the tag public key (i.e. `pk_tag := G^HashToScalar(sk_tag)`),
+    /// which a sender uses to tag the output records it creates for this account.
+    pub fn to_pk_tag(&self) -> Result<Group<N>> {
+        Ok(N::g_scalar_multiply(&N::hash_to_scalar_psd2(&[self.sk_tag])?))
+    }
+
+    /// Returns the tag for the output record with the given nonce (i.e. `tag := Hash(HashToScalar(sk_tag) * G^r)`).
+    /// Note: This costs one scalar multiplication and two hashes per record, and no decryption.
+    pub fn to_output_tag(&self, nonce: Group<N>) -> Result<Field<N>> {
+        let shared_point = nonce * N::hash_to_scalar_psd2(&[self.sk_tag])?;
+        N::hash_psd2(&[N::graph_key_domain(), shared_point.to_x_coordinate()])
+    }
+
+    /// Returns the tag for an output record, given the tag public key of its owner and the record randomizer
+    /// (i.e. `tag := Hash(r * pk_tag)`). This matches `GraphKey::to_output_tag` for the owner of the record.
+    pub fn derive_output_tag(pk_tag: Group<N>, randomizer: Scalar<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[N::graph_key_domain(), (pk_tag * randomizer).to_x_coordinate()])
+    }
+
+    /// Returns `true` if the given tag belongs to the output record with the given nonce under this graph key.
+    /// Note: This costs one scalar multiplication per record (see `GraphKey::to_output_tag`).
+    pub fn is_output_tag_owner(&self, nonce: Group<N>, tag: Field<N>) -> bool {
+        match self.to_output_tag(nonce) {
+            // Check if the tags match.
+            Ok(candidate_tag) => candidate_tag == tag,
+            // If the computation fails, return false.
+            Err(error) => {
+                eprintln!("{error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the message that an account signs to bind its tag public key to its address.
+    pub fn to_pk_tag_message(pk_tag: Group<N>) -> [Field<N>; 2] {
+        [N::graph_key_domain(), pk_tag.to_x_coordinate()]
+    }
+
+    /// Returns the tag public key of the given account, and its signature under the account private key.
+    /// A sender provides both to the output circuit, which verifies the signature against the record owner.
+    #[cfg(feature = "signature")]
+    pub fn sign_pk_tag<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<(Group<N>, Signature<N>)> {
+        let pk_tag = Self::try_from(private_key)?.to_pk_tag()?;
+        Ok((pk_tag, Signature::sign(private_key, &Self::to_pk_tag_message(pk_tag), rng)?))
+    }
+
+    /// Returns `true` if the given signature binds the given tag public key to the given address.
+    #[cfg(feature = "signature")]
+    pub fn verify_pk_tag(address: &Address<N>, pk_tag: Group<N>, signature: &Signature<N>) -> bool {
+        signature.verify(address, &Self::to_pk_tag_message(pk_tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_is_output_tag_owner() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new graph key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let graph_key = GraphKey::try_from(private_key)?;
+
+            // Sample a record randomizer, and compute the output tag as the sender.
+            let randomizer = Uniform::rand(rng);
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            let tag = GraphKey::derive_output_tag(graph_key.to_pk_tag()?, randomizer)?;
+            assert_eq!(tag, graph_key.to_output_tag(nonce)?);
+            assert!(graph_key.is_output_tag_owner(nonce, tag));
+
+            // Ensure the tag does not match a different nonce.
+            assert!(!graph_key.is_output_tag_owner(CurrentNetwork::g_scalar_multiply(&Uniform::rand(rng)), tag));
+
+            // Ensure the tag does not match a different graph key.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_graph_key = GraphKey::try_from(other_private_key)?;
+            assert!(!other_graph_key.is_output_tag_owner(nonce, tag));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_pk_tag() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new account, and sign its tag public key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let (pk_tag, signature) = GraphKey::sign_pk_tag(&private_key, rng)?;
+            assert_eq!(GraphKey::try_from(&private_key)?.to_pk_tag()?, pk_tag);
+            assert!(GraphKey::verify_pk_tag(&address, pk_tag, &signature));
+
+            // Ensure the signature does not bind the tag public key of another account to the address.
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_address = Address::try_from(&other_private_key)?;
+            let (other_pk_tag, other_signature) = GraphKey::sign_pk_tag(&other_private_key, rng)?;
+            assert!(!GraphKey::verify_pk_tag(&address, other_pk_tag, &signature));
+            assert!(!GraphKey::verify_pk_tag(&address, other_pk_tag, &other_signature));
+            assert!(!GraphKey::verify_pk_tag(&other_address, pk_tag, &signature));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(feature = "private_key")]
+impl<N: Network> TryFrom<PrivateKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account private key.
+    fn try_from(private_key: PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&private_key)
+    }
+}
+
+#[cfg(feature = "private_key")]
+impl<N: Network> TryFrom<&PrivateKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account private key.
+    fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(ViewKey::try_from(private_key)?)
+    }
+}
+
+impl<N: Network> TryFrom<ViewKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account view key.
+    fn try_from(view_key: ViewKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&view_key)
+    }
+}
+
+impl<N: Network> TryFrom<&ViewKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account view key.
+    fn try_from(view_key: &ViewKey<N>) -> Result<Self, Self::Error> {
+        // Compute sk_tag := Hash(view_key).
+        let sk_tag = N::hash_psd2(&[N::graph_key_domain(), view_key.to_field()?])?;
+        // Output the graph key.
+        Ok(Self { sk_tag })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key and graph key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let graph_key = GraphKey::try_from(private_key)?;
+
+            // Check that the graph key matches.
+            // Compute sk_tag := Hash(view_key).
+            let sk_tag = CurrentNetwork::hash_psd2(&[CurrentNetwork::graph_key_domain(), view_key.to_field()?])?;
+            assert_eq!(graph_key, GraphKey { sk_tag });
+            assert_eq!(graph_key, GraphKey::try_from(view_key)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Keystore {
+    /// Returns the account private key in the keystore, decrypted with the given password.
+    pub fn decrypt_private_key<N: Network>(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Ensure the keystore holds a private key.
+        ensure!(
+            self.kind == KeystoreKind::PrivateKey,
+            "Expected a private key keystore, found a {} keystore",
+            self.kind
+        );
+        // Decrypt the private key.
+        PrivateKey::read_le(&self.decrypt(password)?[..]).map_err(|e| anyhow!("Invalid keystore private key: {e}"))
+    }
+
+    /// Returns the account view key in the keystore, decrypted with the given password.
+    pub fn decrypt_view_key<N: Network>(&self, password: &str) -> Result<ViewKey<N>> {
+        // Ensure the keystore holds a view key.
+        ensure!(self.kind == KeystoreKind::ViewKey, "Expected a view key keystore, found a {} keystore", self.kind);
+        // Decrypt the view key.
+        ViewKey::read_le(&self.decrypt(password)?[..]).map_err(|e| anyhow!("Invalid keystore view key: {e}"))
+    }
+
+    /// Returns the plaintext in the keystore, decrypted with the given password, which is zeroized on drop.
+    fn decrypt(&self, password: &str) -> Result<Zeroizing<Vec<u8>>> {
+        // Ensure the keystore version is supported.
+        ensure!(
+            self.version == KEYSTORE_VERSION,
+            "Unsupported keystore version: found {}, expected {KEYSTORE_VERSION}",
+            self.version
+        );
+
+        // Decode the nonce and ciphertext.
+        let nonce = hex::decode(&self.cipher.nonce)?;
+        ensure!(nonce.len() == NONCE_SIZE_IN_BYTES, "Invalid keystore nonce length: found {}", nonce.len());
+        let ciphertext = hex::decode(&self.ciphertext)?;
+
+        // Derive the cipher key from the password.
+        let key = self.kdf.derive_key(password)?;
+
+        // Decrypt the ciphertext, which fails if the password or the keystore header is incorrect.
+        let aad = Self::associated_data(self.version, self.kind);
+        Self::cipher(self.cipher.algorithm, &key)?
+            .decrypt(XNonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad })
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow!("Failed to decrypt the keystore: incorrect password or corrupted keystore"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// The `(memory, passes, parallelism)` costs of the key derivation function in tests.
+    const TEST_COST: (u32, u32, u32) = (64, 1, 1);
+
+    #[test]
+    fn test_decrypt_private_key() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let plaintext = private_key.to_bytes_le()?;
+
+            // Check the private key decrypts with the correct password.
+            let keystore =
+                Keystore::encrypt(KeystoreKind::PrivateKey, &plaintext, "password", TEST_COST, &mut test_crypto_rng())?;
+            assert_eq!(private_key, keystore.decrypt_private_key("password")?);
+
+            // Check the decryption fails with an incorrect password, or as the wrong kind of key.
+            assert!(keystore.decrypt_private_key::<CurrentNetwork>("Password").is_err());
+            assert!(keystore.decrypt_private_key::<CurrentNetwork>("").is_err());
+            assert!(keystore.decrypt_view_key::<CurrentNetwork>("password").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_view_key() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let plaintext = view_key.to_bytes_le()?;
+
+            // Check the view key decrypts with the correct password.
+            let keystore =
+                Keystore::encrypt(KeystoreKind::ViewKey, &plaintext, "password", TEST_COST, &mut test_crypto_rng())?;
+            assert_eq!(view_key, keystore.decrypt_view_key("password")?);
+
+            // Check the decryption fails with an incorrect password, or as the wrong kind of key.
+            assert!(keystore.decrypt_view_key::<CurrentNetwork>("Password").is_err());
+            assert!(keystore.decrypt_private_key::<CurrentNetwork>("password").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_default_cost() -> Result<()> {
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+        // Check the private key decrypts with the default parameters.
+        let keystore = Keystore::encrypt_private_key(&private_key, "password", &mut test_crypto_rng())?;
+        assert_eq!(private_key, keystore.decrypt_private_key("password")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_tampered() -> Result<()> {
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let plaintext = private_key.to_bytes_le()?;
+        let keystore =
+            Keystore::encrypt(KeystoreKind::PrivateKey, &plaintext, "password", TEST_COST, &mut test_crypto_rng())?;
+
+        // Check the decryption fails for an unsupported version.
+        let mut candidate = keystore.clone();
+        candidate.version += 1;
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for a relabeled kind of key.
+        let mut candidate = keystore.clone();
+        candidate.kind = KeystoreKind::ViewKey;
+        assert!(candidate.decrypt_view_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for modified KDF parameters.
+        let mut candidate = keystore.clone();
+        candidate.kdf.t_cost += 1;
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for an excessive memory cost.
+        let mut candidate = keystore.clone();
+        candidate.kdf.m_cost = MAX_M_COST + 1;
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for an excessive number of passes or degree of parallelism.
+        let mut candidate = keystore.clone();
+        candidate.kdf.t_cost = MAX_T_COST + 1;
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.kdf.p_cost = MAX_P_COST + 1;
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for a modified nonce.
+        let mut candidate = keystore.clone();
+        candidate.cipher.nonce = hex::encode([0u8; NONCE_SIZE_IN_BYTES]);
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+
+        // Check the decryption fails for a modified or truncated ciphertext.
+        let mut candidate = keystore.clone();
+        let mut ciphertext = hex::decode(&candidate.ciphertext)?;
+        ciphertext[0] ^= 1;
+        candidate.ciphertext = hex::encode(&ciphertext);
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+        candidate.ciphertext = hex::encode(&ciphertext[1..]);
+        assert!(candidate.decrypt_private_key::<CurrentNetwork>("password").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Keystore {
+    /// Returns a keystore of the given account private key, encrypted under the given password.
+    pub fn encrypt_private_key<N: Network, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::encrypt(
+            KeystoreKind::PrivateKey,
+            &Zeroizing::new(private_key.to_bytes_le()?),
+            password,
+            (KdfParams::DEFAULT_M_COST, KdfParams::DEFAULT_T_COST, KdfParams::DEFAULT_P_COST),
+            rng,
+        )
+    }
+
+    /// Returns a keystore of the given account view key, encrypted under the given password.
+    pub fn encrypt_view_key<N: Network, R: Rng + CryptoRng>(
+        view_key: &ViewKey<N>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::encrypt(
+            KeystoreKind::ViewKey,
+            &Zeroizing::new(view_key.to_bytes_le()?),
+            password,
+            (KdfParams::DEFAULT_M_COST, KdfParams::DEFAULT_T_COST, KdfParams::DEFAULT_P_COST),
+            rng,
+        )
+    }
+
+    /// Returns a keystore of the given plaintext, encrypted under the given password,
+    /// with the given `(memory, passes, parallelism)` costs for the key derivation function.
+    pub(super) fn encrypt<R: Rng + CryptoRng>(
+        kind: KeystoreKind,
+        plaintext: &[u8],
+        password: &str,
+        (m_cost, t_cost, p_cost): (u32, u32, u32),
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random salt and nonce.
+        let salt: [u8; SALT_SIZE_IN_BYTES] = rng.gen();
+        let nonce: [u8; NONCE_SIZE_IN_BYTES] = rng.gen();
+
+        // Derive the cipher key from the password.
+        let kdf = KdfParams { algorithm: KdfAlgorithm::Argon2id, m_cost, t_cost, p_cost, salt: hex::encode(salt) };
+        let key = kdf.derive_key(password)?;
+
+        // Encrypt the plaintext, binding it to the keystore header.
+        let algorithm = CipherAlgorithm::XChaCha20Poly1305;
+        let aad = Self::associated_data(KEYSTORE_VERSION, kind);
+        let ciphertext = Self::cipher(algorithm, &key)?
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad: &aad })
+            .map_err(|_| anyhow!("Failed to encrypt the keystore"))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            kind,
+            kdf,
+            cipher: CipherParams { algorithm, nonce: hex::encode(nonce) },
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_encrypt_private_key() -> Result<()> {
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+        // Check the keystore uses the default parameters.
+        let keystore = Keystore::encrypt_private_key(&private_key, "password", &mut test_crypto_rng())?;
+        assert_eq!(KEYSTORE_VERSION, keystore.version());
+        assert_eq!(KeystoreKind::PrivateKey, keystore.kind());
+        assert_eq!(KdfAlgorithm::Argon2id, keystore.kdf.algorithm);
+        assert_eq!(KdfParams::DEFAULT_M_COST, keystore.kdf.m_cost);
+        assert_eq!(CipherAlgorithm::XChaCha20Poly1305, keystore.cipher.algorithm);
+
+        // Check the ciphertext does not contain the private key.
+        assert!(!keystore.ciphertext.contains(&hex::encode(private_key.to_bytes_le()?)));
+        // Check the salt and nonce are freshly sampled.
+        let other = Keystore::encrypt_private_key(&private_key, "password", &mut test_crypto_rng())?;
+        assert_ne!(keystore.kdf.salt, other.kdf.salt);
+        assert_ne!(keystore.cipher.nonce, other.cipher.nonce);
+        assert_ne!(keystore.ciphertext, other.ciphertext);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_view_key() -> Result<()> {
+        // Sample a new view key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let view_key = ViewKey::try_from(private_key)?;
+
+        // Check the keystore holds a view key.
+        let keystore = Keystore::encrypt_view_key(&view_key, "password", &mut test_crypto_rng())?;
+        assert_eq!(KeystoreKind::ViewKey, keystore.kind());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_invalid_cost() {
+        // Check the key derivation function rejects invalid and excessive costs.
+        let max_costs = [(MAX_M_COST + 1, 1, 1), (8, MAX_T_COST + 1, 1), (8 * (MAX_P_COST + 1), 1, MAX_P_COST + 1)];
+        for cost in [(0, 1, 1), (8, 0, 1), (8, 1, 0)].into_iter().chain(max_costs) {
+            assert!(Keystore::encrypt(KeystoreKind::ViewKey, &[0u8; 32], "", cost, &mut test_crypto_rng()).is_err());
+        }
+    }
+}
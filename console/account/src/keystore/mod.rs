@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod decrypt;
+mod encrypt;
+mod string;
+
+use crate::{PrivateKey, ViewKey};
+use snarkvm_console_network::prelude::*;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305,
+    XNonce,
+};
+use zeroize::Zeroizing;
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u16 = 1;
+/// The number of bytes in the key derivation salt.
+const SALT_SIZE_IN_BYTES: usize = 16;
+/// The number of bytes in the cipher nonce.
+const NONCE_SIZE_IN_BYTES: usize = 24;
+/// The number of bytes in the cipher key.
+const KEY_SIZE_IN_BYTES: usize = 32;
+/// The maximum memory cost of the key derivation function, in KiB.
+const MAX_M_COST: u32 = 1024 * 1024;
+/// The maximum number of passes of the key derivation function.
+const MAX_T_COST: u32 = 16;
+/// The maximum degree of parallelism of the key derivation function.
+const MAX_P_COST: u32 = 16;
+
+/// A password-encrypted account key, which is stored as versioned JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    /// The version of the keystore format.
+    version: u16,
+    /// The kind of account key in the keystore.
+    kind: KeystoreKind,
+    /// The parameters of the key derivation function.
+    kdf: KdfParams,
+    /// The parameters of the cipher.
+    cipher: CipherParams,
+    /// The encrypted account key, as a hex string.
+    ciphertext: String,
+}
+
+/// The kind of account key in a keystore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeystoreKind {
+    /// An account private key.
+    PrivateKey,
+    /// An account view key.
+    ViewKey,
+}
+
+impl Display for KeystoreKind {
+    /// Writes the kind of account key.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::PrivateKey => write!(f, "private key"),
+            Self::ViewKey => write!(f, "view key"),
+        }
+    }
+}
+
+/// The parameters of the key derivation function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    /// The key derivation function.
+    algorithm: KdfAlgorithm,
+    /// The memory cost, in KiB.
+    m_cost: u32,
+    /// The number of passes.
+    t_cost: u32,
+    /// The degree of parallelism.
+    p_cost: u32,
+    /// The salt, as a hex string.
+    salt: String,
+}
+
+/// The key derivation functions of the keystore format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum KdfAlgorithm {
+    #[serde(rename = "argon2id")]
+    Argon2id,
+}
+
+/// The parameters of the cipher.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    /// The authenticated cipher.
+    algorithm: CipherAlgorithm,
+    /// The nonce, as a hex string.
+    nonce: String,
+}
+
+/// The authenticated ciphers of the keystore format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum CipherAlgorithm {
+    #[serde(rename = "xchacha20poly1305")]
+    XChaCha20Poly1305,
+}
+
+impl KdfParams {
+    /// The default memory cost, in KiB.
+    const DEFAULT_M_COST: u32 = 19 * 1024;
+    /// The default number of passes.
+    const DEFAULT_T_COST: u32 = 2;
+    /// The default degree of parallelism.
+    const DEFAULT_P_COST: u32 = 1;
+
+    /// Returns the cipher key derived from the given password, which is zeroized on drop.
+    fn derive_key(&self, password: &str) -> Result<Zeroizing<[u8; KEY_SIZE_IN_BYTES]>> {
+        // Ensure the costs are bounded, as the keystore may be untrusted.
+        ensure!(self.m_cost <= MAX_M_COST, "Invalid keystore KDF memory cost: found {} KiB", self.m_cost);
+        ensure!(self.t_cost <= MAX_T_COST, "Invalid keystore KDF number of passes: found {}", self.t_cost);
+        ensure!(self.p_cost <= MAX_P_COST, "Invalid keystore KDF degree of parallelism: found {}", self.p_cost);
+        // Decode the salt.
+        let salt = hex::decode(&self.salt)?;
+        ensure!(salt.len() == SALT_SIZE_IN_BYTES, "Invalid keystore salt length: found {}", salt.len());
+        // Initialize the key derivation function.
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_SIZE_IN_BYTES))
+            .map_err(|e| anyhow!("Invalid keystore KDF parameters: {e}"))?;
+        let argon2 = match self.algorithm {
+            KdfAlgorithm::Argon2id => Argon2::new(Algorithm::Argon2id, Version::V0x13, params),
+        };
+        // Derive the cipher key.
+        let mut key = Zeroizing::new([0u8; KEY_SIZE_IN_BYTES]);
+        argon2.hash_password_into(password.as_bytes(), &salt, &mut *key).map_err(|e| anyhow!("{e}"))?;
+        Ok(key)
+    }
+}
+
+impl Keystore {
+    /// Returns the version of the keystore format.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the kind of account key in the keystore.
+    pub const fn kind(&self) -> KeystoreKind {
+        self.kind
+    }
+
+    /// Returns the associated data, which binds the ciphertext to the keystore header.
+    fn associated_data(version: u16, kind: KeystoreKind) -> Vec<u8> {
+        format!("aleo.keystore.{version}.{kind}").into_bytes()
+    }
+
+    /// Returns the cipher for the given key.
+    fn cipher(algorithm: CipherAlgorithm, key: &[u8; KEY_SIZE_IN_BYTES]) -> Result<XChaCha20Poly1305> {
+        match algorithm {
+            CipherAlgorithm::XChaCha20Poly1305 => XChaCha20Poly1305::new_from_slice(key).map_err(|e| anyhow!("{e}")),
+        }
+    }
+}
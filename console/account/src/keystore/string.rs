@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl FromStr for Keystore {
+    type Err = Error;
+
+    /// Reads in a keystore from a JSON string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl Display for Keystore {
+    /// Writes the keystore as a JSON string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let expected = Keystore::encrypt_private_key(&private_key, "password", &mut test_crypto_rng())?;
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!(expected, Keystore::from_str(&candidate)?);
+        assert_eq!(private_key, Keystore::from_str(&candidate)?.decrypt_private_key("password")?);
+
+        // Check the versioned JSON fields.
+        let json = serde_json::Value::from_str(&candidate)?;
+        assert_eq!(KEYSTORE_VERSION as u64, json["version"].as_u64().unwrap());
+        assert_eq!("private_key", json["kind"].as_str().unwrap());
+        assert_eq!("argon2id", json["kdf"]["algorithm"].as_str().unwrap());
+        assert_eq!("xchacha20poly1305", json["cipher"]["algorithm"].as_str().unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        assert!(Keystore::from_str("").is_err());
+        assert!(Keystore::from_str("{}").is_err());
+        assert!(Keystore::from_str("APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p").is_err());
+
+        // Check an unknown key derivation function is rejected.
+        let keystore = serde_json::json!({
+            "version": 1,
+            "kind": "private_key",
+            "kdf": { "algorithm": "scrypt", "m_cost": 8, "t_cost": 1, "p_cost": 1, "salt": "" },
+            "cipher": { "algorithm": "xchacha20poly1305", "nonce": "" },
+            "ciphertext": ""
+        });
+        assert!(Keystore::from_str(&keystore.to_string()).is_err());
+
+        // Check an unknown kind of account key is rejected.
+        let mut candidate = keystore;
+        candidate["kdf"]["algorithm"] = "argon2id".into();
+        assert!(Keystore::from_str(&candidate.to_string()).is_ok());
+        candidate["kind"] = "compute_key".into();
+        assert!(Keystore::from_str(&candidate.to_string()).is_err());
+    }
+}
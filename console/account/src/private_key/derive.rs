@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child account private key at the given index.
+    ///
+    /// The child account seed is `HashPSD2(domain || seed || index)`. As the derivation requires
+    /// the parent account seed, every child is hardened, and a child reveals nothing about its parent.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Compute the child account seed.
+        let seed = N::hash_psd2(&[domain, self.seed, Field::from_u32(index)])?;
+        // Output the child private key.
+        Self::try_from(seed)
+    }
+
+    /// Returns the descendant account private key at the given path, i.e. `m/0/1/2`.
+    ///
+    /// The path starts at the master key `m`, and each component is the index of the next child.
+    pub fn derive_path(&self, path: &str) -> Result<Self> {
+        let mut components = path.split('/');
+        // Ensure the path starts at the master key.
+        ensure!(components.next() == Some("m"), "Invalid account derivation path '{path}': expected a leading 'm'");
+        // Derive the child at each index of the path.
+        components.try_fold(*self, |private_key, component| match component.parse::<u32>() {
+            Ok(index) => private_key.derive_child(index),
+            Err(_) => bail!("Invalid account derivation path '{path}': found an invalid index '{component}'"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Check the derivation is deterministic.
+            let child = private_key.derive_child(0)?;
+            assert_eq!(child, private_key.derive_child(0)?);
+
+            // Check the children are distinct from each other and from the parent.
+            assert_ne!(private_key, child);
+            assert_ne!(child, private_key.derive_child(1)?);
+            assert_ne!(child, child.derive_child(0)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Check the path derivation matches the child derivation.
+            assert_eq!(private_key, private_key.derive_path("m")?);
+            assert_eq!(private_key.derive_child(7)?, private_key.derive_path("m/7")?);
+            assert_eq!(
+                private_key.derive_child(0)?.derive_child(1)?.derive_child(u32::MAX)?,
+                private_key.derive_path("m/0/1/4294967295")?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_invalid_path() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+        assert!(private_key.derive_path("").is_err());
+        assert!(private_key.derive_path("0/1").is_err());
+        assert!(private_key.derive_path("m/").is_err());
+        assert!(private_key.derive_path("m//1").is_err());
+        assert!(private_key.derive_path("m/-1").is_err());
+        assert!(private_key.derive_path("m/1'").is_err());
+        assert!(private_key.derive_path("m/4294967296").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_restores_accounts() -> Result<()> {
+        // Sample a new private key, and back it up as a mnemonic.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let phrase = private_key.to_mnemonic()?;
+
+        // Check the restored private key derives the same accounts.
+        let restored = PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase)?;
+        for index in 0..10 {
+            let path = format!("m/0/{index}");
+            assert_eq!(private_key.derive_path(&path)?, restored.derive_path(&path)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use bip39::Mnemonic;
+
+/// The number of words in the mnemonic of an account seed.
+const MNEMONIC_NUM_WORDS: usize = 24;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key from a BIP-39 mnemonic of the account seed.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self> {
+        // Parse the mnemonic, which checks the words and the checksum.
+        let mnemonic = Mnemonic::parse(phrase).map_err(|e| anyhow!("Invalid account mnemonic: {e}"))?;
+        // Ensure the mnemonic encodes a full account seed.
+        ensure!(
+            mnemonic.word_count() == MNEMONIC_NUM_WORDS,
+            "Invalid account mnemonic length: found {} words, expected {MNEMONIC_NUM_WORDS}",
+            mnemonic.word_count()
+        );
+        // Recover the account seed from the entropy, which must be less than the field modulus.
+        let seed = Field::new(FromBytes::read_le(&mnemonic.to_entropy()[..])?);
+        // Output the private key.
+        Self::try_from(seed)
+    }
+
+    /// Returns the BIP-39 mnemonic of the account seed.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        // Encode the account seed bytes as the entropy of the mnemonic.
+        let mnemonic = Mnemonic::from_entropy(&self.seed.to_bytes_le()?).map_err(|e| anyhow!("{e}"))?;
+        // Output the mnemonic phrase.
+        Ok(mnemonic.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_mnemonic() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Check the mnemonic representation.
+            let candidate = expected.to_mnemonic()?;
+            assert_eq!(MNEMONIC_NUM_WORDS, candidate.split_whitespace().count());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&candidate)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_zero_seed() -> Result<()> {
+        // The BIP-39 test vector for 32 zero bytes of entropy.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+        let private_key = PrivateKey::<CurrentNetwork>::try_from(Field::zero())?;
+        assert_eq!(phrase, private_key.to_mnemonic()?);
+        assert_eq!(private_key, PrivateKey::from_mnemonic(phrase)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_mnemonic() {
+        // The BIP-39 test vector for 32 bytes of `0xff`, which exceeds the field modulus.
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase).is_err());
+
+        // The BIP-39 test vector for 16 bytes of entropy, which is too short for an account seed.
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase).is_err());
+
+        // A mnemonic with an invalid checksum.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase).is_err());
+
+        // A mnemonic with an unknown word.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic("aleo").is_err());
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic("").is_err());
+    }
+}
@@ -15,6 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod derive;
+mod mnemonic;
 mod serialize;
 mod string;
 mod try_from;
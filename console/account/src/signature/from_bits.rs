@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBits for Signature<N> {
+    /// Initializes a new signature from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits matches the signature size.
+        ensure!(bits_le.len() == Self::size_in_bits(), "Invalid signature size in bits");
+
+        // Split the bits into the challenge, response, and compute key components.
+        let (challenge, bits_le) = bits_le.split_at(Scalar::<N>::size_in_bits());
+        let (response, bits_le) = bits_le.split_at(Scalar::<N>::size_in_bits());
+        let (pk_sig, pr_sig) = bits_le.split_at(Field::<N>::size_in_bits());
+
+        // Recover the challenge and response.
+        let challenge = Scalar::from_bits_le(challenge)?;
+        let response = Scalar::from_bits_le(response)?;
+        // Recover the compute key from the affine x-coordinates of `pk_sig` and `pr_sig`.
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_le(pk_sig)?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_le(pr_sig)?)?;
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+
+        // Return the signature.
+        Ok(Self { challenge, response, compute_key })
+    }
+
+    /// Initializes a new signature from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Ensure the number of bits matches the signature size.
+        ensure!(bits_be.len() == Self::size_in_bits(), "Invalid signature size in bits");
+
+        // Split the bits into the challenge, response, and compute key components.
+        let (challenge, bits_be) = bits_be.split_at(Scalar::<N>::size_in_bits());
+        let (response, bits_be) = bits_be.split_at(Scalar::<N>::size_in_bits());
+        let (pk_sig, pr_sig) = bits_be.split_at(Field::<N>::size_in_bits());
+
+        // Recover the challenge and response.
+        let challenge = Scalar::from_bits_be(challenge)?;
+        let response = Scalar::from_bits_be(response)?;
+        // Recover the compute key from the affine x-coordinates of `pk_sig` and `pr_sig`.
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_be(pk_sig)?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_be(pr_sig)?)?;
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+
+        // Return the signature.
+        Ok(Self { challenge, response, compute_key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = Signature::sign(&private_key, &message, rng)?;
+
+            assert_eq!(expected, Signature::from_bits_le(&expected.to_bits_le())?);
+            assert_eq!(expected, Signature::from_bits_be(&expected.to_bits_be())?);
+
+            // Ensure an incorrect number of bits fails.
+            assert!(Signature::<CurrentNetwork>::from_bits_le(&expected.to_bits_le()[1..]).is_err());
+            assert!(Signature::<CurrentNetwork>::from_bits_be(&[expected.to_bits_be(), vec![false]].concat()).is_err());
+        }
+        Ok(())
+    }
+}
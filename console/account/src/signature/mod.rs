@@ -15,7 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod from_bits;
+mod parse;
+mod serialize;
 mod sign;
+mod size_in_bits;
+mod to_bits;
 
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
@@ -24,9 +29,9 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Field, Scalar};
+use snarkvm_console_types::{Field, Group, Scalar};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
     /// The verifier challenge to check against.
     challenge: Scalar<N>,
@@ -50,6 +55,14 @@ impl<N: Network> From<&(Scalar<N>, Scalar<N>, ComputeKey<N>)> for Signature<N> {
     }
 }
 
+impl<N: Network> TypeName for Signature<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "signature"
+    }
+}
+
 impl<N: Network> Signature<N> {
     /// Returns the verifier challenge.
     pub const fn challenge(&self) -> Scalar<N> {
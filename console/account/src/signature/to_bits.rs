@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ToBits for Signature<N> {
+    /// Returns the little-endian bits of the signature.
+    fn to_bits_le(&self) -> Vec<bool> {
+        // Allocate the `bits_le` vector.
+        let mut bits_le = Vec::with_capacity(Self::size_in_bits());
+        // Write the challenge bits.
+        bits_le.extend(self.challenge.to_bits_le());
+        // Write the response bits.
+        bits_le.extend(self.response.to_bits_le());
+        // Write the compute key bits, as the affine x-coordinates of `pk_sig` and `pr_sig`.
+        bits_le.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_le());
+        bits_le.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_le());
+        // Return the `bits_le` vector.
+        bits_le
+    }
+
+    /// Returns the big-endian bits of the signature.
+    fn to_bits_be(&self) -> Vec<bool> {
+        // Allocate the `bits_be` vector.
+        let mut bits_be = Vec::with_capacity(Self::size_in_bits());
+        // Write the challenge bits.
+        bits_be.extend(self.challenge.to_bits_be());
+        // Write the response bits.
+        bits_be.extend(self.response.to_bits_be());
+        // Write the compute key bits, as the affine x-coordinates of `pk_sig` and `pr_sig`.
+        bits_be.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_be());
+        bits_be.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_be());
+        // Return the `bits_be` vector.
+        bits_be
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_bits() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+
+            let candidate = signature.to_bits_le();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), candidate.len());
+            assert_eq!(signature.challenge().to_bits_le(), candidate[..Scalar::<CurrentNetwork>::size_in_bits()]);
+
+            let candidate = signature.to_bits_be();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), candidate.len());
+            assert_eq!(signature.challenge().to_bits_be(), candidate[..Scalar::<CurrentNetwork>::size_in_bits()]);
+        }
+        Ok(())
+    }
+}
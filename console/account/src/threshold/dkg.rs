@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static THRESHOLD_DKG_DOMAIN: &str = "AleoThresholdDKG0";
+
+/// A participant in the distributed key generation of a threshold account.
+///
+/// Every participant in a session must use the same context, which must be unique to the session
+/// (e.g. the hash of the session ID and the participant set), so that proofs can't be replayed across sessions.
+///
+/// The protocol runs in two rounds:
+///     1. Each participant broadcasts its `DkgCommitment`, from `DkgParticipant::new`.
+///     2. Each participant privately sends a `DkgShare` to every participant, from `DkgParticipant::share`.
+/// Finally, each participant derives its `KeyShare` from all commitments and shares it received.
+pub struct DkgParticipant<N: Network> {
+    /// The context identifying the session.
+    context: Field<N>,
+    /// The index of the participant, starting at 1.
+    index: u16,
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The secret polynomial for the signature secret key.
+    sk_sig_coefficients: Vec<Scalar<N>>,
+    /// The secret polynomial for the signature randomizer.
+    r_sig_coefficients: Vec<Scalar<N>>,
+}
+
+/// The round 1 broadcast of a participant in the distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DkgCommitment<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The commitments to the coefficients of the signature secret key polynomial.
+    sk_sig_commitments: Vec<Group<N>>,
+    /// The commitments to the coefficients of the signature randomizer polynomial.
+    r_sig_commitments: Vec<Group<N>>,
+    /// The proof of knowledge `(challenge, response)` of the signature secret key contribution.
+    sk_sig_proof: (Scalar<N>, Scalar<N>),
+    /// The proof of knowledge `(challenge, response)` of the signature randomizer contribution.
+    r_sig_proof: (Scalar<N>, Scalar<N>),
+}
+
+/// The round 2 private message from one participant to another in the distributed key generation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DkgShare<N: Network> {
+    /// The index of the sending participant.
+    sender: u16,
+    /// The index of the receiving participant.
+    recipient: u16,
+    /// The share of the signature secret key contribution.
+    sk_sig_share: Scalar<N>,
+    /// The share of the signature randomizer contribution.
+    r_sig_share: Scalar<N>,
+}
+
+impl<N: Network> DkgParticipant<N> {
+    /// Returns a new participant at the given index of the session with the given context,
+    /// and its round 1 commitment to broadcast.
+    pub fn new<R: Rng + CryptoRng>(
+        context: Field<N>,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, DkgCommitment<N>)> {
+        // Ensure the threshold and index are valid.
+        ensure!(
+            (1..=num_participants).contains(&threshold),
+            "Invalid threshold: found {threshold}, expected 1 to {num_participants}"
+        );
+        ensure!(
+            (1..=num_participants).contains(&index),
+            "Invalid threshold participant index: found {index}, expected 1 to {num_participants}"
+        );
+
+        // Sample the secret polynomials of degree `threshold - 1`.
+        let sk_sig_coefficients: Vec<Scalar<N>> = (0..threshold).map(|_| Uniform::rand(rng)).collect();
+        let r_sig_coefficients: Vec<Scalar<N>> = (0..threshold).map(|_| Uniform::rand(rng)).collect();
+
+        // Commit to the coefficients of the polynomials.
+        let sk_sig_commitments: Vec<_> = sk_sig_coefficients.iter().map(N::g_scalar_multiply).collect();
+        let r_sig_commitments: Vec<_> = r_sig_coefficients.iter().map(N::g_scalar_multiply).collect();
+
+        // Prove knowledge of the constant terms, which prevents rogue key attacks.
+        let sk_sig_proof = prove_knowledge(context, index, &sk_sig_coefficients[0], &sk_sig_commitments[0], rng)?;
+        let r_sig_proof = prove_knowledge(context, index, &r_sig_coefficients[0], &r_sig_commitments[0], rng)?;
+
+        let commitment = DkgCommitment { index, sk_sig_commitments, r_sig_commitments, sk_sig_proof, r_sig_proof };
+        Ok((Self { context, index, threshold, num_participants, sk_sig_coefficients, r_sig_coefficients }, commitment))
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the round 2 share for the given recipient, which must be sent over a private channel.
+    pub fn share(&self, recipient: u16) -> Result<DkgShare<N>> {
+        // Ensure the recipient is a participant.
+        ensure!(
+            (1..=self.num_participants).contains(&recipient),
+            "Invalid threshold participant index: found {recipient}, expected 1 to {}",
+            self.num_participants
+        );
+        Ok(DkgShare {
+            sender: self.index,
+            recipient,
+            sk_sig_share: evaluate(&self.sk_sig_coefficients, recipient),
+            r_sig_share: evaluate(&self.r_sig_coefficients, recipient),
+        })
+    }
+
+    /// Returns the key share of the participant, given the round 1 commitments of all participants,
+    /// and the round 2 shares sent to this participant by all participants (including itself).
+    pub fn finalize(&self, commitments: &[DkgCommitment<N>], shares: &[DkgShare<N>]) -> Result<KeyShare<N>> {
+        let num_participants = self.num_participants as usize;
+
+        // Order the commitments by participant index.
+        let commitments: Vec<_> = commitments.iter().sorted_by_key(|commitment| commitment.index).collect();
+        // Ensure there is one commitment from each participant.
+        ensure!(commitments.len() == num_participants, "Expected {num_participants} DKG commitments");
+        for (i, commitment) in (1..=self.num_participants).zip_eq(&commitments) {
+            ensure!(commitment.index == i, "Missing the DKG commitment of participant {i}");
+            // Ensure the commitments are to polynomials of degree `threshold - 1`.
+            ensure!(
+                commitment.sk_sig_commitments.len() == self.threshold as usize
+                    && commitment.r_sig_commitments.len() == self.threshold as usize,
+                "Invalid DKG commitment length from participant {i}"
+            );
+            // Ensure the proofs of knowledge are valid.
+            ensure!(
+                verify_knowledge(self.context, i, &commitment.sk_sig_commitments[0], commitment.sk_sig_proof)
+                    && verify_knowledge(self.context, i, &commitment.r_sig_commitments[0], commitment.r_sig_proof),
+                "Invalid DKG proof of knowledge from participant {i}"
+            );
+        }
+
+        // Order the shares by participant index.
+        let shares: Vec<_> = shares.iter().sorted_by_key(|share| share.sender).collect();
+        // Ensure there is one share from each participant.
+        ensure!(shares.len() == num_participants, "Expected {num_participants} DKG shares");
+        for ((i, share), commitment) in (1..=self.num_participants).zip_eq(&shares).zip_eq(&commitments) {
+            ensure!(share.sender == i, "Missing the DKG share of participant {i}");
+            ensure!(share.recipient == self.index, "Received a DKG share for participant {}", share.recipient);
+            // Ensure the shares are consistent with the commitments of the sender.
+            ensure!(
+                N::g_scalar_multiply(&share.sk_sig_share)
+                    == evaluate_in_exponent(&commitment.sk_sig_commitments, self.index)
+                    && N::g_scalar_multiply(&share.r_sig_share)
+                        == evaluate_in_exponent(&commitment.r_sig_commitments, self.index),
+                "Invalid DKG share from participant {i}"
+            );
+        }
+
+        // Compute the shares of this participant, as the sum of the shares it received.
+        let sk_sig_share = shares.iter().map(|share| share.sk_sig_share).sum();
+        let r_sig_share = shares.iter().map(|share| share.r_sig_share).sum();
+
+        // Compute pk_sig and pr_sig, as the sum of the committed constant terms.
+        let pk_sig = commitments.iter().map(|commitment| commitment.sk_sig_commitments[0]).sum();
+        let pr_sig = commitments.iter().map(|commitment| commitment.r_sig_commitments[0]).sum();
+
+        // Compute the verification share of each participant.
+        let verification_shares = (1..=self.num_participants)
+            .map(|i| commitments.iter().map(|commitment| evaluate_in_exponent(&commitment.sk_sig_commitments, i)).sum())
+            .collect();
+
+        let public_key = ThresholdPublicKey {
+            threshold: self.threshold,
+            compute_key: ComputeKey::try_from((pk_sig, pr_sig))?,
+            verification_shares,
+        };
+        Ok(KeyShare { index: self.index, sk_sig_share, r_sig_share, public_key })
+    }
+}
+
+impl<N: Network> DkgCommitment<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<N: Network> DkgShare<N> {
+    /// Returns the index of the sending participant.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the index of the receiving participant.
+    pub const fn recipient(&self) -> u16 {
+        self.recipient
+    }
+}
+
+/// Returns a proof of knowledge `(challenge, response)` of the secret for `commitment := G^secret`, where:
+///     challenge := HashToScalar(domain, context, index, commitment, nonce * G)
+///     response := nonce - challenge * secret
+fn prove_knowledge<N: Network, R: Rng + CryptoRng>(
+    context: Field<N>,
+    index: u16,
+    secret: &Scalar<N>,
+    commitment: &Group<N>,
+    rng: &mut R,
+) -> Result<(Scalar<N>, Scalar<N>)> {
+    // Sample a random nonce from the scalar field.
+    let nonce = Scalar::rand(rng);
+    // Compute the verifier challenge.
+    let challenge = knowledge_challenge(context, index, commitment, &N::g_scalar_multiply(&nonce))?;
+    // Output the proof.
+    Ok((challenge, nonce - challenge * secret))
+}
+
+/// Returns `true` if the given proof of knowledge is valid for the given commitment.
+fn verify_knowledge<N: Network>(
+    context: Field<N>,
+    index: u16,
+    commitment: &Group<N>,
+    (challenge, response): (Scalar<N>, Scalar<N>),
+) -> bool {
+    // Compute `g_r` := (response * G) + (challenge * commitment).
+    let g_r = N::g_scalar_multiply(&response) + (*commitment * challenge);
+    // Check the candidate challenge, and return `false` if it fails to compute.
+    matches!(knowledge_challenge(context, index, commitment, &g_r), Ok(candidate) if candidate == challenge)
+}
+
+/// Returns the challenge of a proof of knowledge.
+fn knowledge_challenge<N: Network>(
+    context: Field<N>,
+    index: u16,
+    commitment: &Group<N>,
+    g_r: &Group<N>,
+) -> Result<Scalar<N>> {
+    let domain = Field::new_domain_separator(THRESHOLD_DKG_DOMAIN);
+    let index = Field::from_u16(index);
+    N::hash_to_scalar_psd4(&[domain, context, index, commitment.to_x_coordinate(), g_r.to_x_coordinate()])
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the key shares of a threshold account, from running the distributed key generation.
+    pub(crate) fn sample_key_shares<N: Network>(threshold: u16, num_participants: u16) -> Result<Vec<KeyShare<N>>> {
+        let rng = &mut test_crypto_rng();
+        let context = Uniform::rand(rng);
+
+        // Round 1: Each participant broadcasts its commitment.
+        let (participants, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|index| DkgParticipant::<N>::new(context, index, threshold, num_participants, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // Round 2: Each participant sends a share to every participant.
+        participants
+            .iter()
+            .map(|recipient| {
+                let shares =
+                    participants.iter().map(|sender| sender.share(recipient.index())).collect::<Result<Vec<_>>>()?;
+                recipient.finalize(&commitments, &shares)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dkg() -> Result<()> {
+        for (threshold, num_participants) in [(1, 1), (1, 3), (2, 3), (3, 3), (3, 5)] {
+            let key_shares = sample_key_shares::<CurrentNetwork>(threshold, num_participants)?;
+
+            // Check all participants agree on the public key.
+            let public_key = key_shares[0].public_key();
+            assert!(key_shares.iter().all(|key_share| key_share.public_key() == public_key));
+            assert_eq!(threshold, public_key.threshold());
+            assert_eq!(num_participants, public_key.num_participants());
+
+            // Check each verification share matches the secret share of its participant.
+            for key_share in &key_shares {
+                let expected = CurrentNetwork::g_scalar_multiply(&key_share.sk_sig_share);
+                assert_eq!(expected, public_key.verification_share(key_share.index())?);
+            }
+
+            // Check any `threshold` shares interpolate to the secret key of `pk_sig`.
+            let indices: Vec<_> = key_shares.iter().rev().take(threshold as usize).map(KeyShare::index).collect();
+            let mut sk_sig = Scalar::zero();
+            for key_share in key_shares.iter().rev().take(threshold as usize) {
+                sk_sig += lagrange_coefficient::<CurrentNetwork>(key_share.index(), &indices)? * key_share.sk_sig_share;
+            }
+            assert_eq!(public_key.compute_key().pk_sig(), CurrentNetwork::g_scalar_multiply(&sk_sig));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_invalid_parameters() {
+        let rng = &mut test_crypto_rng();
+        let context = Uniform::rand(rng);
+
+        assert!(DkgParticipant::<CurrentNetwork>::new(context, 1, 0, 3, rng).is_err());
+        assert!(DkgParticipant::<CurrentNetwork>::new(context, 1, 4, 3, rng).is_err());
+        assert!(DkgParticipant::<CurrentNetwork>::new(context, 0, 2, 3, rng).is_err());
+        assert!(DkgParticipant::<CurrentNetwork>::new(context, 4, 2, 3, rng).is_err());
+    }
+
+    #[test]
+    fn test_dkg_invalid_messages() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+        let context = Uniform::rand(rng);
+
+        // Run round 1 for three participants.
+        let (participants, commitments): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| DkgParticipant::<CurrentNetwork>::new(context, index, 2, 3, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let shares = participants.iter().map(|sender| sender.share(1)).collect::<Result<Vec<_>>>()?;
+        assert!(participants[0].finalize(&commitments, &shares).is_ok());
+        assert!(participants[0].share(4).is_err());
+
+        // Check a missing commitment or share is rejected.
+        assert!(participants[0].finalize(&commitments[1..], &shares).is_err());
+        assert!(participants[0].finalize(&commitments, &shares[1..]).is_err());
+
+        // Check a share for another participant is rejected.
+        let mut candidate = shares.clone();
+        candidate[1] = participants[1].share(2)?;
+        assert!(participants[0].finalize(&commitments, &candidate).is_err());
+
+        // Check a share that is inconsistent with its commitment is rejected.
+        let mut candidate = shares.clone();
+        candidate[1].sk_sig_share += Scalar::one();
+        assert!(participants[0].finalize(&commitments, &candidate).is_err());
+
+        // Check a commitment with an invalid proof of knowledge is rejected.
+        let mut candidate = commitments.clone();
+        candidate[2].sk_sig_commitments[0] += CurrentNetwork::g_scalar_multiply(&Scalar::one());
+        assert!(participants[0].finalize(&candidate, &shares).is_err());
+
+        // Check a commitment replayed from another session is rejected.
+        for (candidate_context, is_valid) in [(context, true), (Uniform::rand(rng), false)] {
+            let (participant, commitment) = DkgParticipant::<CurrentNetwork>::new(candidate_context, 3, 2, 3, rng)?;
+            let (mut candidate, mut candidate_shares) = (commitments.clone(), shares.clone());
+            candidate[2] = commitment;
+            candidate_shares[2] = participant.share(1)?;
+            assert_eq!(is_valid, participants[0].finalize(&candidate, &candidate_shares).is_ok());
+        }
+
+        // Check a commitment with the wrong degree is rejected.
+        let mut candidate = commitments;
+        candidate[2].r_sig_commitments.pop();
+        assert!(participants[0].finalize(&candidate, &shares).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod dkg;
+pub use dkg::*;
+
+mod sign;
+pub use sign::*;
+
+use crate::{Address, ComputeKey, Signature, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The share of a threshold account, which is held by a single participant.
+///
+/// The signature secret key `sk_sig` and randomizer `r_sig` of the account are never reconstructed;
+/// instead, each participant holds a Shamir share of both, which is output by the distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyShare<N: Network> {
+    /// The index of the participant, starting at 1.
+    index: u16,
+    /// The participant share of the signature secret key.
+    sk_sig_share: Scalar<N>,
+    /// The participant share of the signature randomizer.
+    r_sig_share: Scalar<N>,
+    /// The public key of the threshold account.
+    public_key: ThresholdPublicKey<N>,
+}
+
+/// The public key of a threshold account, which is known to all participants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdPublicKey<N: Network> {
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The compute key of the threshold account.
+    compute_key: ComputeKey<N>,
+    /// The verification share `G^sk_sig_share` of each participant, in order of index.
+    verification_shares: Vec<Group<N>>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the public key of the threshold account.
+    pub const fn public_key(&self) -> &ThresholdPublicKey<N> {
+        &self.public_key
+    }
+
+    /// Returns the participant share of the account view key, `sk_sig_share + r_sig_share`.
+    ///
+    /// Any `threshold` view key shares recover the account view key, without revealing `sk_sig`.
+    pub fn view_key_share(&self) -> Scalar<N> {
+        self.sk_sig_share + self.r_sig_share
+    }
+}
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Returns the number of participants required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> u16 {
+        self.verification_shares.len() as u16
+    }
+
+    /// Returns the compute key of the threshold account.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address of the threshold account.
+    pub fn address(&self) -> Result<Address<N>> {
+        Address::try_from(self.compute_key)
+    }
+
+    /// Returns the verification share of the participant at the given index.
+    pub fn verification_share(&self, index: u16) -> Result<Group<N>> {
+        self.check_index(index)?;
+        Ok(self.verification_shares[index as usize - 1])
+    }
+
+    /// Returns the account view key, recovered from the given `(index, view_key_share)` pairs
+    /// of at least `threshold` participants.
+    pub fn recover_view_key(&self, view_key_shares: &[(u16, Scalar<N>)]) -> Result<ViewKey<N>> {
+        // Ensure the indices are valid and distinct.
+        let indices: Vec<u16> = view_key_shares.iter().map(|(index, _)| *index).collect();
+        self.check_indices(&indices)?;
+        // Interpolate the view key shares at zero, to recover `sk_sig + r_sig`.
+        let mut view_key = Scalar::zero();
+        for (index, view_key_share) in view_key_shares {
+            view_key += lagrange_coefficient::<N>(*index, &indices)? * view_key_share;
+        }
+        // Compute view_key := sk_sig + r_sig + sk_prf.
+        let view_key = ViewKey::from_scalar(view_key + self.compute_key.sk_prf());
+        // Ensure the view key corresponds to the account address.
+        ensure!(Address::try_from(view_key)? == self.address()?, "Failed to recover the threshold view key");
+        Ok(view_key)
+    }
+
+    /// Ensures the given index belongs to a participant.
+    fn check_index(&self, index: u16) -> Result<()> {
+        ensure!(
+            (1..=self.num_participants()).contains(&index),
+            "Invalid threshold participant index: found {index}, expected 1 to {}",
+            self.num_participants()
+        );
+        Ok(())
+    }
+
+    /// Ensures the given indices belong to at least `threshold` distinct participants.
+    fn check_indices(&self, indices: &[u16]) -> Result<()> {
+        ensure!(
+            indices.len() >= self.threshold as usize,
+            "Expected at least {} threshold participants, found {}",
+            self.threshold,
+            indices.len()
+        );
+        ensure!(!has_duplicates(indices), "Found duplicate threshold participant indices");
+        indices.iter().try_for_each(|index| self.check_index(*index))
+    }
+}
+
+/// Returns the given participant index as a scalar.
+fn to_scalar<N: Network>(index: u16) -> Scalar<N> {
+    Scalar::new(N::Scalar::from(index))
+}
+
+/// Returns the Lagrange coefficient of the given participant index, for interpolation at zero
+/// over the given participant indices.
+fn lagrange_coefficient<N: Network>(index: u16, indices: &[u16]) -> Result<Scalar<N>> {
+    let (mut numerator, mut denominator) = (Scalar::<N>::one(), Scalar::<N>::one());
+    for other in indices.iter().filter(|other| **other != index) {
+        numerator *= to_scalar::<N>(*other);
+        denominator *= to_scalar::<N>(*other) - to_scalar::<N>(index);
+    }
+    Ok(numerator * denominator.inverse()?)
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given participant index.
+fn evaluate<N: Network>(coefficients: &[Scalar<N>], index: u16) -> Scalar<N> {
+    let x = to_scalar::<N>(index);
+    coefficients.iter().rev().fold(Scalar::zero(), |evaluation, coefficient| evaluation * x + coefficient)
+}
+
+/// Returns the evaluation of the polynomial committed to by the given commitments at the given participant index.
+fn evaluate_in_exponent<N: Network>(commitments: &[Group<N>], index: u16) -> Group<N> {
+    let x = to_scalar::<N>(index);
+    commitments.iter().rev().fold(Group::zero(), |evaluation, commitment| evaluation * x + commitment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::dkg::tests::sample_key_shares;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_lagrange_interpolation() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random polynomial of degree 2.
+            let coefficients: Vec<Scalar<CurrentNetwork>> = (0..3).map(|_| Uniform::rand(rng)).collect();
+
+            // Check any 3 evaluations interpolate to the constant term.
+            for indices in [[1u16, 2, 3], [1, 3, 5], [2, 4, 7]] {
+                let mut candidate = Scalar::zero();
+                for index in indices {
+                    let coefficient = lagrange_coefficient::<CurrentNetwork>(index, &indices)?;
+                    candidate += coefficient * evaluate(&coefficients, index);
+                }
+                assert_eq!(coefficients[0], candidate);
+            }
+
+            // Check the evaluation in the exponent matches the evaluation.
+            let commitments: Vec<_> = coefficients.iter().map(CurrentNetwork::g_scalar_multiply).collect();
+            let expected = CurrentNetwork::g_scalar_multiply(&evaluate(&coefficients, 4));
+            assert_eq!(expected, evaluate_in_exponent(&commitments, 4));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_view_key() -> Result<()> {
+        let key_shares = sample_key_shares::<CurrentNetwork>(2, 3)?;
+        let public_key = key_shares[0].public_key();
+
+        // Check any `threshold` view key shares recover the view key of the threshold address.
+        let view_key_shares: Vec<_> = key_shares.iter().map(|share| (share.index(), share.view_key_share())).collect();
+        let view_key = public_key.recover_view_key(&view_key_shares[1..])?;
+        assert_eq!(public_key.address()?, Address::try_from(view_key)?);
+        assert_eq!(view_key, public_key.recover_view_key(&view_key_shares)?);
+
+        // Check fewer than `threshold` view key shares are rejected.
+        assert!(public_key.recover_view_key(&view_key_shares[..1]).is_err());
+        Ok(())
+    }
+}
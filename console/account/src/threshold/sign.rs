@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static THRESHOLD_BINDING_DOMAIN: &str = "AleoThresholdBinding0";
+
+/// The secret nonces of a signer for a single threshold signature, which must never be reused.
+pub struct SigningNonces<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The hiding nonce.
+    hiding: Scalar<N>,
+    /// The binding nonce.
+    binding: Scalar<N>,
+}
+
+/// The round 1 broadcast of a signer for a single threshold signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitment<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The commitment `G^hiding` to the hiding nonce.
+    hiding: Group<N>,
+    /// The commitment `G^binding` to the binding nonce.
+    binding: Group<N>,
+}
+
+/// The round 2 response of a signer for a single threshold signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The share of the prover response.
+    response: Scalar<N>,
+}
+
+impl<N: Network> SigningCommitment<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the secret nonces to keep, and the commitment to broadcast, for round 1 of a threshold signature.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitment<N>) {
+        // Sample random nonces from the scalar field.
+        let (hiding, binding) = (Scalar::rand(rng), Scalar::rand(rng));
+        // Commit to the nonces.
+        let commitment = SigningCommitment {
+            index: self.index,
+            hiding: N::g_scalar_multiply(&hiding),
+            binding: N::g_scalar_multiply(&binding),
+        };
+        (SigningNonces { index: self.index, hiding, binding }, commitment)
+    }
+
+    /// Returns the signature share for round 2 of a threshold signature, where:
+    ///     response_share := hiding + binding * binding_factor - lagrange_coefficient * sk_sig_share * challenge
+    ///
+    /// The nonces are consumed, as reusing them across messages or commitments reveals the secret key share.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces<N>,
+        message: &[Field<N>],
+        commitments: &[SigningCommitment<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the nonces belong to this signer.
+        ensure!(nonces.index == self.index, "Expected the signing nonces of participant {}", self.index);
+        // Ensure the commitment of this signer is included, and matches its nonces.
+        ensure!(
+            commitments.iter().any(|commitment| commitment.index == self.index
+                && commitment.hiding == N::g_scalar_multiply(&nonces.hiding)
+                && commitment.binding == N::g_scalar_multiply(&nonces.binding)),
+            "Missing the signing commitment of participant {}",
+            self.index
+        );
+
+        // Compute the signing package.
+        let (commitments, binding_factors, challenge) = self.public_key.signing_package(message, commitments)?;
+        let indices: Vec<u16> = commitments.iter().map(|commitment| commitment.index).collect();
+        let binding_factor = match indices.iter().position(|index| *index == self.index) {
+            Some(position) => binding_factors[position],
+            None => bail!("Missing the signing commitment of participant {}", self.index),
+        };
+
+        // Compute the signature share.
+        let lagrange_coefficient = lagrange_coefficient::<N>(self.index, &indices)?;
+        let response =
+            nonces.hiding + (nonces.binding * binding_factor) - (lagrange_coefficient * self.sk_sig_share * challenge);
+        Ok(SignatureShare { index: self.index, response })
+    }
+}
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Returns the signature for the given message, aggregated from the round 1 commitments and the round 2 shares
+    /// of the signers. The result is a regular account signature, which verifies under the threshold address.
+    pub fn aggregate(
+        &self,
+        message: &[Field<N>],
+        commitments: &[SigningCommitment<N>],
+        shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Compute the signing package.
+        let (commitments, binding_factors, challenge) = self.signing_package(message, commitments)?;
+        let indices: Vec<u16> = commitments.iter().map(|commitment| commitment.index).collect();
+
+        // Order the shares by signer index.
+        let shares: Vec<_> = shares.iter().sorted_by_key(|share| share.index).collect();
+        // Ensure there is one share from each signer.
+        ensure!(shares.len() == commitments.len(), "Expected {} signature shares", commitments.len());
+
+        let mut response = Scalar::zero();
+        for ((commitment, binding_factor), share) in commitments.iter().zip_eq(&binding_factors).zip_eq(&shares) {
+            ensure!(share.index == commitment.index, "Missing the signature share of participant {}", commitment.index);
+
+            // Ensure the share is valid, to identify a misbehaving signer:
+            //     G^response_share == hiding + binding * binding_factor - Y^(lagrange_coefficient * challenge)
+            let lagrange_coefficient = lagrange_coefficient::<N>(share.index, &indices)?;
+            let verification_share = self.verification_share(share.index)?;
+            ensure!(
+                N::g_scalar_multiply(&share.response)
+                    == commitment.hiding + (commitment.binding * binding_factor)
+                        - (verification_share * (lagrange_coefficient * challenge)),
+                "Invalid signature share from participant {}",
+                share.index
+            );
+
+            response += share.response;
+        }
+
+        // Output the signature.
+        Ok(Signature::from((challenge, response, self.compute_key)))
+    }
+
+    /// Returns the signing package `(commitments, binding_factors, challenge)` for the given message, where:
+    ///     binding_factor_i := HashToScalar(domain, address, len(commitments), commitments, len(message), message, i)
+    ///     g_r := sum_i (hiding_i + binding_i * binding_factor_i)
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    /// The commitments are output in order of signer index.
+    fn signing_package(
+        &self,
+        message: &[Field<N>],
+        commitments: &[SigningCommitment<N>],
+    ) -> Result<(Vec<SigningCommitment<N>>, Vec<Scalar<N>>, Scalar<N>)> {
+        // Order the commitments by signer index.
+        let commitments: Vec<_> = commitments.iter().copied().sorted_by_key(|commitment| commitment.index).collect();
+        // Ensure the signers are at least `threshold` distinct participants.
+        self.check_indices(&commitments.iter().map(|commitment| commitment.index).collect::<Vec<_>>())?;
+
+        // Retrieve pk_sig.
+        let pk_sig = self.compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = self.compute_key.pr_sig();
+        // Derive the address from the compute key.
+        let address = self.address()?;
+
+        // Construct the binding factor input as (domain, address, commitments, message),
+        // where the commitments and the message are each prefixed by their length.
+        let mut preimage = Vec::with_capacity(4 + 5 * commitments.len() + message.len() + 1);
+        preimage.push(Field::new_domain_separator(THRESHOLD_BINDING_DOMAIN));
+        preimage.push(address.to_x_coordinate());
+        preimage.push(Field::from_u64(commitments.len() as u64));
+        for commitment in &commitments {
+            let (hiding_x, hiding_y) = commitment.hiding.to_xy_coordinate();
+            let (binding_x, binding_y) = commitment.binding.to_xy_coordinate();
+            preimage.extend([Field::from_u16(commitment.index), hiding_x, hiding_y, binding_x, binding_y]);
+        }
+        preimage.push(Field::from_u64(message.len() as u64));
+        preimage.extend(message);
+
+        // Compute the binding factor of each signer, and the group commitment `g_r`.
+        let mut binding_factors = Vec::with_capacity(commitments.len());
+        let mut g_r = Group::zero();
+        for commitment in &commitments {
+            preimage.push(Field::from_u16(commitment.index));
+            let binding_factor = N::hash_to_scalar_psd8(&preimage)?;
+            preimage.pop();
+
+            g_r += commitment.hiding + (commitment.binding * binding_factor);
+            binding_factors.push(binding_factor);
+        }
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok((commitments, binding_factors, challenge))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::dkg::tests::sample_key_shares;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Returns the threshold signature of the given signers on the given message.
+    fn threshold_sign(
+        key_shares: &[&KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+    ) -> Result<Signature<CurrentNetwork>> {
+        let rng = &mut test_crypto_rng();
+
+        // Round 1: Each signer broadcasts its commitment.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares.iter().map(|key_share| key_share.commit(rng)).unzip();
+        // Round 2: Each signer responds with its signature share.
+        let shares = key_shares
+            .iter()
+            .zip_eq(nonces)
+            .map(|(key_share, nonces)| key_share.sign(nonces, message, &commitments))
+            .collect::<Result<Vec<_>>>()?;
+        // Aggregate the signature shares.
+        key_shares[0].public_key().aggregate(message, &commitments, &shares)
+    }
+
+    #[test]
+    fn test_threshold_sign() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let key_shares = sample_key_shares::<CurrentNetwork>(threshold, num_participants)?;
+            let address = key_shares[0].public_key().address()?;
+
+            for i in 0..ITERATIONS {
+                // Sample a random message.
+                let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+                // Check any `threshold` or more signers produce a valid signature.
+                for signers in key_shares.iter().combinations(threshold as usize).chain([key_shares.iter().collect()]) {
+                    let signature = threshold_sign(&signers, &message)?;
+                    assert!(signature.verify(&address, &message));
+                    assert_eq!(address, signature.signer()?);
+
+                    // Check the signature does not verify for another message.
+                    let other: Vec<_> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+                    assert!(!signature.verify(&address, &other));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_too_few_signers() -> Result<()> {
+        let key_shares = sample_key_shares::<CurrentNetwork>(3, 5)?;
+        let message = [Field::from_u8(1)];
+
+        // Check fewer than `threshold` signers are rejected.
+        assert!(threshold_sign(&[&key_shares[0], &key_shares[1]], &message).is_err());
+        // Check duplicate signers are rejected.
+        assert!(threshold_sign(&[&key_shares[0], &key_shares[1], &key_shares[1]], &message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_invalid_share() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let key_shares = sample_key_shares::<CurrentNetwork>(2, 3)?;
+        let public_key = key_shares[0].public_key();
+        let message = [Field::from_u8(1), Field::from_u8(2)];
+
+        // Run round 1 for the first two signers.
+        let (nonces_0, commitment_0) = key_shares[0].commit(rng);
+        let (nonces_1, commitment_1) = key_shares[1].commit(rng);
+        let commitments = [commitment_0, commitment_1];
+
+        // Check a signer rejects commitments that do not include its own.
+        let (nonces_2, _) = key_shares[2].commit(rng);
+        assert!(key_shares[2].sign(nonces_2, &message, &commitments).is_err());
+
+        // Run round 2 for the first two signers.
+        let share_0 = key_shares[0].sign(nonces_0, &message, &commitments)?;
+        let share_1 = key_shares[1].sign(nonces_1, &message, &commitments)?;
+        assert!(public_key.aggregate(&message, &commitments, &[share_1, share_0]).is_ok());
+
+        // Check a missing or modified share is rejected.
+        assert!(public_key.aggregate(&message, &commitments, &[share_0]).is_err());
+        let mut candidate = share_1;
+        candidate.response += Scalar::one();
+        assert!(public_key.aggregate(&message, &commitments, &[share_0, candidate]).is_err());
+
+        // Check the shares do not aggregate for another message.
+        assert!(public_key.aggregate(&[Field::from_u8(3)], &commitments, &[share_0, share_1]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use crate::Blake2Xs;
+use snarkvm_console_types::prelude::*;
+use snarkvm_curves::secp256k1::{Fq, Fr, Secp256k1Affine};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger256;
+
+/// The number of bits in a public key, which is the uncompressed `(x, y)` point.
+pub const PUBLIC_KEY_SIZE_IN_BITS: usize = 512;
+/// The number of bits in a digest.
+pub const DIGEST_SIZE_IN_BITS: usize = 256;
+/// The number of bits in a signature, which is the `(r, s)` pair.
+pub const SIGNATURE_SIZE_IN_BITS: usize = 512;
+
+/// ECDSA signature verification over the secp256k1 curve, as used by Ethereum and Bitcoin.
+///
+/// The public key, digest, and signature are read as big-endian byte strings, with each byte
+/// in little-endian bit order, such that verifying `bytes.to_bits_le()` for the 64-byte public key
+/// `x || y`, the 32-byte digest, and the 64-byte signature `r || s` follows the standard encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ECDSASecp256k1 {
+    /// The offset point for the scalar multiplication in the circuit.
+    offset: Secp256k1Affine,
+}
+
+impl ECDSASecp256k1 {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub fn setup(message: &str) -> Self {
+        // Sample an offset point of unknown discrete logarithm.
+        let (offset, _, _) = Blake2Xs::hash_to_curve::<Secp256k1Affine>(&format!("{message} Offset"));
+        Self { offset }
+    }
+
+    /// Returns the generator of the secp256k1 group.
+    pub fn generator(&self) -> Secp256k1Affine {
+        Secp256k1Affine::prime_subgroup_generator()
+    }
+
+    /// Returns the offset point for the scalar multiplication in the circuit.
+    pub const fn offset(&self) -> Secp256k1Affine {
+        self.offset
+    }
+}
+
+/// Returns the field element for the given big-endian bytes, in little-endian bit order,
+/// or `None` if the encoded integer is not less than the modulus.
+fn from_bits_be_bytes<F: PrimeField<BigInteger = BigInteger256>>(bits: &[bool]) -> Option<F> {
+    // Collect the bytes in little-endian order.
+    let bytes = bits
+        .chunks(8)
+        .rev()
+        .map(|byte| byte.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+        .collect::<Vec<_>>();
+    BigInteger256::read_le(&bytes[..]).ok().and_then(F::from_repr)
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl ECDSASecp256k1 {
+    /// Returns `true` if the signature is valid for the given public key and digest.
+    ///
+    /// Returns `false` if the public key is not a point on the curve,
+    /// or if either half of the signature is not in `[1, n - 1]`.
+    pub fn verify(&self, public_key: &[bool], digest: &[bool], signature: &[bool]) -> Result<bool> {
+        // Ensure the inputs are of the expected sizes.
+        ensure!(
+            public_key.len() == PUBLIC_KEY_SIZE_IN_BITS,
+            "ECDSA public key must be {PUBLIC_KEY_SIZE_IN_BITS} bits, found {} bits",
+            public_key.len()
+        );
+        ensure!(
+            digest.len() == DIGEST_SIZE_IN_BITS,
+            "ECDSA digest must be {DIGEST_SIZE_IN_BITS} bits, found {} bits",
+            digest.len()
+        );
+        ensure!(
+            signature.len() == SIGNATURE_SIZE_IN_BITS,
+            "ECDSA signature must be {SIGNATURE_SIZE_IN_BITS} bits, found {} bits",
+            signature.len()
+        );
+
+        // Parse the public key, and ensure it is on the curve.
+        let public_key = match (from_bits_be_bytes::<Fq>(&public_key[..256]), from_bits_be_bytes(&public_key[256..])) {
+            (Some(x), Some(y)) => Secp256k1Affine::new(x, y, false),
+            _ => return Ok(false),
+        };
+        if !public_key.is_on_curve() {
+            return Ok(false);
+        }
+
+        // Parse the signature, and ensure both halves are nonzero.
+        let (r, s) = match (from_bits_be_bytes::<Fr>(&signature[..256]), from_bits_be_bytes::<Fr>(&signature[256..])) {
+            (Some(r), Some(s)) if !r.is_zero() && !s.is_zero() => (r, s),
+            _ => return Ok(false),
+        };
+
+        // Reduce the digest into the scalar field.
+        let digest = digest
+            .chunks(8)
+            .map(|byte| byte.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .collect::<Vec<_>>();
+        let z = Fr::from_bytes_be_mod_order(&digest);
+
+        // Compute `R := (z / s) * G + (r / s) * Q`.
+        let s_inverse = match s.inverse() {
+            Some(s_inverse) => s_inverse,
+            None => return Ok(false),
+        };
+        let point = (self.generator() * (z * s_inverse) + public_key * (r * s_inverse)).to_affine();
+        if point.is_zero() {
+            return Ok(false);
+        }
+
+        // Ensure the x-coordinate of `R`, reduced into the scalar field, is equal to `r`.
+        Ok(Fr::from_bytes_le_mod_order(&point.x.to_repr().to_bytes_le()?) == r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the little-endian bits of the big-endian bytes of the given field element.
+    fn to_bits_be_bytes<F: PrimeField>(element: &F) -> Result<Vec<bool>> {
+        let mut bytes = element.to_repr().to_bytes_le()?;
+        bytes.reverse();
+        Ok(to_bits_le(&bytes))
+    }
+
+    /// Returns the public key, digest, and signature for a randomly sampled key and digest.
+    fn sample_signature<R: Rng>(rng: &mut R) -> Result<(Vec<bool>, Vec<bool>, Vec<bool>)> {
+        let private_key = Fr::rand(rng);
+        let nonce = Fr::rand(rng);
+        let digest = (0..32).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+
+        // Compute the public key.
+        let public_key = (Secp256k1Affine::prime_subgroup_generator() * private_key).to_affine();
+        // Compute the signature as `r := (k * G).x` and `s := (z + r * d) / k`.
+        let r = Fr::from_bytes_le_mod_order(
+            &(Secp256k1Affine::prime_subgroup_generator() * nonce).to_affine().x.to_repr().to_bytes_le()?,
+        );
+        let s = (Fr::from_bytes_be_mod_order(&digest) + r * private_key) * nonce.inverse().unwrap();
+
+        let public_key = [to_bits_be_bytes(&public_key.x)?, to_bits_be_bytes(&public_key.y)?].concat();
+        let signature = [to_bits_be_bytes(&r)?, to_bits_be_bytes(&s)?].concat();
+        Ok((public_key, to_bits_le(&digest), signature))
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let ecdsa = ECDSASecp256k1::setup("ECDSASecp256k1Test");
+
+        let public_key = to_bits_le(&hex::decode(
+            "18c1c3718e5aae9ee90ce68d49ebd0821eff8821e53e38ef1c188d9c7310863c\
+             b5f224384dc08f6e8d2f5bd66225e629443b987fefbfccf2d2c3eb8ff95c8082",
+        )?);
+        let digest = to_bits_le(&hex::decode("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9")?);
+        let signature = to_bits_le(&hex::decode(
+            "17f53289eac961e5adc858d3ca50dab056ddca7a1a906c0815a0369312d1aa49\
+             58624d9b5c649dccbcfa65fdcc8760329175ddf081426a4c11b69f98bcc0b01c",
+        )?);
+        assert!(ecdsa.verify(&public_key, &digest, &signature)?);
+
+        // Ensure a different digest fails to verify.
+        let mut candidate = digest.clone();
+        candidate[0] = !candidate[0];
+        assert!(!ecdsa.verify(&public_key, &candidate, &signature)?);
+
+        // Ensure a different signature fails to verify.
+        let mut candidate = signature.clone();
+        candidate[300] = !candidate[300];
+        assert!(!ecdsa.verify(&public_key, &digest, &candidate)?);
+
+        // Ensure a public key that is not on the curve fails to verify.
+        let mut candidate = public_key.clone();
+        candidate[0] = !candidate[0];
+        assert!(!ecdsa.verify(&candidate, &digest, &signature)?);
+
+        // Ensure inputs of the wrong size fail.
+        assert!(ecdsa.verify(&public_key[1..], &digest, &signature).is_err());
+        assert!(ecdsa.verify(&public_key, &digest[1..], &signature).is_err());
+        assert!(ecdsa.verify(&public_key, &digest, &signature[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_random() -> Result<()> {
+        let ecdsa = ECDSASecp256k1::setup("ECDSASecp256k1Test");
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let (public_key, digest, signature) = sample_signature(rng)?;
+            assert!(ecdsa.verify(&public_key, &digest, &signature)?);
+
+            // Ensure the signature fails to verify for another public key.
+            let (other_public_key, _, _) = sample_signature(rng)?;
+            assert!(!ecdsa.verify(&other_public_key, &digest, &signature)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_out_of_range() -> Result<()> {
+        let ecdsa = ECDSASecp256k1::setup("ECDSASecp256k1Test");
+        let (public_key, digest, signature) = sample_signature(&mut test_rng())?;
+
+        // Ensure `r` and `s` must be nonzero.
+        let zero = vec![false; 256];
+        assert!(!ecdsa.verify(&public_key, &digest, &[zero.clone(), signature[256..].to_vec()].concat())?);
+        assert!(!ecdsa.verify(&public_key, &digest, &[signature[..256].to_vec(), zero].concat())?);
+
+        // Ensure `r` and `s` must be less than the group order.
+        let max = vec![true; 256];
+        assert!(!ecdsa.verify(&public_key, &digest, &[max.clone(), signature[256..].to_vec()].concat())?);
+        assert!(!ecdsa.verify(&public_key, &digest, &[signature[..256].to_vec(), max.clone()].concat())?);
+
+        // Ensure the coordinates must be less than the field modulus.
+        assert!(!ecdsa.verify(&[max.clone(), public_key[256..].to_vec()].concat(), &digest, &signature)?);
+        assert!(!ecdsa.verify(&[public_key[..256].to_vec(), max].concat(), &digest, &signature)?);
+        Ok(())
+    }
+}
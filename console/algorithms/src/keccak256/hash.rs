@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Keccak256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak-256 digest of the given input, as 256 bits.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Pad the message with the `pad10*1` rule, to a multiple of the rate.
+        let mut message = input.to_vec();
+        message.push(true);
+        message.resize(message.len() + (RATE - 1 - message.len() % RATE) % RATE, false);
+        message.push(true);
+
+        // Absorb each block of the message into the state.
+        let mut state = [0u64; 25];
+        for block in message.chunks(RATE) {
+            for (lane, bits) in state.iter_mut().zip(block.chunks(64)) {
+                *lane ^= bits.iter().rev().fold(0u64, |lane, bit| (lane << 1) | *bit as u64);
+            }
+            Self::permute(&mut state);
+        }
+
+        // Squeeze the first 256 bits of the state, in little-endian bits per lane.
+        Ok(state.iter().take(4).flat_map(|lane| (0..64).map(move |i| (lane >> i) & 1 == 1)).collect())
+    }
+}
+
+impl Keccak256 {
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(state: &mut [u64; 25]) {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta
+            let c = (0..5).map(|x| (0..5).fold(0u64, |acc, y| acc ^ state[x + 5 * y])).collect::<Vec<_>>();
+            for (i, lane) in state.iter_mut().enumerate() {
+                *lane ^= c[(i + 4) % 5] ^ c[(i + 1) % 5].rotate_left(1);
+            }
+
+            // Rho and Pi
+            let mut b = [0u64; 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+
+            // Chi
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+
+            // Iota
+            state[0] ^= round_constant;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_hash(input: &[u8], expected: &str) -> Result<()> {
+        let candidate = Keccak256.hash(&to_bits_le(input))?;
+        assert_eq!(to_bits_le(&hex::decode(expected)?), candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        check_hash(b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")?;
+        check_hash(b"abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")?;
+        check_hash(&[b'a'; 200], "96ea54061def936c4be90b518992fdc6f12f535068a256229aca54267b4d084d")
+    }
+}
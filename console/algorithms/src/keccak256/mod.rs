@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// The round constants for the Keccak-f[1600] permutation.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets for the Keccak-f[1600] permutation, indexed by `x + 5 * y`.
+const ROTATION_OFFSETS: [u32; 25] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// The number of bits absorbed into the state per permutation, for a 256-bit digest.
+const RATE: usize = 1088;
+
+/// Keccak-256 is the Keccak sponge function with a 256-bit digest, as used in Ethereum.
+/// Note that this is the original Keccak padding, which differs from the NIST SHA3-256 standard.
+///
+/// The input bits are read as bytes in little-endian bit order, and the digest bytes are returned
+/// in the same order, such that hashing `bytes.to_bits_le()` returns `keccak256(bytes).to_bits_le()`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak256;
+
+impl Keccak256 {
+    /// Returns the round constants for the permutation.
+    pub const fn round_constants(&self) -> &[u64; 24] {
+        &ROUND_CONSTANTS
+    }
+
+    /// Returns the rotation offsets for the permutation, indexed by `x + 5 * y`.
+    pub const fn rotation_offsets(&self) -> &[u32; 25] {
+        &ROTATION_OFFSETS
+    }
+
+    /// Returns the number of bits absorbed into the state per permutation.
+    pub const fn rate(&self) -> usize {
+        RATE
+    }
+}
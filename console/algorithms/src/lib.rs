@@ -25,11 +25,20 @@ pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::ECDSASecp256k1;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak256;
+pub use keccak256::Keccak256;
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod sha256;
+pub use sha256::Sha256;
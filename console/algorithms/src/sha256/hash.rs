@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 digest of the given input, as 256 bits.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Reorder the input bits into big-endian bits per byte, as SHA-256 is defined over big-endian bits.
+        let mut message = input.chunks(8).flat_map(|byte| byte.iter().rev().copied()).collect::<Vec<_>>();
+
+        // Pad the message with a `1` bit, zero bits, and the 64-bit message length, to a multiple of 512 bits.
+        let num_bits = message.len() as u64;
+        message.push(true);
+        message.resize(message.len() + (448 + 512 - message.len() % 512) % 512, false);
+        message.extend((0..64).rev().map(|i| (num_bits >> i) & 1 == 1));
+
+        // Compress each 512-bit block into the state.
+        let mut state = INITIAL_STATE;
+        for block in message.chunks(512) {
+            // Construct the message schedule.
+            let mut schedule = [0u32; 64];
+            for (word, bits) in schedule.iter_mut().zip(block.chunks(32)) {
+                *word = bits.iter().fold(0u32, |word, bit| (word << 1) | *bit as u32);
+            }
+            for t in 16..64 {
+                let s0 = schedule[t - 15].rotate_right(7) ^ schedule[t - 15].rotate_right(18) ^ (schedule[t - 15] >> 3);
+                let s1 = schedule[t - 2].rotate_right(17) ^ schedule[t - 2].rotate_right(19) ^ (schedule[t - 2] >> 10);
+                schedule[t] = schedule[t - 16].wrapping_add(s0).wrapping_add(schedule[t - 7]).wrapping_add(s1);
+            }
+
+            // Perform the rounds of the compression function.
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+            for (round_constant, word) in ROUND_CONSTANTS.iter().zip_eq(schedule.iter()) {
+                let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+                let ch = (e & f) ^ (!e & g);
+                let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*round_constant).wrapping_add(*word);
+                let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+                let maj = (a & b) ^ (a & c) ^ (b & c);
+                let t2 = s0.wrapping_add(maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = d.wrapping_add(t1);
+                d = c;
+                c = b;
+                b = a;
+                a = t1.wrapping_add(t2);
+            }
+
+            // Add the compressed block into the state.
+            for (word, value) in state.iter_mut().zip_eq([a, b, c, d, e, f, g, h]) {
+                *word = word.wrapping_add(value);
+            }
+        }
+
+        // Return the digest as bytes, in little-endian bits per byte.
+        Ok(state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_hash(input: &[u8], expected: &str) -> Result<()> {
+        let candidate = Sha256.hash(&to_bits_le(input))?;
+        assert_eq!(to_bits_le(&hex::decode(expected)?), candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        check_hash(b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")?;
+        check_hash(b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")?;
+        check_hash(
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        )?;
+        check_hash(&[b'a'; 200], "c2a908d98f5df987ade41b5fce213067efbcc21ef2240212a41e54b5e7c28ae5")
+    }
+}
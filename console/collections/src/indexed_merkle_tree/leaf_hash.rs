@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::merkle_tree::LeafHash;
+use snarkvm_console_algorithms::{Poseidon, BHP};
+use snarkvm_console_types::prelude::*;
+
+pub trait IndexedLeafHash: LeafHash {
+    /// Returns the hash of the leaf with the given key, next key, and value hash.
+    /// The leaf is hashed with `hash_leaf`, so it does not share a domain with the path hasher.
+    fn hash_indexed_leaf(
+        &self,
+        key: &Self::Hash,
+        next_key: &Self::Hash,
+        value_hash: &Self::Hash,
+    ) -> Result<Self::Hash>;
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> IndexedLeafHash
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Returns the hash of the leaf with the given key, next key, and value hash.
+    fn hash_indexed_leaf(
+        &self,
+        key: &Self::Hash,
+        next_key: &Self::Hash,
+        value_hash: &Self::Hash,
+    ) -> Result<Self::Hash> {
+        // Concatenate the bits of the key, next key, and value hash.
+        let mut input = key.to_bits_le();
+        input.extend(next_key.to_bits_le());
+        input.extend(value_hash.to_bits_le());
+        // Hash the input as a leaf.
+        self.hash_leaf(&input)
+    }
+}
+
+impl<E: Environment, const RATE: usize> IndexedLeafHash for Poseidon<E, RATE> {
+    /// Returns the hash of the leaf with the given key, next key, and value hash.
+    fn hash_indexed_leaf(
+        &self,
+        key: &Self::Hash,
+        next_key: &Self::Hash,
+        value_hash: &Self::Hash,
+    ) -> Result<Self::Hash> {
+        // Hash the key, next key, and value hash as a leaf.
+        self.hash_leaf(&vec![*key, *next_key, *value_hash])
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod leaf_hash;
+pub use leaf_hash::*;
+
+mod path;
+pub use path::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::PathHash;
+use snarkvm_console_types::prelude::*;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// An indexed Merkle tree of key-value pairs, where each leaf also stores the next larger key
+/// in the tree, so the leaves form a linked list sorted by key.
+///
+/// The first leaf holds the zero key, and a next key of zero marks the largest key in the tree.
+/// A key is absent from the tree if there is a "low leaf" whose key is less than it, and whose
+/// next key is either zero or greater than it. As such, the zero key can not be inserted.
+#[derive(Clone)]
+pub struct IndexedMerkleTree<
+    E: Environment,
+    LH: IndexedLeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the Merkle tree.
+    root: PH::Hash,
+    /// The hash of an empty subtree at each height, from the leaves (height 0) to the root (height `DEPTH`).
+    empty_hashes: Vec<PH::Hash>,
+    /// The key, next key, and value hash of each leaf, in the order of their leaf index.
+    leaves: Vec<(Field<E>, Field<E>, Field<E>)>,
+    /// The leaf index and value of each key, excluding the zero key.
+    entries: BTreeMap<Field<E>, (usize, LH::Leaf)>,
+    /// The hash of each non-empty node, indexed by its height and its index at that height.
+    nodes: HashMap<(u8, u64), PH::Hash>,
+}
+
+impl<E: Environment, LH: IndexedLeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IndexedMerkleTree<E, LH, PH, DEPTH>
+{
+    /// Initializes a new indexed Merkle tree, containing only the leaf for the zero key.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the hash of an empty subtree at each height.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        let mut tree = Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[DEPTH as usize],
+            empty_hashes,
+            leaves: vec![(Field::zero(), Field::zero(), Field::zero())],
+            entries: Default::default(),
+            nodes: Default::default(),
+        };
+        // Hash the leaf for the zero key.
+        tree.update_leaf_hash(0)?;
+        Ok(tree)
+    }
+
+    /// Inserts the given key-value pair into the tree.
+    pub fn insert(&mut self, key: Field<E>, value: LH::Leaf) -> Result<()> {
+        // Ensure the key is not zero.
+        ensure!(!key.is_zero(), "The zero key can not be inserted into the indexed Merkle tree");
+        // Ensure the key does not exist.
+        ensure!(!self.contains_key(&key), "The key already exists in the indexed Merkle tree");
+        // Ensure the tree is not full.
+        let index = self.leaves.len();
+        ensure!((index as u128) < (1u128 << DEPTH), "The indexed Merkle tree is full");
+
+        // Compute the value hash.
+        let value_hash = self.leaf_hasher.hash_leaf(&value)?;
+
+        // Insert the new leaf after the low leaf.
+        let low_index = self.low_leaf_index(&key);
+        let next_key = self.leaves[low_index].1;
+        self.leaves[low_index].1 = key;
+        self.leaves.push((key, next_key, value_hash));
+        self.entries.insert(key, (index, value));
+
+        // Update the hashes of the low leaf and the new leaf.
+        self.update_leaf_hash(low_index)?;
+        self.update_leaf_hash(index)
+    }
+
+    /// Updates the value of the given key in the tree.
+    pub fn update(&mut self, key: Field<E>, value: LH::Leaf) -> Result<()> {
+        // Compute the value hash.
+        let value_hash = self.leaf_hasher.hash_leaf(&value)?;
+        // Retrieve the leaf index of the key, and update its value.
+        let index = match self.entries.get_mut(&key) {
+            Some((index, existing_value)) => {
+                *existing_value = value;
+                *index
+            }
+            None => bail!("The key does not exist in the indexed Merkle tree"),
+        };
+        self.leaves[index].2 = value_hash;
+        self.update_leaf_hash(index)
+    }
+
+    /// Removes the given key from the tree, returning its value.
+    /// The last leaf is moved into the position of the removed leaf, so the leaves remain contiguous.
+    pub fn delete(&mut self, key: &Field<E>) -> Result<LH::Leaf> {
+        // Remove the key.
+        let (index, value) = match self.entries.remove(key) {
+            Some(entry) => entry,
+            None => bail!("The key does not exist in the indexed Merkle tree"),
+        };
+
+        // Point the low leaf to the next key of the removed leaf.
+        let low_index = self.low_leaf_index(key);
+        let low_key = self.leaves[low_index].0;
+        self.leaves[low_index].1 = self.leaves[index].1;
+
+        // Move the last leaf into the position of the removed leaf.
+        let last_index = self.leaves.len() - 1;
+        self.leaves.swap_remove(index);
+        if index != last_index {
+            let moved_key = self.leaves[index].0;
+            if let Some((moved_index, _)) = self.entries.get_mut(&moved_key) {
+                *moved_index = index;
+            }
+            self.update_leaf_hash(index)?;
+        }
+        self.clear_leaf_hash(last_index)?;
+
+        // Update the hash of the low leaf, which may have been moved.
+        self.update_leaf_hash(self.leaf_index(&low_key))?;
+        Ok(value)
+    }
+
+    /// Returns the value of the given key, if it exists.
+    pub fn get(&self, key: &Field<E>) -> Option<&LH::Leaf> {
+        self.entries.get(key).map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the given key exists in the tree.
+    pub fn contains_key(&self, key: &Field<E>) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the Merkle path for the given key.
+    /// If the key exists, the path is for its leaf and proves membership.
+    /// Otherwise, the path is for its low leaf and proves non-membership.
+    pub fn prove(&self, key: &Field<E>) -> Result<IndexedMerklePath<E, DEPTH>> {
+        // Ensure the key is not zero.
+        ensure!(!key.is_zero(), "The zero key can not be proven in the indexed Merkle tree");
+
+        // Retrieve the leaf index of the key, or of its low leaf.
+        let index = match self.entries.get(key) {
+            Some((index, _)) => *index,
+            None => self.low_leaf_index(key),
+        };
+        let (leaf_key, next_key, value_hash) = self.leaves[index];
+
+        // Collect the sibling hashes along the path, from the leaf to the root.
+        let siblings = (0..DEPTH).map(|height| self.node(height, ((index as u64) >> height) ^ 1)).collect::<Vec<_>>();
+
+        IndexedMerklePath::try_from((U64::new(index as u64), leaf_key, next_key, value_hash, siblings))
+    }
+
+    /// Returns `true` if the given Merkle path proves that the given key-value pair exists for the given root.
+    pub fn verify_membership(
+        &self,
+        path: &IndexedMerklePath<E, DEPTH>,
+        root: &PH::Hash,
+        key: &Field<E>,
+        value: &LH::Leaf,
+    ) -> bool {
+        path.verify_membership(&self.leaf_hasher, &self.path_hasher, root, key, value)
+    }
+
+    /// Returns `true` if the given Merkle path proves that the given key does not exist for the given root.
+    pub fn verify_non_membership(&self, path: &IndexedMerklePath<E, DEPTH>, root: &PH::Hash, key: &Field<E>) -> bool {
+        path.verify_non_membership(&self.leaf_hasher, &self.path_hasher, root, key)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the number of keys in the tree, excluding the zero key.
+    pub fn number_of_leaves(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the leaf index of the given key, which must be in the tree or be zero.
+    fn leaf_index(&self, key: &Field<E>) -> usize {
+        self.entries.get(key).map_or(0, |(index, _)| *index)
+    }
+
+    /// Returns the leaf index of the largest key in the tree that is less than the given key.
+    fn low_leaf_index(&self, key: &Field<E>) -> usize {
+        self.entries.range(..key).next_back().map_or(0, |(_, (index, _))| *index)
+    }
+
+    /// Returns the hash of the node at the given height and index.
+    fn node(&self, height: u8, index: u64) -> PH::Hash {
+        match self.nodes.get(&(height, index)) {
+            Some(hash) => *hash,
+            None => self.empty_hashes[height as usize],
+        }
+    }
+
+    /// Recomputes the hash of the leaf at the given index, and the hashes along its path to the root.
+    fn update_leaf_hash(&mut self, index: usize) -> Result<()> {
+        let (key, next_key, value_hash) = &self.leaves[index];
+        let leaf_hash = self.leaf_hasher.hash_indexed_leaf(key, next_key, value_hash)?;
+        self.set_leaf_hash(index as u64, leaf_hash)
+    }
+
+    /// Empties the leaf at the given index, and recomputes the hashes along its path to the root.
+    fn clear_leaf_hash(&mut self, index: usize) -> Result<()> {
+        self.set_leaf_hash(index as u64, self.empty_hashes[0])
+    }
+
+    /// Sets the hash of the leaf at the given index, and recomputes the hashes along its path to the root.
+    fn set_leaf_hash(&mut self, index: u64, leaf_hash: PH::Hash) -> Result<()> {
+        // Compute the hashes along the path, from the leaf to the root, before modifying the tree.
+        let mut current_hash = leaf_hash;
+        let mut path_hashes = Vec::with_capacity(DEPTH as usize);
+        for height in 0..DEPTH {
+            path_hashes.push(current_hash);
+
+            // Retrieve the sibling hash at this height.
+            let sibling_hash = self.node(height, (index >> height) ^ 1);
+
+            // Construct the ordering of the left & right child hash for this level.
+            current_hash = match (index >> height) & 1 == 0 {
+                true => self.path_hasher.hash_children(&current_hash, &sibling_hash)?,
+                false => self.path_hasher.hash_children(&sibling_hash, &current_hash)?,
+            };
+        }
+
+        // Store the hashes along the path, omitting the hashes of empty subtrees.
+        for (height, hash) in path_hashes.into_iter().enumerate() {
+            let node = (height as u8, index >> height);
+            match hash == self.empty_hashes[height] {
+                true => self.nodes.remove(&node),
+                false => self.nodes.insert(node, hash),
+            };
+        }
+
+        self.root = current_hash;
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Insert the key-value pairs into the indexed Merkle tree.
+/// 2. Check that the membership proof for every key is valid, and the non-membership proof is not.
+/// 3. Update every value, and check that the membership proof for the new value is valid.
+/// 4. Delete every key, and check that the non-membership proof for every key is valid.
+fn check_indexed_merkle_tree<
+    E: Environment,
+    LH: IndexedLeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    entries: &[(Field<E>, LH::Leaf)],
+    new_values: &[LH::Leaf],
+) -> Result<()>
+where
+    LH::Leaf: Debug + PartialEq,
+{
+    // Construct the indexed Merkle tree.
+    let mut tree = IndexedMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let empty_root = *tree.root();
+
+    // Check that every key is absent from the empty tree.
+    for (key, value) in entries {
+        let proof = tree.prove(key)?;
+        assert!(tree.verify_non_membership(&proof, &empty_root, key));
+        assert!(!tree.verify_membership(&proof, &empty_root, key, value));
+    }
+
+    // Insert the key-value pairs.
+    for (key, value) in entries {
+        tree.insert(*key, value.clone())?;
+        // Ensure the key can not be inserted twice.
+        assert!(tree.insert(*key, value.clone()).is_err());
+    }
+    assert_eq!(entries.len(), tree.number_of_leaves());
+    let root = *tree.root();
+
+    // Check each key in the indexed Merkle tree.
+    for (key, value) in entries {
+        assert_eq!(Some(value), tree.get(key));
+
+        let proof = tree.prove(key)?;
+        assert!(tree.verify_membership(&proof, &root, key, value));
+        assert!(!tree.verify_membership(&proof, &PH::Hash::rand(&mut test_rng()), key, value));
+        assert!(!tree.verify_membership(&proof, &root, &Field::rand(&mut test_rng()), value));
+        assert!(!tree.verify_non_membership(&proof, &root, key));
+        // Ensure the path of another key does not prove the membership of this key.
+        for (other_key, _) in entries.iter().filter(|(other_key, _)| other_key != key) {
+            assert!(!tree.verify_membership(&tree.prove(other_key)?, &root, key, value));
+        }
+    }
+
+    // Update the value of each key.
+    for ((key, _), new_value) in entries.iter().zip_eq(new_values) {
+        tree.update(*key, new_value.clone())?;
+    }
+    assert_eq!(entries.len(), tree.number_of_leaves());
+    let updated_root = *tree.root();
+
+    // Check each updated key in the indexed Merkle tree.
+    for ((key, value), new_value) in entries.iter().zip_eq(new_values) {
+        assert_eq!(Some(new_value), tree.get(key));
+
+        let proof = tree.prove(key)?;
+        assert!(tree.verify_membership(&proof, &updated_root, key, new_value));
+        assert!(!tree.verify_membership(&proof, &updated_root, key, value));
+    }
+
+    // Delete each key.
+    for ((key, _), new_value) in entries.iter().zip_eq(new_values) {
+        assert_eq!(*new_value, tree.delete(key)?);
+        // Ensure the key can not be deleted or updated twice.
+        assert!(tree.delete(key).is_err());
+        assert!(tree.update(*key, new_value.clone()).is_err());
+
+        let proof = tree.prove(key)?;
+        assert!(tree.verify_non_membership(&proof, tree.root(), key));
+    }
+
+    // Ensure the tree returns to the empty root.
+    assert_eq!(0, tree.number_of_leaves());
+    assert_eq!(empty_root, *tree.root());
+    Ok(())
+}
+
+#[test]
+fn test_indexed_merkle_tree_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoIndexedMerkleTree0")?;
+        let path_hasher = PH::setup("AleoIndexedMerkleTree1")?;
+
+        let create_value = || Field::<CurrentEnvironment>::rand(&mut test_rng()).to_bits_le();
+
+        for num_entries in 0..ITERATIONS {
+            let entries = (0..num_entries)
+                .map(|_| (Field::<CurrentEnvironment>::rand(&mut test_rng()), create_value()))
+                .collect::<Vec<_>>();
+            let new_values = (0..num_entries).map(|_| create_value()).collect::<Vec<_>>();
+
+            check_indexed_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &entries,
+                &new_values,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>().is_err());
+    // Spot check important depths.
+    assert!(run_test::<32>().is_ok());
+    assert!(run_test::<64>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_indexed_merkle_tree_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoIndexedMerkleTree0")?;
+        let path_hasher = PH::setup("AleoIndexedMerkleTree1")?;
+
+        for num_entries in 0..ITERATIONS {
+            let entries = (0..num_entries)
+                .map(|_| (Field::<CurrentEnvironment>::rand(&mut test_rng()), vec![Uniform::rand(&mut test_rng())]))
+                .collect::<Vec<_>>();
+            let new_values = (0..num_entries).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+            check_indexed_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &entries,
+                &new_values,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>().is_err());
+    // Ensure DEPTH = 65 fails.
+    assert!(run_test::<65>().is_err());
+    // Spot check important depths.
+    assert!(run_test::<4>().is_ok());
+    assert!(run_test::<32>().is_ok());
+    assert!(run_test::<64>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_indexed_merkle_tree_empty_root() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoIndexedMerkleTree0")?;
+    let path_hasher = PH::setup("AleoIndexedMerkleTree1")?;
+
+    // Compute the expected root of a depth-3 tree with only the leaf for the zero key.
+    let zero = Field::zero();
+    let mut expected_root = leaf_hasher.hash_indexed_leaf(&zero, &zero, &zero)?;
+    let mut empty_hash = path_hasher.hash_empty()?;
+    for _ in 0..3 {
+        expected_root = path_hasher.hash_children(&expected_root, &empty_hash)?;
+        empty_hash = path_hasher.hash_children(&empty_hash, &empty_hash)?;
+    }
+
+    let tree = IndexedMerkleTree::<CurrentEnvironment, LH, PH, 3>::new(&leaf_hasher, &path_hasher)?;
+    assert_eq!(expected_root, *tree.root());
+    assert_eq!(path_hasher.hash_empty()?, *tree.empty_hash());
+    assert_eq!(0, tree.number_of_leaves());
+    Ok(())
+}
+
+#[test]
+fn test_indexed_merkle_tree_low_leaf() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoIndexedMerkleTree0")?;
+    let path_hasher = PH::setup("AleoIndexedMerkleTree1")?;
+
+    let mut tree = IndexedMerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher)?;
+
+    // Ensure the zero key can not be inserted or proven.
+    assert!(tree.insert(Field::zero(), vec![Uniform::rand(&mut test_rng())]).is_err());
+    assert!(tree.prove(&Field::zero()).is_err());
+
+    for key in [10, 30, 20] {
+        tree.insert(Field::from_u64(key), vec![Uniform::rand(&mut test_rng())])?;
+    }
+    let root = *tree.root();
+
+    // Ensure each absent key is proven by its low leaf.
+    for (key, low_key, next_key) in [(5, 0, 10), (15, 10, 20), (25, 20, 30), (35, 30, 0)] {
+        let key = Field::from_u64(key);
+        let proof = tree.prove(&key)?;
+        assert_eq!(Field::from_u64(low_key), proof.leaf_key());
+        assert_eq!(Field::from_u64(next_key), proof.next_key());
+        assert!(tree.verify_non_membership(&proof, &root, &key));
+    }
+
+    // Ensure a low leaf does not prove the non-membership of a key outside of its range.
+    let proof = tree.prove(&Field::from_u64(15))?;
+    for key in [5, 10, 20, 25] {
+        assert!(!tree.verify_non_membership(&proof, &root, &Field::from_u64(key)));
+    }
+
+    // Ensure the leaf of an existing key does not prove its non-membership.
+    let proof = tree.prove(&Field::from_u64(20))?;
+    assert!(!tree.verify_non_membership(&proof, &root, &Field::from_u64(20)));
+
+    // Ensure the low leaf is relinked when a key is deleted.
+    tree.delete(&Field::from_u64(20))?;
+    let proof = tree.prove(&Field::from_u64(20))?;
+    assert_eq!(Field::from_u64(10), proof.leaf_key());
+    assert_eq!(Field::from_u64(30), proof.next_key());
+    assert!(tree.verify_non_membership(&proof, tree.root(), &Field::from_u64(20)));
+    Ok(())
+}
+
+#[test]
+fn test_indexed_merkle_tree_is_full() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoIndexedMerkleTree0")?;
+    let path_hasher = PH::setup("AleoIndexedMerkleTree1")?;
+
+    // A depth-2 tree has room for the zero key and three other keys.
+    let mut tree = IndexedMerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher)?;
+    for key in 1..=3 {
+        tree.insert(Field::from_u64(key), vec![Uniform::rand(&mut test_rng())])?;
+    }
+    let root = *tree.root();
+
+    assert!(tree.insert(Field::from_u64(4), vec![Uniform::rand(&mut test_rng())]).is_err());
+    assert_eq!(root, *tree.root());
+    assert_eq!(3, tree.number_of_leaves());
+    Ok(())
+}
@@ -19,4 +19,5 @@
 
 pub use snarkvm_console_types::prelude::*;
 
+pub mod indexed_merkle_tree;
 pub mod merkle_tree;
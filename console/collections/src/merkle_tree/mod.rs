@@ -17,6 +17,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -259,6 +262,41 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    #[inline]
+    /// Returns the Merkle multi-path for the given (strictly increasing) leaf indices and leaves.
+    pub fn prove_multi(&self, leaf_indices: &[usize], leaves: &[LH::Leaf]) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Ensure there is one leaf for each leaf index.
+        ensure!(leaf_indices.len() == leaves.len(), "The number of Merkle leaf indices and leaves must match");
+
+        // Compute the leaf hashes.
+        let leaf_hashes = self.leaf_hasher.hash_leaves(leaves)?;
+        // Ensure each leaf hash matches the one in the tree.
+        for (leaf_index, leaf_hash) in leaf_indices.iter().zip_eq(&leaf_hashes) {
+            // Ensure the leaf index is valid.
+            ensure!(*leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+            // Ensure the leaf hash matches the one in the tree.
+            ensure!(
+                self.leaf_hashes()[*leaf_index] == *leaf_hash,
+                "The given Merkle leaf does not match the one in the Merkle tree"
+            );
+        }
+
+        // Collect the sibling hashes that can not be computed from the leaves.
+        let leaf_indices = leaf_indices.iter().map(|leaf_index| *leaf_index as u64).collect::<Vec<_>>();
+        let siblings = MerkleMultiPath::<E, DEPTH>::sibling_positions(&leaf_indices)
+            .into_iter()
+            .map(|(height, position)| self.node(height, position))
+            .collect();
+
+        // Return the Merkle multi-path.
+        MerkleMultiPath::try_from((leaf_indices.into_iter().map(U64::new).collect(), siblings))
+    }
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    pub fn verify_multi(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -283,6 +321,17 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Return the leaf hashes.
         &self.tree[start..end]
     }
+
+    /// Returns the hash of the node at the given height (from the leaves) and position, including the padded levels.
+    fn node(&self, height: u8, position: u64) -> PH::Hash {
+        // Compute the number of nodes on the given level of the full Merkle tree.
+        let num_nodes = self.number_of_leaves.next_power_of_two().checked_shr(height as u32).unwrap_or(0);
+        // Return the node hash, or the empty hash if the node is outside of the full Merkle tree.
+        match (position as usize) < num_nodes {
+            true => self.tree[num_nodes - 1 + position as usize],
+            false => self.empty_hash,
+        }
+    }
 }
 
 /// Returns the depth of the tree, given the size of the tree.
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A Merkle multi-path proves a set of leaves against a single root, while sharing
+/// the sibling hashes that are common to their individual Merkle paths.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in strictly increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes that can not be computed from the leaves,
+    /// ordered by level from the leaves to the root, and by position within each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Merkle multi-path must contain at least one leaf index");
+        // Ensure the leaf indices are within the tree depth.
+        for leaf_index in &leaf_indices {
+            ensure!((**leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        }
+        // Ensure the leaf indices are in strictly increasing order.
+        ensure!(
+            leaf_indices.windows(2).all(|pair| *pair[0] < *pair[1]),
+            "Merkle leaf indices must be in strictly increasing order"
+        );
+        // Ensure the Merkle multi-path contains the correct number of siblings.
+        let leaf_indices_u64 = leaf_indices.iter().map(|leaf_index| **leaf_index).collect::<Vec<_>>();
+        ensure!(
+            siblings.len() == Self::sibling_positions(&leaf_indices_u64).len(),
+            "Found an incorrect number of Merkle multi-path siblings"
+        );
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the path.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns the height and position of each sibling hash that is required to compute the root
+    /// from the given (strictly increasing) leaf indices, in the order they appear in the path.
+    pub fn sibling_positions(leaf_indices: &[u64]) -> Vec<(u8, u64)> {
+        // Initialize a vector for the sibling positions.
+        let mut sibling_positions = Vec::new();
+        // Initialize the positions of the known nodes on the current level.
+        let mut positions = leaf_indices.to_vec();
+
+        for height in 0..DEPTH {
+            let mut parents = Vec::with_capacity(positions.len());
+            let mut i = 0;
+            while i < positions.len() {
+                let position = positions[i];
+                // If the sibling is the next known node, it does not need to be provided.
+                match position % 2 == 0 && positions.get(i + 1) == Some(&(position + 1)) {
+                    true => i += 2,
+                    false => {
+                        sibling_positions.push((height, position ^ 1));
+                        i += 1;
+                    }
+                }
+                parents.push(position >> 1);
+            }
+            positions = parents;
+        }
+        sibling_positions
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure there is one leaf for each leaf index.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of Merkle leaves");
+            return false;
+        }
+
+        // Initialize the known nodes on the current level, by computing the leaf hashes to start.
+        let mut nodes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(leaf_hashes) => {
+                self.leaf_indices.iter().map(|leaf_index| **leaf_index).zip_eq(leaf_hashes).collect::<Vec<_>>()
+            }
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            let mut parents = Vec::with_capacity(nodes.len());
+            let mut i = 0;
+            while i < nodes.len() {
+                let (position, current_hash) = nodes[i];
+                // Construct the ordering of the left & right child hash for this node.
+                let (left, right) = match nodes.get(i + 1) {
+                    // If the sibling is the next known node, hash the two nodes together.
+                    Some((next_position, next_hash)) if position % 2 == 0 && *next_position == position + 1 => {
+                        i += 1;
+                        (current_hash, *next_hash)
+                    }
+                    // Otherwise, use the next sibling hash in the path.
+                    _ => match siblings.next() {
+                        Some(sibling_hash) => match position % 2 == 0 {
+                            true => (current_hash, *sibling_hash),
+                            false => (*sibling_hash, current_hash),
+                        },
+                        None => {
+                            eprintln!("Found too few Merkle multi-path siblings");
+                            return false;
+                        }
+                    },
+                };
+                i += 1;
+                // Compute the parent hash for the next level.
+                match path_hasher.hash_children(&left, &right) {
+                    Ok(hash) => parents.push((position >> 1, hash)),
+                    Err(error) => {
+                        eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            nodes = parents;
+        }
+
+        // Ensure every sibling hash was used.
+        if siblings.next().is_some() {
+            eprintln!("Found too many Merkle multi-path siblings");
+            return false;
+        }
+
+        // Ensure the final hash matches the given root.
+        match nodes.as_slice() {
+            [(_, current_hash)] => *current_hash == *root,
+            _ => false,
+        }
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf indices.
+        let num_leaf_indices = u32::read_le(&mut reader)?;
+        let leaf_indices =
+            (0..num_leaf_indices).map(|_| Ok(U64::new(u64::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Read the Merkle multi-path siblings.
+        let num_siblings = u32::read_le(&mut reader)?;
+        let siblings = (0..num_siblings)
+            .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(|err| error(err.to_string()))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf indices.
+        (self.leaf_indices.len() as u32).write_le(&mut writer)?;
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the Merkle multi-path siblings.
+        (self.siblings.len() as u32).write_le(&mut writer)?;
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for MerkleMultiPath<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerkleMultiPath<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle multi-path")
+    }
+}
@@ -580,6 +580,121 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the Merkle multi-path for each set of leaf indices is valid.
+/// 3. Check that the Merkle multi-path is invalid for an incorrect root or incorrect leaves.
+/// 4. Check that the Merkle multi-path requires no more siblings than the individual Merkle paths.
+fn check_merkle_multi_path<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Construct the sets of leaf indices to check.
+    let num_leaves = leaves.len();
+    let sets_of_leaf_indices = vec![
+        // A single leaf.
+        vec![0],
+        vec![num_leaves - 1],
+        // Every leaf.
+        (0..num_leaves).collect::<Vec<_>>(),
+        // A contiguous range of leaves.
+        (num_leaves / 4..num_leaves / 2 + 1).collect::<Vec<_>>(),
+        // A random subset of leaves.
+        (0..num_leaves).filter(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>(),
+    ];
+
+    for leaf_indices in sets_of_leaf_indices.into_iter().filter(|leaf_indices| !leaf_indices.is_empty()) {
+        let selected_leaves = leaf_indices.iter().map(|i| leaves[*i].clone()).collect::<Vec<_>>();
+
+        // Check the Merkle multi-path.
+        let proof = merkle_tree.prove_multi(&leaf_indices, &selected_leaves)?;
+        assert_eq!(leaf_indices.len(), proof.leaf_indices().len());
+        assert!(merkle_tree.verify_multi(&proof, merkle_tree.root(), &selected_leaves));
+        assert!(!merkle_tree.verify_multi(&proof, &PH::Hash::rand(&mut test_rng()), &selected_leaves));
+        assert!(!merkle_tree.verify_multi(&proof, merkle_tree.root(), &selected_leaves[1..]));
+
+        // Ensure the Merkle multi-path matches the individual Merkle path for a single leaf.
+        if leaf_indices.len() == 1 {
+            let path = merkle_tree.prove(leaf_indices[0], &selected_leaves[0])?;
+            assert_eq!(path.siblings(), proof.siblings());
+        }
+        // Ensure the Merkle multi-path shares the sibling hashes of the individual Merkle paths.
+        assert!(proof.siblings().len() <= leaf_indices.len() * DEPTH as usize);
+
+        // Ensure the Merkle multi-path serializes and deserializes.
+        let bytes = proof.to_bytes_le()?;
+        assert_eq!(proof, MerkleMultiPath::read_le(&bytes[..])?);
+
+        // Ensure the Merkle multi-path is invalid for an incorrect leaf.
+        if selected_leaves.len() > 1 {
+            let mut incorrect_leaves = selected_leaves.clone();
+            incorrect_leaves.swap(0, 1);
+            assert!(!merkle_tree.verify_multi(&proof, merkle_tree.root(), &incorrect_leaves));
+        }
+    }
+
+    // Ensure the leaf indices must be strictly increasing.
+    if num_leaves > 1 {
+        assert!(merkle_tree.prove_multi(&[1, 0], &[leaves[1].clone(), leaves[0].clone()]).is_err());
+        assert!(merkle_tree.prove_multi(&[0, 0], &[leaves[0].clone(), leaves[0].clone()]).is_err());
+    }
+    // Ensure the leaf indices must be within the tree.
+    assert!(merkle_tree.prove_multi(&[num_leaves], &[leaves[0].clone()]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_bhp() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let create_leaves = |num_leaves| {
+        (0..num_leaves)
+            .map(|_| Field::<CurrentEnvironment>::rand(&mut test_rng()).to_bits_le())
+            .collect::<Vec<Vec<bool>>>()
+    };
+
+    for num_leaves in 1..ITERATIONS as usize {
+        check_merkle_multi_path::<CurrentEnvironment, LH, PH, 32>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_poseidon() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let create_leaves = |num_leaves| (0..num_leaves).map(|_| vec![Uniform::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+    for num_leaves in 1..ITERATIONS as usize {
+        check_merkle_multi_path::<CurrentEnvironment, LH, PH, 32>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )?;
+    }
+    // Check a multi-path for the 16 leaves of a transition.
+    check_merkle_multi_path::<CurrentEnvironment, LH, PH, 4>(&leaf_hasher, &path_hasher, &create_leaves(16))
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
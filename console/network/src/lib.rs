@@ -102,6 +102,9 @@ pub trait Network:
     /// Returns the encryption domain as a constant field element.
     fn encryption_domain() -> Field<Self>;
 
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self>;
+
     /// Returns the MAC domain as a constant field element.
     fn mac_domain() -> Field<Self>;
 
@@ -156,6 +159,12 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the SHA-256 hash of the given input, as 256 bits.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the Keccak-256 hash of the given input, as 256 bits.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
@@ -17,11 +17,13 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     BHP1024,
     BHP256,
     BHP512,
@@ -36,6 +38,8 @@ lazy_static! {
     pub static ref BCM_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoBalanceCommitment0");
     /// The encryption domain as a constant field element.
     pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricEncryption0");
+    /// The graph key domain as a constant field element.
+    pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
     /// The MAC domain as a constant field element.
     pub static ref MAC_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricKeyCommitment0");
     /// The randomizer domain as a constant field element.
@@ -118,6 +122,11 @@ impl Network for Testnet3 {
         *ENCRYPTION_DOMAIN
     }
 
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        *GRAPH_KEY_DOMAIN
+    }
+
     /// Returns the MAC domain as a constant field element.
     fn mac_domain() -> Field<Self> {
         *MAC_DOMAIN
@@ -215,6 +224,16 @@ impl Network for Testnet3 {
         PEDERSEN_128.hash(input)
     }
 
+    /// Returns the SHA-256 hash of the given input, as 256 bits.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
+    /// Returns the Keccak-256 hash of the given input, as 256 bits.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak256.hash(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
         POSEIDON_2.hash(input)
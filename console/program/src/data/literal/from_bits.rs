@@ -49,7 +49,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
+            16 => Literal::Signature(Box::new(Signature::from_bits_le(literal)?)),
+            17.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
     }
@@ -86,7 +87,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
+            16 => Literal::Signature(Box::new(Signature::from_bits_be(literal)?)),
+            17.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
         };
         Ok(literal)
     }
@@ -145,6 +147,10 @@ mod tests {
             check_serialization(Literal::<CurrentNetwork>::U128(U128::new(Uniform::rand(rng))))?;
             // Scalar
             check_serialization(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
+            // Signature
+            let message: Vec<_> = (0..rng.gen_range(0..16)).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, &mut test_crypto_rng())?;
+            check_serialization(Literal::<CurrentNetwork>::Signature(Box::new(signature)))?;
             // String
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string: String = (0..(CurrentNetwork::MAX_STRING_BYTES) / 4).map(|_| rng.gen::<char>()).collect();
@@ -24,7 +24,8 @@ mod to_bits;
 mod to_type;
 mod variant;
 
-use crate::LiteralType;
+use crate::{LiteralType, Signature};
+use snarkvm_console_account::ComputeKey;
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Boolean};
 
@@ -63,4 +64,6 @@ pub enum Literal<N: Network> {
     Scalar(Scalar<N>),
     /// The string type.
     String(StringType<N>),
+    /// The signature type.
+    Signature(Box<Signature<N>>),
 }
@@ -36,6 +36,14 @@ impl<N: Network> Literal<N> {
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
             LiteralType::String => Literal::String(StringType::rand(rng)),
+            LiteralType::Signature => {
+                // Sample a compute key from random affine group elements, which are always valid.
+                let compute_key = match ComputeKey::try_from((Group::rand(rng), Group::rand(rng))) {
+                    Ok(compute_key) => compute_key,
+                    Err(error) => panic!("Failed to sample a compute key: {error}"),
+                };
+                Literal::Signature(Box::new(Signature::from((Scalar::rand(rng), Scalar::rand(rng), compute_key))))
+            }
         }
     }
 }
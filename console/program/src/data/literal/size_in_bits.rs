@@ -36,6 +36,7 @@ impl<N: Network> Literal<N> {
             Self::U128(..) => U128::<N>::size_in_bits() as u16,
             Self::Scalar(..) => Scalar::<N>::size_in_bits() as u16,
             Self::String(string) => (string.len() * 8) as u16,
+            Self::Signature(..) => Signature::<N>::size_in_bits() as u16,
         }
     }
 }
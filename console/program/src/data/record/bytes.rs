@@ -39,8 +39,25 @@ impl<N: Network, Private: Visibility> FromBytes for Record<N, Private> {
             // Add the entry.
             data.insert(identifier, entry);
         }
+        // Read the memo, if it exists.
+        let memo = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => {
+                // Read the memo (in 2 steps to prevent infinite recursion).
+                let num_bytes = u16::read_le(&mut reader)?;
+                // Read the memo bytes.
+                let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Recover the memo.
+                Some(Private::read_le(&mut bytes.as_slice())?)
+            }
+            _ => return Err(error("Invalid record memo variant")),
+        };
 
-        Ok(Self { owner, balance, data })
+        // Construct the record.
+        let record = Self { owner, balance, data, memo };
+        // Ensure the record, including the memo, is within `N::MAX_DATA_SIZE_IN_FIELDS`.
+        record.num_randomizers().map_err(|e| error(e.to_string()))?;
+        Ok(record)
     }
 }
 
@@ -60,11 +77,27 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
             // Write the entry value (performed in 2 steps to prevent infinite recursion).
             let bytes = entry_value.to_bytes_le().map_err(|e| error(e.to_string()))?;
             // Write the number of bytes.
-            (bytes.len() as u16).write_le(&mut writer)?;
+            u16::try_from(bytes.len())
+                .map_err(|_| error("Record entry exceeds the maximum size"))?
+                .write_le(&mut writer)?;
             // Write the bytes.
             bytes.write_le(&mut writer)?;
         }
-        Ok(())
+        // Write the memo, if it exists.
+        match &self.memo {
+            None => 0u8.write_le(&mut writer),
+            Some(memo) => {
+                1u8.write_le(&mut writer)?;
+                // Write the memo (performed in 2 steps to prevent infinite recursion).
+                let bytes = memo.to_bytes_le().map_err(|e| error(e.to_string()))?;
+                // Write the number of bytes.
+                u16::try_from(bytes.len())
+                    .map_err(|_| error("Record memo exceeds the maximum size"))?
+                    .write_le(&mut writer)?;
+                // Write the bytes.
+                bytes.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -72,6 +105,7 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::StringType;
 
     type CurrentNetwork = Testnet3;
 
@@ -86,6 +120,57 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Record::read_le(&expected_bytes[..])?);
         assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes[1..]).is_err());
+
+        // Construct a new record with a memo.
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private } memo 12345field",
+        )?;
+        assert!(expected.memo().is_some());
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Record::read_le(&expected_bytes[..])?);
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+
+    /// Returns a plaintext interface of `num_members` interfaces, each with 24 strings of 255 bytes.
+    fn sample_large_plaintext(num_members: usize) -> Result<Plaintext<CurrentNetwork>> {
+        let string = Plaintext::from(Literal::String(StringType::new(&"a".repeat(255))));
+        let inner = (0..24)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, string.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let inner = Plaintext::Interface(inner, Default::default());
+        let members = (0..num_members)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, inner.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        Ok(Plaintext::Interface(members, Default::default()))
+    }
+
+    #[test]
+    fn test_bytes_with_large_memo() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private }",
+        )?;
+
+        // Ensure a memo that does not fit in a `u16` length prefix fails to serialize.
+        let memo = sample_large_plaintext(12)?;
+        assert!(memo.to_bytes_le()?.len() > u16::MAX as usize);
+        assert!(Record { memo: Some(memo), ..record.clone() }.to_bytes_le().is_err());
+
+        // Construct a record whose data and memo each fit, but together exceed `N::MAX_DATA_SIZE_IN_FIELDS`.
+        let large = sample_large_plaintext(8)?;
+        let data = (0..2)
+            .map(|i| Ok((Identifier::from_str(&format!("e{i}"))?, Entry::Private(large.clone()))))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let candidate = Record { data, memo: None, ..record };
+        assert!(candidate.num_randomizers().is_ok());
+        let candidate = Record { memo: Some(large), ..candidate };
+        assert!(candidate.num_randomizers().is_err());
+
+        // Ensure the record serializes, but fails to deserialize.
+        let candidate_bytes = candidate.to_bytes_le()?;
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&candidate_bytes[..]).is_err());
         Ok(())
     }
 }
@@ -92,8 +92,25 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             index += num_randomizers;
         }
 
+        // Decrypt the memo, if it exists.
+        let memo = match &self.memo {
+            Some(memo) => {
+                // Retrieve the randomizers for the memo.
+                let randomizers = &randomizers[index..index + memo.len()];
+                // Decrypt the memo.
+                Some(Plaintext::from_fields(
+                    &memo
+                        .iter()
+                        .zip_eq(randomizers)
+                        .map(|(ciphertext, randomizer)| *ciphertext - randomizer)
+                        .collect::<Vec<_>>(),
+                )?)
+            }
+            None => None,
+        };
+
         // Return the decrypted record.
-        Ok(Record { owner, balance, data: decrypted_data })
+        Ok(Record { owner, balance, data: decrypted_data, memo })
     }
 }
 
@@ -103,7 +120,7 @@ mod tests {
     use crate::Literal;
     use snarkvm_console_account::PrivateKey;
     use snarkvm_console_network::Testnet3;
-    use snarkvm_console_types::Field;
+    use snarkvm_console_types::{Field, StringType};
 
     type CurrentNetwork = Testnet3;
 
@@ -131,6 +148,7 @@ mod tests {
                 ]
                 .into_iter(),
             ),
+            memo: None,
         };
         // Encrypt the record.
         let randomizer = Scalar::rand(&mut test_rng());
@@ -138,6 +156,18 @@ mod tests {
         // Decrypt the record.
         let nonce = N::g_scalar_multiply(&randomizer);
         assert_eq!(record, ciphertext.decrypt(view_key, nonce)?);
+
+        // Attach a memo to the record.
+        let memo = Plaintext::from(Literal::String(StringType::new("INV-2022-0001")));
+        let record = record.with_memo(memo)?;
+        // Encrypt the record.
+        let randomizer = Scalar::rand(&mut test_rng());
+        let ciphertext = record.encrypt(randomizer)?;
+        // Ensure the memo is encrypted.
+        assert!(ciphertext.memo().is_some());
+        // Decrypt the record.
+        let nonce = N::g_scalar_multiply(&randomizer);
+        assert_eq!(record, ciphertext.decrypt(view_key, nonce)?);
         Ok(())
     }
 
@@ -93,7 +93,26 @@ impl<N: Network> Record<N, Plaintext<N>> {
             index += num_randomizers;
         }
 
+        // Encrypt the memo, if it exists.
+        let memo = match &self.memo {
+            Some(memo) => {
+                // Retrieve the plaintext fields of the memo.
+                let fields = memo.to_fields()?;
+                // Retrieve the randomizers for the memo.
+                let randomizers = &randomizers[index..index + fields.len()];
+                // Encrypt the memo.
+                Some(Ciphertext::try_from(
+                    fields
+                        .iter()
+                        .zip_eq(randomizers)
+                        .map(|(plaintext, randomizer)| *plaintext + randomizer)
+                        .collect::<Vec<_>>(),
+                )?)
+            }
+            None => None,
+        };
+
         // Return the encrypted record.
-        Ok(Record { owner, balance, data: encrypted_data })
+        Ok(Record { owner, balance, data: encrypted_data, memo })
     }
 }
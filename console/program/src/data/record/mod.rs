@@ -47,6 +47,8 @@ pub struct Record<N: Network, Private: Visibility> {
     balance: Balance<N, Private>,
     /// The program data.
     data: IndexMap<Identifier<N>, Entry<N, Private>>,
+    /// The optional memo, which is always encrypted to the record owner.
+    memo: Option<Private>,
 }
 
 impl<N: Network> Record<N, Plaintext<N>> {
@@ -61,7 +63,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Ensure the number of interfaces is within `N::MAX_DATA_ENTRIES`.
         ensure!(data.len() <= N::MAX_DATA_ENTRIES, "Found a record that exceeds size ({})", data.len());
         // Return the record.
-        Ok(Self { owner, balance, data })
+        Ok(Self { owner, balance, data, memo: None })
     }
 }
 
@@ -77,7 +79,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         // Ensure the number of interfaces is within `N::MAX_DATA_ENTRIES`.
         ensure!(data.len() <= N::MAX_DATA_ENTRIES, "Found a record that exceeds size ({})", data.len());
         // Return the record.
-        Ok(Self { owner, balance, data })
+        Ok(Self { owner, balance, data, memo: None })
     }
 }
 
@@ -96,4 +98,70 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
     pub const fn data(&self) -> &IndexMap<Identifier<N>, Entry<N, Private>> {
         &self.data
     }
+
+    /// Returns the memo, if one is attached to the program record.
+    pub const fn memo(&self) -> Option<&Private> {
+        self.memo.as_ref()
+    }
+
+    /// Attaches the given memo to the program record.
+    pub fn with_memo(self, memo: Private) -> Result<Self> {
+        // Attach the memo.
+        let record = Self { memo: Some(memo), ..self };
+        // Ensure the record, including the memo, is within `N::MAX_DATA_SIZE_IN_FIELDS`.
+        record.num_randomizers()?;
+        // Return the record.
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::StringType;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a sample record without a memo.
+    fn sample_record() -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private, token_amount: 100u64.private }",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_with_memo() -> Result<()> {
+        // Attach a memo to the record.
+        let record = sample_record();
+        let memo = Plaintext::from(Literal::String(StringType::new("INV-2022-0001")));
+        let candidate = record.clone().with_memo(memo.clone())?;
+        assert_eq!(Some(&memo), candidate.memo());
+        assert_eq!(record.data(), candidate.data());
+
+        // Ensure the memo is covered by the record bits, and hence the commitment.
+        assert_ne!(record.to_bits_le(), candidate.to_bits_le());
+        let randomizer = Uniform::rand(&mut test_rng());
+        assert_ne!(record.to_commitment(&randomizer)?, candidate.to_commitment(&randomizer)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_memo_exceeds_size() -> Result<()> {
+        // Construct a memo that exceeds `N::MAX_DATA_SIZE_IN_FIELDS`.
+        let string = Plaintext::from(Literal::String(StringType::new(&"a".repeat(255))));
+        let inner = (0..24)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, string.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let inner = Plaintext::Interface(inner, Default::default());
+        let memo = (0..24)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, inner.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let memo = Plaintext::Interface(memo, Default::default());
+
+        // Ensure the memo is rejected.
+        assert!(sample_record().with_memo(memo).is_err());
+        Ok(())
+    }
 }
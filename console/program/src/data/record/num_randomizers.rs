@@ -39,6 +39,13 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
                 .ok_or_else(|| anyhow!("Number of randomizers exceeds maximum allowed size."))?;
         }
 
+        // If there is a memo, increment the number of randomizers by the number of memo randomizers.
+        if let Some(memo) = &self.memo {
+            num_randomizers = num_randomizers
+                .checked_add(memo.size_in_fields()?)
+                .ok_or_else(|| anyhow!("Number of randomizers exceeds maximum allowed size."))?;
+        }
+
         // Ensure the number of randomizers does not exceed the maximum allowed size.
         match num_randomizers as u32 <= N::MAX_DATA_SIZE_IN_FIELDS {
             true => Ok(num_randomizers),
@@ -17,7 +17,8 @@
 use super::*;
 
 impl<N: Network> Parser for Record<N, Plaintext<N>> {
-    /// Parses a string as a record: `{ owner: address, balance: u64, identifier_0: entry_0, ..., identifier_n: entry_n }`.
+    /// Parses a string as a record: `{ owner: address, balance: u64, identifier_0: entry_0, ..., identifier_n: entry_n }`,
+    /// followed by an optional memo: `memo plaintext`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a sanitized pair: `identifier: entry`.
@@ -35,6 +36,18 @@ impl<N: Network> Parser for Record<N, Plaintext<N>> {
             Ok((string, (identifier, entry)))
         }
 
+        /// Parses a sanitized memo: `memo plaintext`.
+        fn parse_memo<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "memo" tag from the string.
+            let (string, _) = tag("memo")(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the memo from the string.
+            Plaintext::parse(string)
+        }
+
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the "{" from the string.
@@ -97,8 +110,21 @@ impl<N: Network> Parser for Record<N, Plaintext<N>> {
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the '}' from the string.
         let (string, _) = tag("}")(string)?;
-        // Output the record.
-        Ok((string, Record { owner, balance, data: IndexMap::from_iter(entries.into_iter()) }))
+        // Construct the record, without the memo.
+        let record = Self { owner, balance, data: IndexMap::from_iter(entries.into_iter()), memo: None };
+
+        // Parse the memo from the string, if it exists, and attach it to the record.
+        map_res(opt(parse_memo), move |memo| {
+            let record = Self { memo, ..record.clone() };
+            // Ensure the record, including the memo, is within `N::MAX_DATA_SIZE_IN_FIELDS`.
+            match record.num_randomizers() {
+                Ok(_) => Ok(record),
+                Err(error) => {
+                    eprintln!("{error}");
+                    Err(error)
+                }
+            }
+        })(string)
     }
 }
 
@@ -171,7 +197,12 @@ impl<N: Network> Record<N, Plaintext<N>> {
             }
         }
         // Print the closing brace.
-        write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+        write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)?;
+        // Print the memo, if it exists.
+        match &self.memo {
+            Some(memo) => write!(f, " memo {memo}"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -179,6 +210,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::StringType;
 
     type CurrentNetwork = Testnet3;
 
@@ -243,6 +275,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_memo() -> Result<()> {
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+  balance: 99u64.private,
+  foo: 5u8.constant
+} memo {
+  invoice: 1234u32,
+  memo: 5u8
+}";
+        let (remainder, candidate) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(expected)?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        assert_eq!(1, candidate.data().len());
+        assert!(candidate.memo().is_some());
+
+        // Ensure `memo` may be used as the name of a data entry.
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+  balance: 99u64.private,
+  memo: 5u8.public
+} memo 6u8";
+        let candidate = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(expected)?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!(1, candidate.data().len());
+        assert_eq!(Some(&Plaintext::from_str("6u8")?), candidate.memo());
+
+        // Ensure the memo does not have a visibility.
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, balance: 99u64.private } memo 5u8.private";
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(given).is_err());
+        // Ensure the memo must follow the record.
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, balance: 99u64.private, memo 5u8 }";
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(given).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_memo_exceeds_size() -> Result<()> {
+        // Construct a memo that exceeds `N::MAX_DATA_SIZE_IN_FIELDS`.
+        let string = Plaintext::from(Literal::String(StringType::new(&"a".repeat(255))));
+        let inner = (0..24)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, string.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let inner = Plaintext::Interface(inner, Default::default());
+        let memo = (0..24)
+            .map(|i| Ok((Identifier::from_str(&format!("m{i}"))?, inner.clone())))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let memo = Plaintext::Interface(memo, Default::default());
+
+        // Construct the record string, bypassing the size check in `Record::with_memo`.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, balance: 99u64.private }",
+        )?;
+        let given = Record { memo: Some(memo), ..record }.to_string();
+
+        // Ensure the memo is rejected.
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&given).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
@@ -32,6 +32,12 @@ impl<N: Network> ToBits for Record<N, Plaintext<N>> {
         bits_le.extend(self.balance.to_bits_le());
         bits_le.extend((data_bits_le.len() as u32).to_bits_le());
         bits_le.extend(data_bits_le);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_le = memo.to_bits_le();
+            bits_le.extend((memo_bits_le.len() as u32).to_bits_le());
+            bits_le.extend(memo_bits_le);
+        }
         bits_le
     }
 
@@ -50,6 +56,12 @@ impl<N: Network> ToBits for Record<N, Plaintext<N>> {
         bits_be.extend(self.balance.to_bits_be());
         bits_be.extend((data_bits_be.len() as u32).to_bits_be());
         bits_be.extend(data_bits_be);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_be = memo.to_bits_be();
+            bits_be.extend((memo_bits_be.len() as u32).to_bits_be());
+            bits_be.extend(memo_bits_be);
+        }
         bits_be
     }
 }
@@ -70,6 +82,12 @@ impl<N: Network> ToBits for Record<N, Ciphertext<N>> {
         bits_le.extend(self.balance.to_bits_le());
         bits_le.extend((data_bits_le.len() as u32).to_bits_le());
         bits_le.extend(data_bits_le);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_le = memo.to_bits_le();
+            bits_le.extend((memo_bits_le.len() as u32).to_bits_le());
+            bits_le.extend(memo_bits_le);
+        }
         bits_le
     }
 
@@ -88,6 +106,12 @@ impl<N: Network> ToBits for Record<N, Ciphertext<N>> {
         bits_be.extend(self.balance.to_bits_be());
         bits_be.extend((data_bits_be.len() as u32).to_bits_be());
         bits_be.extend(data_bits_be);
+        // Append the memo bits, if the memo exists.
+        if let Some(memo) = &self.memo {
+            let memo_bits_be = memo.to_bits_be();
+            bits_be.extend((memo_bits_be.len() as u32).to_bits_be());
+            bits_be.extend(memo_bits_be);
+        }
         bits_be
     }
 }
@@ -47,6 +47,20 @@ impl<N: Network> Parser for RecordType<N> {
             Ok((string, (identifier, value_type)))
         }
 
+        /// Parses the start of an entry (i.e. `identifier as`) from the string.
+        fn parse_entry_prefix<N: Network>(string: &str) -> ParserResult<Identifier<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the identifier from the string.
+            let (string, identifier) = Identifier::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "as" from the string.
+            let (string, _) = tag("as")(string)?;
+            // Return the identifier.
+            Ok((string, identifier))
+        }
+
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the type name from the string.
@@ -104,18 +118,23 @@ impl<N: Network> Parser for RecordType<N> {
         // Parse the ";" from the string.
         let (string, _) = tag(";")(string)?;
 
-        // Parse the entries from the string.
-        let (string, entries) = map_res(many1(parse_tuple), |entries| {
-            // Ensure the entries has no duplicate names.
-            if has_duplicates(entries.iter().map(|(identifier, _)| identifier)) {
-                return Err(error(format!("Duplicate identifier found in record '{}'", name)));
-            }
-            // Ensure the number of members is within `N::MAX_DATA_ENTRIES`.
-            if entries.len() > N::MAX_DATA_ENTRIES {
-                return Err(error("Failed to parse record: too many entries"));
-            }
-            Ok(entries)
-        })(string)?;
+        // Parse the entries from the string, which may be empty.
+        let (string, entries) =
+            map_res(pair(many0(parse_tuple), opt(parse_entry_prefix::<N>)), |(entries, malformed)| {
+                // Ensure the entries are not followed by a malformed entry.
+                if let Some(identifier) = malformed {
+                    return Err(error(format!("Malformed entry '{identifier}' found in record '{}'", name)));
+                }
+                // Ensure the entries has no duplicate names.
+                if has_duplicates(entries.iter().map(|(identifier, _)| identifier)) {
+                    return Err(error(format!("Duplicate identifier found in record '{}'", name)));
+                }
+                // Ensure the number of members is within `N::MAX_DATA_ENTRIES`.
+                if entries.len() > N::MAX_DATA_ENTRIES {
+                    return Err(error("Failed to parse record: too many entries"));
+                }
+                Ok(entries)
+            })(string)?;
 
         // Return the record type.
         Ok((string, Self { name, owner, balance, entries: IndexMap::from_iter(entries.into_iter()) }))
@@ -184,6 +203,17 @@ record message:
         )?;
         assert_eq!("\n", remainder);
         assert_eq!(expected, candidate);
+
+        // Ensure a record with no entries besides the owner and balance is parsed.
+        let (remainder, candidate) = RecordType::<CurrentNetwork>::parse(
+            r"
+record credits:
+    owner as address.private;
+    balance as u64.private;
+",
+        )?;
+        assert_eq!("\n", remainder);
+        assert!(candidate.entries().is_empty());
         Ok(())
     }
 
@@ -20,6 +20,7 @@
 #[macro_use]
 extern crate enum_index_derive;
 
+pub use snarkvm_console_account::Signature;
 pub use snarkvm_console_network::Network;
 pub use snarkvm_console_types::prelude::*;
 
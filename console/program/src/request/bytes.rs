@@ -16,24 +16,69 @@
 
 use super::*;
 
+impl<N: Network> FromBytes for InputID<N> {
+    /// Reads the input ID from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the input ID.
+        let input_id = match variant {
+            0 => Self::Constant(Field::read_le(&mut reader)?),
+            1 => Self::Public(Field::read_le(&mut reader)?),
+            2 => Self::Private(Field::read_le(&mut reader)?),
+            3 => Self::Record(Group::read_le(&mut reader)?, Field::read_le(&mut reader)?),
+            4.. => return Err(error(format!("Failed to decode input ID variant {variant}"))),
+        };
+        Ok(input_id)
+    }
+}
+
+impl<N: Network> ToBytes for InputID<N> {
+    /// Writes the input ID to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(field) => {
+                0u8.write_le(&mut writer)?;
+                field.write_le(&mut writer)
+            }
+            Self::Public(field) => {
+                1u8.write_le(&mut writer)?;
+                field.write_le(&mut writer)
+            }
+            Self::Private(field) => {
+                2u8.write_le(&mut writer)?;
+                field.write_le(&mut writer)
+            }
+            Self::Record(gamma, serial_number) => {
+                3u8.write_le(&mut writer)?;
+                gamma.write_le(&mut writer)?;
+                serial_number.write_le(&mut writer)
+            }
+        }
+    }
+}
+
 impl<N: Network> FromBytes for Request<N> {
     /// Reads the request from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let caller = Address::read_le(&mut reader)?;
-        let network_id = U16::new(u16::read_le(&mut reader)?);
+        let network_id = U16::read_le(&mut reader)?;
         let program_id = ProgramID::read_le(&mut reader)?;
         let function_name = Identifier::read_le(&mut reader)?;
 
+        let input_ids_len = u16::read_le(&mut reader)? as usize;
+        let input_ids = (0..input_ids_len).map(|_| InputID::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
         let inputs_len = u16::read_le(&mut reader)? as usize;
+        if inputs_len != input_ids_len {
+            return Err(error(format!("Failed to read request. Found {inputs_len} inputs, expected {input_ids_len}")));
+        }
         let inputs = (0..inputs_len).map(|_| Value::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
 
-        Ok(Self {
-            caller,
-            network_id,
-            program_id,
-            function_name,
-            inputs,
-        })
+        let signature = Signature::read_le(&mut reader)?;
+        let tvk = Field::read_le(&mut reader)?;
+
+        Ok(Self { caller, network_id, program_id, function_name, input_ids, inputs, signature, tvk })
     }
 }
 
@@ -45,8 +90,13 @@ impl<N: Network> ToBytes for Request<N> {
         self.program_id.write_le(&mut writer)?;
         self.function_name.write_le(&mut writer)?;
 
-        if self.inputs.len() > u16::MAX as usize {
-            return Err(error(format!("Failed to write request. Too many inputs: '{}'", self.inputs.len())));
+        if self.inputs.len() > u16::MAX as usize || self.input_ids.len() != self.inputs.len() {
+            return Err(error(format!("Failed to write request. Invalid number of inputs: '{}'", self.inputs.len())));
+        }
+
+        (self.input_ids.len() as u16).write_le(&mut writer)?;
+        for input_id in &self.input_ids {
+            input_id.write_le(&mut writer)?;
         }
 
         (self.inputs.len() as u16).write_le(&mut writer)?;
@@ -54,6 +104,64 @@ impl<N: Network> ToBytes for Request<N> {
             input.write_le(&mut writer)?;
         }
 
+        self.signature.write_le(&mut writer)?;
+        self.tvk.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Plaintext, Record};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random private key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Construct a program ID and function name.
+            let program_id = ProgramID::from_str("token.aleo")?;
+            let function_name = Identifier::from_str("transfer")?;
+
+            // Prepare a record belonging to the address.
+            let record_string =
+                format!("{{ owner: {address}.private, balance: 5u64.private, token_amount: 100u64.private }}");
+
+            // Construct four inputs.
+            let input_constant = Value::Plaintext(Plaintext::from_str("{ token_amount: 9876543210u128 }")?);
+            let input_public = Value::Plaintext(Plaintext::from_str("{ token_amount: 9876543210u128 }")?);
+            let input_private = Value::Plaintext(Plaintext::from_str("{ token_amount: 9876543210u128 }")?);
+            let input_record = Value::Record(Record::from_str(&record_string)?);
+            let inputs = vec![input_constant, input_public, input_private, input_record];
+
+            // Construct the input types.
+            let input_types = vec![
+                ValueType::from_str("amount.constant")?,
+                ValueType::from_str("amount.public")?,
+                ValueType::from_str("amount.private")?,
+                ValueType::from_str("token.record")?,
+            ];
+
+            // Compute the signed request.
+            let expected = Request::sign(&private_key, program_id, function_name, inputs, &input_types, rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = Request::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert!(candidate.verify());
+            assert!(Request::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+            assert!(Request::<CurrentNetwork>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        }
         Ok(())
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-// mod bytes;
+mod bytes;
 mod sign;
 mod verify;
 
@@ -35,7 +35,7 @@ pub enum InputID<N: Network> {
     Record(Group<N>, Field<N>),
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Request<N: Network> {
     /// The request caller.
     caller: Address<N>,
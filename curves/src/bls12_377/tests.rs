@@ -408,6 +408,34 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+#[test]
+fn test_fr_large_subgroup_root_of_unity() {
+    use crate::bls12_377::FrParameters;
+
+    let large_root = Fr::large_subgroup_root_of_unity().unwrap();
+    let two_adic_order = 1u64 << FrParameters::TWO_ADICITY;
+    assert_eq!(large_root.pow([3 * two_adic_order]), Fr::one());
+    assert_ne!(large_root.pow([two_adic_order]), Fr::one());
+    assert_ne!(large_root.pow([3 * two_adic_order / 2]), Fr::one());
+    assert_eq!(large_root.pow([3]), Fr::two_adic_root_of_unity());
+
+    // Power-of-two roots of unity are unaffected by the small subgroup.
+    let mut omega = Fr::two_adic_root_of_unity();
+    for log_n in (0..=FrParameters::TWO_ADICITY).rev() {
+        assert_eq!(Fr::get_root_of_unity(1 << log_n), Some(omega));
+        omega.square_in_place();
+    }
+
+    // Mixed-radix roots of unity have the requested order.
+    for n in [3usize, 6, 12, 3 << 10] {
+        let omega = Fr::get_root_of_unity(n).unwrap();
+        assert_eq!(omega.pow([n as u64]), Fr::one());
+        assert_ne!(omega.pow([n as u64 / 3]), Fr::one());
+        assert_ne!(omega.pow([n as u64 / 2]), Fr::one());
+    }
+    assert!(Fr::get_root_of_unity(9).is_none());
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
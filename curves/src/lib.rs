@@ -27,10 +27,14 @@ extern crate thiserror;
 
 pub mod bls12_377;
 
+pub mod bw6_761;
+
 pub mod edwards_bls12;
 
 pub mod edwards_bw6;
 
+pub mod secp256k1;
+
 pub mod errors;
 pub use errors::*;
 
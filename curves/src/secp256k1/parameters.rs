@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    secp256k1::{Fq, Fr},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+pub type Secp256k1Affine = Affine<Secp256k1Parameters>;
+pub type Secp256k1Projective = Projective<Secp256k1Parameters>;
+
+/// The secp256k1 curve, `y^2 = x^3 + 7`, as specified in SEC 2.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Secp256k1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 7
+    const COEFF_B: Fq = field!(Fq, BigInteger256([0x700001ab7, 0x0, 0x0, 0x0]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field!(Fr, BigInteger256([0x402da1732fc9bebf, 0x4551231950b75fc4, 0x1, 0x0]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+///
+/// GENERATOR_X =
+/// 55066263022277343669578718895168534326250603453777594175500187360389116729240
+///
+pub const GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256([
+        15507633332195041431u64,
+        2530505477788034779u64,
+        10925531211367256732u64,
+        11061375339145502536u64,
+    ])
+);
+
+///
+/// GENERATOR_Y =
+/// 32670510020758816978083085130507043184471273380659243275938904335757337482424
+///
+pub const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256([
+        12780836216951778274u64,
+        10231155108014310989u64,
+        8121878653926228278u64,
+        14933801261141951190u64,
+    ])
+);
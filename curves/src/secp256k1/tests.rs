@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    secp256k1::*,
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{Field, One, Zero};
+
+use std::str::FromStr;
+
+const ITERATIONS: usize = 5;
+
+#[test]
+fn test_secp256k1_fr() {
+    for _ in 0..ITERATIONS {
+        let a: Fr = rand::random();
+        let b: Fr = rand::random();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        field_serialization_test::<Fr>();
+    }
+}
+
+#[test]
+fn test_secp256k1_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_full_width_modulus() {
+    // The moduli use the most significant bit, so sums and products may exceed the backing capacity.
+    let minus_one = -Fq::one();
+    let minus_two = -Fq::one().double();
+    assert_eq!(minus_one + minus_one, minus_two);
+    assert_eq!(minus_one.double(), minus_two);
+    assert_eq!(minus_one * minus_one, Fq::one());
+    assert_eq!(minus_one.square(), Fq::one());
+    assert_eq!(minus_two.inverse().unwrap() * minus_two, Fq::one());
+
+    let minus_one = -Fr::one();
+    let minus_two = -Fr::one().double();
+    assert_eq!(minus_one + minus_one, minus_two);
+    assert_eq!(minus_one.double(), minus_two);
+    assert_eq!(minus_one * minus_one, Fr::one());
+    assert_eq!(minus_one.square(), Fr::one());
+    assert_eq!(minus_two.inverse().unwrap() * minus_two, Fr::one());
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Secp256k1Projective>();
+    sw_tests::<Secp256k1Parameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    for _ in 0..ITERATIONS {
+        let a: Secp256k1Projective = rand::random();
+        let b: Secp256k1Projective = rand::random();
+        projective_test(a, b);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    for _ in 0..ITERATIONS {
+        let a: Secp256k1Affine = rand::random();
+        affine_test(a);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(!generator.is_zero());
+}
+
+#[test]
+fn test_generator_double() {
+    // Ensure the doubled generator matches the known value.
+    let expected_x =
+        Fq::from_str("89565891926547004231252920425935692360644145829622209833684329913297188986597").unwrap();
+    let expected_y =
+        Fq::from_str("12158399299693830322967808612713398636155367887041628176798871954788371653930").unwrap();
+
+    let double = Secp256k1Affine::prime_subgroup_generator().to_projective().double().to_affine();
+    assert_eq!(double.x, expected_x);
+    assert_eq!(double.y, expected_y);
+}
@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+};
+use serde::{Deserialize, Serialize};
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use std::marker::PhantomData;
+
+pub trait Bw6Parameters: 'static {
+    const X: &'static [u64];
+    const X_IS_NEGATIVE: bool;
+    const ATE_LOOP_COUNT_1: &'static [u64];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool;
+    /// The second Miller loop count, in non-adjacent form (least-significant digit first).
+    const ATE_LOOP_COUNT_2: &'static [i8];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool;
+    const TWIST_TYPE: TwistType;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp3Params = Self::Fp3Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+        BaseField = Self::Fp,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+/// The coefficients of `R0(x)`, from the highest degree term down, where
+/// `R0(x) + p * R1(x)` is a multiple of `(p^2 - p + 1) / r` that is coprime to `r`.
+/// See https://eprint.iacr.org/2020/351.pdf, Section 4.3.2.
+const FINAL_EXPONENT_R0: [i64; 8] = [-103, 70, 269, -197, -314, -73, -263, -220];
+/// The coefficients of `R1(x)`, from the highest degree term down.
+const FINAL_EXPONENT_R1: [i64; 10] = [103, -276, 77, 492, -445, -65, 452, -181, 34, 229];
+
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Bw6<P: Bw6Parameters>(PhantomData<fn() -> P>);
+
+type CoeffTriplet<T> = (T, T, T);
+
+impl<P: Bw6Parameters> Bw6<P> {
+    // Evaluate the line function at point p.
+    fn ell(f: &mut Fp6<P::Fp6Params>, coeffs: &CoeffTriplet<P::Fp>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    fn exp_by_x(f: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+        let mut result = f.pow(P::X);
+        if P::X_IS_NEGATIVE {
+            result.conjugate();
+        }
+        result
+    }
+
+    /// Returns `f^c`, for an element `f` of the cyclotomic subgroup.
+    fn exp_by_small(f: &Fp6<P::Fp6Params>, c: i64) -> Fp6<P::Fp6Params> {
+        let mut result = f.pow([c.unsigned_abs()]);
+        // In the cyclotomic subgroup, the inverse is the conjugate.
+        if c < 0 {
+            result.conjugate();
+        }
+        result
+    }
+
+    /// Returns `f^(poly(x))` using Horner's rule, for an element `f` of the cyclotomic subgroup.
+    fn exp_by_poly(f: &Fp6<P::Fp6Params>, coefficients: &[i64]) -> Fp6<P::Fp6Params> {
+        let mut result = Self::exp_by_small(f, coefficients[0]);
+        for c in &coefficients[1..] {
+            result = Self::exp_by_x(&result) * Self::exp_by_small(f, *c);
+        }
+        result
+    }
+}
+
+impl<P: Bw6Parameters> PairingEngine for Bw6<P>
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+{
+    type Fq = P::Fp;
+    type Fqe = P::Fp;
+    type Fqk = Fp6<P::Fp6Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        // Computes the optimal ate pairing of https://eprint.iacr.org/2020/351.pdf:
+        // f_{ATE_LOOP_COUNT_1, Q}(P) * f_{ATE_LOOP_COUNT_2, Q}(P)^p
+        let mut pairs = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.ell_coeffs_1.iter(), q.ell_coeffs_2.iter()));
+            }
+        }
+
+        let mut f_1 = Self::Fqk::one();
+
+        for i in BitIteratorBE::new(P::ATE_LOOP_COUNT_1).skip(1) {
+            f_1.square_in_place();
+
+            for &mut (p, ref mut coeffs, _) in &mut pairs {
+                Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs, _) in &mut pairs {
+                    Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_1_IS_NEGATIVE {
+            f_1.conjugate();
+        }
+
+        let mut f_2 = Self::Fqk::one();
+
+        for bit in P::ATE_LOOP_COUNT_2.iter().rev().skip(1) {
+            f_2.square_in_place();
+
+            for &mut (p, _, ref mut coeffs) in &mut pairs {
+                Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if *bit != 0 {
+                for &mut (p, _, ref mut coeffs) in &mut pairs {
+                    Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_2_IS_NEGATIVE {
+            f_2.conjugate();
+        }
+
+        f_2.frobenius_map(1);
+
+        f_1 * f_2
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // Easy part: f^((p^3 - 1) * (p + 1)).
+        let f_inverse = f.inverse()?;
+
+        // f1 = f^(p^3)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        // f2 = f^(p^3 - 1)
+        let f2 = f1 * f_inverse;
+
+        // r = f^((p^3 - 1) * (p + 1))
+        let mut r = f2;
+        r.frobenius_map(1);
+        r *= &f2;
+
+        // Hard part: r^(R0(x) + p * R1(x)).
+        let mut y = Self::exp_by_poly(&r, &FINAL_EXPONENT_R1);
+        y.frobenius_map(1);
+        y *= &Self::exp_by_poly(&r, &FINAL_EXPONENT_R0);
+        Some(y)
+    }
+}
@@ -96,6 +96,16 @@ mod testnet1 {
         assert_eq!(ALEO_TESTNET1_VIEW_KEY, view_key.to_string());
     }
 
+    #[test]
+    fn test_view_key_from_console_view_key() {
+        type ConsoleViewKey = snarkvm_console_account::ViewKey<snarkvm_console_network::Testnet3>;
+
+        let view_key = ViewKey::<Testnet1>::from(ConsoleViewKey::from_str(ALEO_TESTNET1_VIEW_KEY).unwrap());
+        assert_eq!(ALEO_TESTNET1_VIEW_KEY, view_key.to_string());
+        let address: Address<_> = view_key.into();
+        assert_eq!(ALEO_TESTNET1_ADDRESS, address.to_string());
+    }
+
     #[test]
     fn test_view_key_from_invalid_str() {
         assert!(ViewKey::<Testnet1>::from_str(ALEO_TESTNET1_PRIVATE_KEY).is_err());
@@ -50,6 +50,26 @@ impl<N: Network> From<&PrivateKey<N>> for ViewKey<N> {
     }
 }
 
+impl<N: Network, C> From<snarkvm_console_account::ViewKey<C>> for ViewKey<N>
+where
+    C: snarkvm_console_network::Network<Scalar = <N::AccountEncryptionScheme as EncryptionScheme>::PrivateKey>,
+{
+    /// Creates a new account view key from a console account view key over the same scalar field.
+    fn from(view_key: snarkvm_console_account::ViewKey<C>) -> Self {
+        Self::from(&view_key)
+    }
+}
+
+impl<N: Network, C> From<&snarkvm_console_account::ViewKey<C>> for ViewKey<N>
+where
+    C: snarkvm_console_network::Network<Scalar = <N::AccountEncryptionScheme as EncryptionScheme>::PrivateKey>,
+{
+    /// Creates a new account view key from a console account view key over the same scalar field.
+    fn from(view_key: &snarkvm_console_account::ViewKey<C>) -> Self {
+        Self(***view_key)
+    }
+}
+
 impl<N: Network> FromStr for ViewKey<N> {
     type Err = AccountError;
 
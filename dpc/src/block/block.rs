@@ -109,8 +109,7 @@ impl<N: Network> Block<N> {
         assert!(!(*transactions).is_empty(), "Cannot create block with no transactions");
 
         // Compute the block hash.
-        let block_hash =
-            N::block_hash_crh().hash_bytes(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?.into();
+        let block_hash = Self::compute_block_hash(previous_block_hash, &header)?;
 
         // Construct the block.
         let block = Self { block_hash, previous_block_hash, header, transactions };
@@ -122,6 +121,14 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Returns the block hash for the given previous block hash and block header.
+    pub fn compute_block_hash(
+        previous_block_hash: N::BlockHash,
+        header: &BlockHeader<N>,
+    ) -> Result<N::BlockHash, BlockError> {
+        Ok(N::block_hash_crh().hash_bytes(&to_bytes_le![previous_block_hash, header.to_header_root()?]?)?.into())
+    }
+
     /// Returns `true` if the block is well-formed.
     pub fn is_valid(&self) -> bool {
         // Ensure the previous block hash is well-formed.
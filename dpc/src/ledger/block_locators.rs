@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::io::{Read, Result as IoResult, Write};
+
+/// A list of block heights and hashes from a chain, starting at the most recent block
+/// and spaced exponentially further apart back to the genesis block.
+///
+/// A peer compares block locators against its own chain to find the most recent block
+/// the two chains have in common, from which it can then sync the missing blocks.
+#[derive(Clone, Derivative)]
+#[derivative(Debug(bound = "N: Network"), PartialEq(bound = "N: Network"), Eq(bound = "N: Network"))]
+pub struct BlockLocators<N: Network> {
+    /// The block heights and hashes, in order of decreasing block height.
+    block_locators: Vec<(u32, N::BlockHash)>,
+}
+
+impl<N: Network> BlockLocators<N> {
+    /// The number of most recent blocks that are included before the spacing starts to double.
+    pub const NUM_RECENT_BLOCK_LOCATORS: usize = 10;
+    /// The maximum number of block locators, which suffices to reach the genesis block from any block height.
+    pub const MAXIMUM_NUMBER_OF_BLOCK_LOCATORS: usize = Self::NUM_RECENT_BLOCK_LOCATORS + 32;
+
+    /// Initializes a new instance of block locators, ensuring they are strictly decreasing in
+    /// block height and end at the genesis block.
+    pub fn from(block_locators: Vec<(u32, N::BlockHash)>) -> Result<Self> {
+        // Ensure the number of block locators is within bounds.
+        if block_locators.is_empty() || block_locators.len() > Self::MAXIMUM_NUMBER_OF_BLOCK_LOCATORS {
+            return Err(anyhow!("Invalid number of block locators ({})", block_locators.len()));
+        }
+
+        // Ensure the block heights are strictly decreasing.
+        if block_locators.windows(2).any(|pair| pair[0].0 <= pair[1].0) {
+            return Err(anyhow!("Block locators must be in order of decreasing block height"));
+        }
+
+        // Ensure the last block locator is the genesis block.
+        if block_locators[block_locators.len() - 1].0 != 0 {
+            return Err(anyhow!("Block locators must end at the genesis block"));
+        }
+
+        Ok(Self { block_locators })
+    }
+
+    /// Returns the block heights and hashes, in order of decreasing block height.
+    pub fn iter(&self) -> impl Iterator<Item = &(u32, N::BlockHash)> + '_ {
+        self.block_locators.iter()
+    }
+
+    /// Returns the block height and hash of the most recent block.
+    pub fn latest(&self) -> (u32, N::BlockHash) {
+        self.block_locators[0]
+    }
+
+    /// Returns the genesis block hash.
+    pub fn genesis_block_hash(&self) -> N::BlockHash {
+        self.block_locators[self.block_locators.len() - 1].1
+    }
+
+    /// Returns the number of block locators.
+    pub fn len(&self) -> usize {
+        self.block_locators.len()
+    }
+
+    /// Returns `true` if there are no block locators.
+    pub fn is_empty(&self) -> bool {
+        self.block_locators.is_empty()
+    }
+}
+
+impl<N: Network> FromBytes for BlockLocators<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_block_locators: u8 = FromBytes::read_le(&mut reader)?;
+        if num_block_locators as usize > Self::MAXIMUM_NUMBER_OF_BLOCK_LOCATORS {
+            let maximum = Self::MAXIMUM_NUMBER_OF_BLOCK_LOCATORS;
+            return Err(error(format!("The number of block locators cannot exceed {maximum}")));
+        }
+
+        let mut block_locators = Vec::with_capacity(num_block_locators as usize);
+        for _ in 0..num_block_locators {
+            let height: u32 = FromBytes::read_le(&mut reader)?;
+            let block_hash = FromBytes::read_le(&mut reader)?;
+            block_locators.push((height, block_hash));
+        }
+        Self::from(block_locators).map_err(|e| error(format!("Failed to deserialize block locators: {e}")))
+    }
+}
+
+impl<N: Network> ToBytes for BlockLocators<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.block_locators.len() as u8).write_le(&mut writer)?;
+        for (height, block_hash) in &self.block_locators {
+            height.write_le(&mut writer)?;
+            block_hash.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_block_locators_serialization() {
+        let rng = &mut thread_rng();
+
+        let block_locators = vec![(11, rng.gen()), (10, rng.gen()), (8, rng.gen()), (4, rng.gen()), (0, rng.gen())];
+        let expected_block_locators = BlockLocators::<Testnet2>::from(block_locators).unwrap();
+        assert_eq!(5, expected_block_locators.len());
+
+        let candidate_block_locators =
+            BlockLocators::read_le(&expected_block_locators.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(expected_block_locators, candidate_block_locators);
+    }
+
+    #[test]
+    fn test_invalid_block_locators() {
+        let rng = &mut thread_rng();
+
+        // Block locators must be non-empty.
+        assert!(BlockLocators::<Testnet2>::from(vec![]).is_err());
+        // Block locators must be in order of decreasing block height.
+        assert!(BlockLocators::<Testnet2>::from(vec![(1, rng.gen()), (2, rng.gen()), (0, rng.gen())]).is_err());
+        assert!(BlockLocators::<Testnet2>::from(vec![(2, rng.gen()), (2, rng.gen()), (0, rng.gen())]).is_err());
+        // Block locators must end at the genesis block.
+        assert!(BlockLocators::<Testnet2>::from(vec![(2, rng.gen()), (1, rng.gen())]).is_err());
+    }
+}
@@ -19,7 +19,7 @@ use snarkvm_algorithms::merkle_tree::*;
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use time::OffsetDateTime;
 
 #[derive(Clone, Debug)]
@@ -152,6 +152,15 @@ impl<N: Network> Blocks<N> {
         }
     }
 
+    /// Returns the block height given the block hash.
+    pub fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<u32> {
+        // Note: The index of a block hash in the ledger tree is its block height.
+        match self.ledger_tree.get_block_hash_index(block_hash) {
+            Some(height) => Ok(*height),
+            None => Err(anyhow!("Missing block height for block hash {}", block_hash)),
+        }
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_height(&self, height: u32) -> bool {
         self.previous_hashes.contains_key(&height)
@@ -165,6 +174,16 @@ impl<N: Network> Blocks<N> {
             || self.headers.values().map(BlockHeader::previous_ledger_root).any(|root| root == *ledger_root)
     }
 
+    /// Returns the ledger roots of the given number of most recent blocks.
+    pub fn get_recent_ledger_roots(&self, num_blocks: u32) -> HashSet<N::LedgerRoot> {
+        // Note: The header of each block contains the ledger root as of its previous block.
+        let start_height = self.current_height.saturating_sub(num_blocks.saturating_sub(1)).saturating_add(1);
+        (start_height..=self.current_height)
+            .filter_map(|height| self.headers.get(&height).map(BlockHeader::previous_ledger_root))
+            .chain(std::iter::once(self.latest_ledger_root()))
+            .collect()
+    }
+
     /// Returns `true` if the given block hash exists.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
         self.current_hash == *block_hash || self.previous_hashes.values().any(|hash| *hash == *block_hash)
@@ -220,39 +239,12 @@ impl<N: Network> Blocks<N> {
             return Err(anyhow!("The given block hash already exists in the ledger"));
         }
 
-        // Ensure the next block timestamp is within the declared time limit.
-        let now = OffsetDateTime::now_utc().unix_timestamp();
-        if block.timestamp() > (now + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS) {
-            return Err(anyhow!("The given block timestamp exceeds the time limit"));
-        }
-
-        // Ensure the next block timestamp is after the current block timestamp.
-        let current_block = self.latest_block()?;
-        if block.timestamp() <= current_block.timestamp() {
-            return Err(anyhow!("The given block timestamp is before the current timestamp"));
-        }
-
-        // Ensure the expected difficulty target is met.
-        let expected_difficulty_target =
-            Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block.timestamp(), block.height());
-        if block.difficulty_target() != expected_difficulty_target {
-            return Err(anyhow!(
-                "The given block difficulty target is incorrect. Found {}, but expected {}",
-                block.difficulty_target(),
-                expected_difficulty_target
-            ));
-        }
-
-        // Ensure the expected cumulative weight is computed correctly.
-        let expected_cumulative_weight =
-            current_block.cumulative_weight().saturating_add((u64::MAX / expected_difficulty_target) as u128);
-        if block.cumulative_weight() != expected_cumulative_weight {
-            return Err(anyhow!(
-                "The given cumulative weight is incorrect. Found {}, but expected {}",
-                block.cumulative_weight(),
-                expected_cumulative_weight
-            ));
-        }
+        // Ensure the block header is a valid successor of the current block header.
+        Self::check_block_header_metadata(
+            self.get_block_header(self.current_height)?,
+            &self.get_difficulty_samples(self.current_height)?,
+            block.header(),
+        )?;
 
         for transaction in block.transactions().iter() {
             // Ensure the transaction in the block do not already exist.
@@ -299,6 +291,185 @@ impl<N: Network> Blocks<N> {
         Ok(())
     }
 
+    /// Returns the block locators of the canon chain, starting at the latest block
+    /// and spaced exponentially further apart back to the genesis block.
+    pub fn get_block_locators(&self) -> Result<BlockLocators<N>> {
+        let mut block_locators = Vec::with_capacity(BlockLocators::<N>::MAXIMUM_NUMBER_OF_BLOCK_LOCATORS);
+        let mut height = self.current_height;
+        let mut step = 1;
+        loop {
+            block_locators.push((height, self.get_block_hash(height)?));
+            if height == 0 {
+                break;
+            }
+
+            // Double the spacing after the most recent block locators.
+            if block_locators.len() >= BlockLocators::<N>::NUM_RECENT_BLOCK_LOCATORS {
+                step *= 2;
+            }
+            height = height.saturating_sub(step);
+        }
+
+        BlockLocators::from(block_locators)
+    }
+
+    /// Returns the block height of the most recent block in the given block locators that is on the canon chain.
+    pub fn find_common_ancestor(&self, block_locators: &BlockLocators<N>) -> Result<u32> {
+        for (height, block_hash) in block_locators.iter() {
+            if *height <= self.current_height && self.get_block_hash(*height)? == *block_hash {
+                return Ok(*height);
+            }
+        }
+        Err(anyhow!("The given block locators do not share a common ancestor with the canon chain"))
+    }
+
+    /// Returns the block headers of the canon chain, from the given start height up to and including the end height.
+    pub fn get_block_headers(&self, start_height: u32, end_height: u32) -> Result<Vec<BlockHeader<N>>> {
+        if start_height > end_height || end_height > self.current_height {
+            return Err(anyhow!("Invalid block header range {}..={}", start_height, end_height));
+        }
+        (start_height..=end_height).map(|height| Ok(self.get_block_header(height)?.clone())).collect()
+    }
+
+    /// Ensures the given block headers form a valid chain extending the canon block with the given hash,
+    /// returning the block hash of each header. This checks the proof of succinct work, difficulty target,
+    /// cumulative weight, and timestamp of each header, without requiring the block transactions.
+    pub fn check_block_headers(
+        &self,
+        previous_block_hash: N::BlockHash,
+        block_headers: &[BlockHeader<N>],
+    ) -> Result<Vec<N::BlockHash>> {
+        let previous_block_height = self.get_block_height(&previous_block_hash)?;
+        let mut previous_block_header = self.get_block_header(previous_block_height)?;
+        let mut previous_block_hash = previous_block_hash;
+        let mut recent_blocks = self.get_difficulty_samples(previous_block_height)?;
+
+        let mut block_hashes = Vec::with_capacity(block_headers.len());
+        for block_header in block_headers {
+            Self::check_next_block_header(previous_block_header, &recent_blocks, block_header)?;
+
+            previous_block_hash = Block::compute_block_hash(previous_block_hash, block_header)?;
+            previous_block_header = block_header;
+            block_hashes.push(previous_block_hash);
+
+            // Slide the window of recent blocks forward.
+            recent_blocks.push(block_header.into());
+            if recent_blocks.len() > N::DifficultyAlgorithm::NUM_RECENT_BLOCKS {
+                recent_blocks.remove(0);
+            }
+        }
+        Ok(block_hashes)
+    }
+
+    /// Ensures the given block header is a valid successor of the given previous block header,
+    /// checking its proof of succinct work, difficulty target, cumulative weight, and timestamp.
+    /// The recent blocks are the difficulty samples of the blocks up to and including the previous block.
+    pub fn check_next_block_header(
+        previous_block_header: &BlockHeader<N>,
+        recent_blocks: &[DifficultySample],
+        block_header: &BlockHeader<N>,
+    ) -> Result<()> {
+        // Ensure the block header itself is valid.
+        if !block_header.is_valid() {
+            return Err(anyhow!("The given block header is invalid"));
+        }
+
+        Self::check_block_header_metadata(previous_block_header, recent_blocks, block_header)
+    }
+
+    /// Returns the difficulty samples of the `N::DifficultyAlgorithm::NUM_RECENT_BLOCKS` most recent blocks
+    /// up to and including the given block height, in order of increasing block height.
+    pub fn get_difficulty_samples(&self, height: u32) -> Result<Vec<DifficultySample>> {
+        let num_recent_blocks = N::DifficultyAlgorithm::NUM_RECENT_BLOCKS as u32;
+        let start_height = (height + 1).saturating_sub(num_recent_blocks);
+        (start_height..=height).map(|height| Ok(self.get_block_header(height)?.into())).collect()
+    }
+
+    /// Ensures the height, timestamp, difficulty target, and cumulative weight of the given block header
+    /// are valid for a successor of the given previous block header.
+    fn check_block_header_metadata(
+        previous_block_header: &BlockHeader<N>,
+        recent_blocks: &[DifficultySample],
+        block_header: &BlockHeader<N>,
+    ) -> Result<()> {
+        // Ensure the block height is the next block height.
+        if previous_block_header.height() + 1 != block_header.height() {
+            return Err(anyhow!("The given block has an incorrect block height"));
+        }
+
+        // Ensure the next block timestamp is within the declared time limit.
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        if block_header.timestamp() > (now + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS) {
+            return Err(anyhow!("The given block timestamp exceeds the time limit"));
+        }
+
+        // Ensure the next block timestamp is after the previous block timestamp.
+        if block_header.timestamp() <= previous_block_header.timestamp() {
+            return Err(anyhow!("The given block timestamp is before the current timestamp"));
+        }
+
+        // Ensure the expected difficulty target is met.
+        let expected_difficulty_target =
+            Blocks::<N>::compute_difficulty_target(recent_blocks, block_header.timestamp(), block_header.height());
+        if block_header.difficulty_target() != expected_difficulty_target {
+            return Err(anyhow!(
+                "The given block difficulty target is incorrect. Found {}, but expected {}",
+                block_header.difficulty_target(),
+                expected_difficulty_target
+            ));
+        }
+
+        // Ensure the expected cumulative weight is computed correctly.
+        let expected_cumulative_weight =
+            previous_block_header.cumulative_weight().saturating_add((u64::MAX / expected_difficulty_target) as u128);
+        if block_header.cumulative_weight() != expected_cumulative_weight {
+            return Err(anyhow!(
+                "The given cumulative weight is incorrect. Found {}, but expected {}",
+                block_header.cumulative_weight(),
+                expected_cumulative_weight
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Removes all blocks above the given block height, returning them in order of increasing height.
+    /// This reverts the ledger tree, serial numbers, and commitments introduced by the removed blocks.
+    /// Note: This method will not revert more than `N::ALEO_MAXIMUM_FORK_DEPTH` blocks.
+    pub fn revert_to_height(&mut self, height: u32) -> Result<Vec<Block<N>>> {
+        // Ensure the block height exists.
+        if height > self.current_height {
+            return Err(anyhow!("Given block height {} is greater than current height", height));
+        }
+
+        // Ensure the number of reverted blocks is within the maximum fork depth.
+        let num_blocks = self.current_height - height;
+        if num_blocks > N::ALEO_MAXIMUM_FORK_DEPTH {
+            return Err(anyhow!("Reverting {} blocks exceeds the maximum fork depth", num_blocks));
+        }
+
+        let reverted_blocks =
+            (height + 1..=self.current_height).map(|height| self.get_block(height)).collect::<Result<Vec<_>>>()?;
+
+        // Remove the blocks from the ledger. This code section executes atomically.
+        {
+            let mut blocks = self.clone();
+
+            blocks.current_height = height;
+            blocks.current_hash = self.get_block_hash(height)?;
+            blocks.ledger_tree.remove_last(num_blocks)?;
+            for block in &reverted_blocks {
+                blocks.previous_hashes.remove(&block.height());
+                blocks.headers.remove(&block.height());
+                blocks.transactions.remove(&block.height());
+            }
+
+            *self = blocks;
+        }
+
+        Ok(reverted_blocks)
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         &self.ledger_tree
@@ -399,116 +570,21 @@ impl<N: Network> Blocks<N> {
         LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
     }
 
-    /// Returns the expected difficulty target given the previous block and expected next block details.
+    /// Returns the expected difficulty target of the next block, given the most recent blocks
+    /// in order of increasing block height, and the timestamp and height of the next block.
     pub fn compute_difficulty_target(
-        anchor_block_header: &BlockHeader<N>,
+        recent_blocks: &[DifficultySample],
         block_timestamp: i64,
         block_height: u32,
     ) -> u64 {
-        Self::asert_retarget(
-            anchor_block_header.timestamp(),
-            anchor_block_header.difficulty_target(),
-            anchor_block_header.height(),
+        N::DifficultyAlgorithm::compute_difficulty_target(
+            &N::genesis_block().header().into(),
+            recent_blocks,
             block_timestamp,
             block_height,
             N::ALEO_BLOCK_TIME_IN_SECS,
         )
     }
-
-    /// ASERT difficulty retarget algorithm based on https://www.reference.cash/protocol/forks/2020-11-15-asert.
-    ///     T_{i+1} = T_anchor * 2^((S - B * N) / tau).
-    ///     T_anchor = Anchor target of a specific block height
-    ///     B = Expected time per block.
-    ///     S = Time elapsed since the anchor.
-    ///     N = Number of blocks since the anchor.
-    ///     tau = The half life of the algorithm. For every `tau` seconds ahead of
-    ///           schedule a block’s timestamp becomes, the difficulty doubles.
-    /// To avoid use of floating points, we use fixed-point arithmetic.
-    fn asert_retarget(
-        anchor_timestamp: i64,
-        anchor_difficulty_target: u64,
-        anchor_block_height: u32,
-        block_timestamp: i64,
-        block_height: u32,
-        target_block_time: i64,
-    ) -> u64 {
-        // Compute the difference in block time elapsed, defined as:
-        // (block_timestamp - anchor_timestamp) - target_block_time * number_of_blocks_elapsed.
-        let drift = {
-            // Determine the block time elapsed (in seconds) since the anchor block.
-            // Note: This operation includes a safety check for a repeat timestamp.
-            let block_time_elapsed = core::cmp::max(block_timestamp.saturating_sub(anchor_timestamp), 1);
-
-            // Determine the number of blocks since the anchor.
-            // Note: This operation includes a safety check for a repeat block height.
-            let number_of_blocks_elapsed = core::cmp::max(block_height.saturating_sub(anchor_block_height), 1);
-
-            // Determine the expected block time elapsed (in seconds) since the anchor block.
-            let expected_block_time_elapsed = target_block_time.saturating_mul(number_of_blocks_elapsed as i64);
-
-            // Determine the difference in block time elapsed (in seconds).
-            // Note: This operation must be *standard subtraction* to account for faster blocks.
-            block_time_elapsed - expected_block_time_elapsed
-        };
-
-        // Constants used for fixed point arithmetic.
-        const RBITS: u32 = 16;
-        const RADIX: u128 = 1 << RBITS;
-
-        // The half life for the expected duration in doubling the difficulty target.
-        const TAU: u128 = 64_800; // 64,800 seconds = 18 hours
-
-        // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
-        let (integral, fractional) = {
-            // Calculate the exponent factor.
-            let exponent = (RADIX as i128).saturating_mul(drift as i128) / (TAU as i128);
-
-            // Decompose into the integral and fractional parts.
-            let integral = exponent >> RBITS;
-            let fractional = (exponent - (integral << RBITS)) as u128;
-            assert!(fractional < RADIX, "Ensure fractional part is within fixed point size");
-            assert_eq!(exponent, integral * (RADIX as i128) + fractional as i128);
-
-            (integral, fractional)
-        };
-
-        // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
-        // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
-        let fractional_multiplier = RADIX
-            + ((195_766_423_245_049_u128 * fractional
-                + 971_821_376_u128 * fractional.pow(2)
-                + 5_127_u128 * fractional.pow(3)
-                + 2_u128.pow(RBITS * 3 - 1))
-                >> (RBITS * 3));
-
-        // Cast the anchor difficulty target from a u64 to a u128.
-        // The difficulty target must allow for leading zeros to account for overflows;
-        // an additional 64-bits for the leading zeros suffices.
-        let candidate_difficulty_target = (anchor_difficulty_target as u128).saturating_mul(fractional_multiplier);
-
-        // Calculate the new difficulty.
-        // Shift the target to multiply by 2^(integer) / RADIX.
-        let shifts = integral - RBITS as i128;
-        let mut candidate_difficulty_target = if shifts < 0 {
-            match candidate_difficulty_target.checked_shr((-shifts) as u32) {
-                Some(target) => core::cmp::max(target, 1),
-                None => 1,
-            }
-        } else {
-            match candidate_difficulty_target.checked_shl(shifts as u32) {
-                Some(target) => core::cmp::max(target, 1),
-                None => u64::MAX as u128,
-            }
-        };
-
-        // Cap the difficulty target at `u64::MAX` if it has overflowed.
-        candidate_difficulty_target = core::cmp::min(candidate_difficulty_target, u64::MAX as u128);
-
-        // Cast the new difficulty target down from a u128 to a u64.
-        // Ensure that the leading 64 bits are zeros.
-        assert_eq!(candidate_difficulty_target.checked_shr(64), Some(0));
-        candidate_difficulty_target as u64
-    }
 }
 
 #[cfg(test)]
@@ -539,7 +615,7 @@ mod tests {
             let simulated_time_elapsed = (simulated_block_height - anchor_block_height) as i64 * simulated_block_time;
 
             let simulated_timestamp = anchor_timestamp.saturating_add(simulated_time_elapsed);
-            let candidate_difficulty_target = Blocks::<Testnet2>::asert_retarget(
+            let candidate_difficulty_target = <Testnet2 as Network>::DifficultyAlgorithm::retarget(
                 anchor_timestamp,
                 anchor_difficulty_target,
                 anchor_block_height,
@@ -604,7 +680,7 @@ mod tests {
                 let drift = simulated_time_elapsed - expected_time_elapsed;
 
                 let simulated_timestamp = anchor_timestamp.saturating_add(simulated_time_elapsed);
-                let candidate_difficulty_target = Blocks::<Testnet2>::asert_retarget(
+                let candidate_difficulty_target = <Testnet2 as Network>::DifficultyAlgorithm::retarget(
                     anchor_timestamp,
                     anchor_difficulty_target,
                     anchor_block_height,
@@ -680,7 +756,7 @@ mod tests {
             let simulated_time_elapsed = (simulated_block_height - anchor_block_height) as i64 * simulated_block_time;
 
             let simulated_timestamp = anchor_timestamp.saturating_add(simulated_time_elapsed);
-            let candidate_difficulty_target = Blocks::<Testnet2>::asert_retarget(
+            let candidate_difficulty_target = <Testnet2 as Network>::DifficultyAlgorithm::retarget(
                 anchor_timestamp,
                 anchor_difficulty_target,
                 anchor_block_height,
@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+/// The block height, timestamp, and difficulty target of a block, used to retarget the difficulty.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DifficultySample {
+    height: u32,
+    timestamp: i64,
+    difficulty_target: u64,
+}
+
+impl DifficultySample {
+    /// Initializes a new instance of a difficulty sample.
+    pub fn new(height: u32, timestamp: i64, difficulty_target: u64) -> Self {
+        Self { height, timestamp, difficulty_target }
+    }
+
+    /// Returns the block height.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block timestamp.
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the block difficulty target.
+    pub fn difficulty_target(&self) -> u64 {
+        self.difficulty_target
+    }
+}
+
+impl<N: Network> From<&BlockHeader<N>> for DifficultySample {
+    fn from(block_header: &BlockHeader<N>) -> Self {
+        Self::new(block_header.height(), block_header.timestamp(), block_header.difficulty_target())
+    }
+}
+
+/// ASERT difficulty retarget algorithm based on https://www.reference.cash/protocol/forks/2020-11-15-asert,
+/// with a half life of `HALF_LIFE_IN_SECS` seconds. The difficulty target is computed relative to the anchor block.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ASERT<const HALF_LIFE_IN_SECS: i64>;
+
+impl<const HALF_LIFE_IN_SECS: i64> DifficultyAlgorithm for ASERT<HALF_LIFE_IN_SECS> {
+    const NUM_RECENT_BLOCKS: usize = 0;
+
+    fn compute_difficulty_target(
+        anchor_block: &DifficultySample,
+        _recent_blocks: &[DifficultySample],
+        block_timestamp: i64,
+        block_height: u32,
+        target_block_time: i64,
+    ) -> u64 {
+        Self::retarget(
+            anchor_block.timestamp(),
+            anchor_block.difficulty_target(),
+            anchor_block.height(),
+            block_timestamp,
+            block_height,
+            target_block_time,
+        )
+    }
+}
+
+impl<const HALF_LIFE_IN_SECS: i64> ASERT<HALF_LIFE_IN_SECS> {
+    /// ASERT difficulty retarget algorithm based on https://www.reference.cash/protocol/forks/2020-11-15-asert.
+    ///     T_{i+1} = T_anchor * 2^((S - B * N) / tau).
+    ///     T_anchor = Anchor target of a specific block height
+    ///     B = Expected time per block.
+    ///     S = Time elapsed since the anchor.
+    ///     N = Number of blocks since the anchor.
+    ///     tau = The half life of the algorithm. For every `tau` seconds ahead of
+    ///           schedule a block’s timestamp becomes, the difficulty doubles.
+    /// To avoid use of floating points, we use fixed-point arithmetic.
+    pub fn retarget(
+        anchor_timestamp: i64,
+        anchor_difficulty_target: u64,
+        anchor_block_height: u32,
+        block_timestamp: i64,
+        block_height: u32,
+        target_block_time: i64,
+    ) -> u64 {
+        // Compute the difference in block time elapsed, defined as:
+        // (block_timestamp - anchor_timestamp) - target_block_time * number_of_blocks_elapsed.
+        let drift = {
+            // Determine the block time elapsed (in seconds) since the anchor block.
+            // Note: This operation includes a safety check for a repeat timestamp.
+            let block_time_elapsed = core::cmp::max(block_timestamp.saturating_sub(anchor_timestamp), 1);
+
+            // Determine the number of blocks since the anchor.
+            // Note: This operation includes a safety check for a repeat block height.
+            let number_of_blocks_elapsed = core::cmp::max(block_height.saturating_sub(anchor_block_height), 1);
+
+            // Determine the expected block time elapsed (in seconds) since the anchor block.
+            let expected_block_time_elapsed = target_block_time.saturating_mul(number_of_blocks_elapsed as i64);
+
+            // Determine the difference in block time elapsed (in seconds).
+            // Note: This operation must be *standard subtraction* to account for faster blocks.
+            block_time_elapsed - expected_block_time_elapsed
+        };
+
+        // Constants used for fixed point arithmetic.
+        const RBITS: u32 = 16;
+        const RADIX: u128 = 1 << RBITS;
+
+        // The half life for the expected duration in doubling the difficulty target.
+        let tau = HALF_LIFE_IN_SECS as i128;
+
+        // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
+        let (integral, fractional) = {
+            // Calculate the exponent factor.
+            let exponent = (RADIX as i128).saturating_mul(drift as i128) / tau;
+
+            // Decompose into the integral and fractional parts.
+            let integral = exponent >> RBITS;
+            let fractional = (exponent - (integral << RBITS)) as u128;
+            assert!(fractional < RADIX, "Ensure fractional part is within fixed point size");
+            assert_eq!(exponent, integral * (RADIX as i128) + fractional as i128);
+
+            (integral, fractional)
+        };
+
+        // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
+        // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
+        let fractional_multiplier = RADIX
+            + ((195_766_423_245_049_u128 * fractional
+                + 971_821_376_u128 * fractional.pow(2)
+                + 5_127_u128 * fractional.pow(3)
+                + 2_u128.pow(RBITS * 3 - 1))
+                >> (RBITS * 3));
+
+        // Cast the anchor difficulty target from a u64 to a u128.
+        // The difficulty target must allow for leading zeros to account for overflows;
+        // an additional 64-bits for the leading zeros suffices.
+        let candidate_difficulty_target = (anchor_difficulty_target as u128).saturating_mul(fractional_multiplier);
+
+        // Calculate the new difficulty.
+        // Shift the target to multiply by 2^(integer) / RADIX.
+        let shifts = integral - RBITS as i128;
+        let mut candidate_difficulty_target = if shifts < 0 {
+            match candidate_difficulty_target.checked_shr((-shifts) as u32) {
+                Some(target) => core::cmp::max(target, 1),
+                None => 1,
+            }
+        } else {
+            match candidate_difficulty_target.checked_shl(shifts as u32) {
+                Some(target) => core::cmp::max(target, 1),
+                None => u64::MAX as u128,
+            }
+        };
+
+        // Cap the difficulty target at `u64::MAX` if it has overflowed.
+        candidate_difficulty_target = core::cmp::min(candidate_difficulty_target, u64::MAX as u128);
+
+        // Cast the new difficulty target down from a u128 to a u64.
+        // Ensure that the leading 64 bits are zeros.
+        assert_eq!(candidate_difficulty_target.checked_shr(64), Some(0));
+        candidate_difficulty_target as u64
+    }
+}
+
+/// Windowed moving-average difficulty retarget algorithm, which scales the average difficulty target
+/// of the most recent `WINDOW` blocks by the ratio of their actual to expected block time elapsed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MovingAverage<const WINDOW: usize>;
+
+impl<const WINDOW: usize> MovingAverage<WINDOW> {
+    /// The maximum factor by which the difficulty target may change in one retarget.
+    const MAXIMUM_ADJUSTMENT_FACTOR: i64 = 4;
+}
+
+impl<const WINDOW: usize> DifficultyAlgorithm for MovingAverage<WINDOW> {
+    const NUM_RECENT_BLOCKS: usize = WINDOW + 1;
+
+    fn compute_difficulty_target(
+        anchor_block: &DifficultySample,
+        recent_blocks: &[DifficultySample],
+        _block_timestamp: i64,
+        _block_height: u32,
+        target_block_time: i64,
+    ) -> u64 {
+        // Only consider the most recent blocks, which span (at most) `WINDOW` block times.
+        let recent_blocks = &recent_blocks[recent_blocks.len().saturating_sub(Self::NUM_RECENT_BLOCKS)..];
+
+        // If there are not yet enough blocks to measure a block time, use the latest difficulty target.
+        let (first_block, last_block) = match (recent_blocks.first(), recent_blocks.last()) {
+            (Some(first_block), Some(last_block)) if first_block.height() < last_block.height() => {
+                (first_block, last_block)
+            }
+            (_, Some(last_block)) => return last_block.difficulty_target(),
+            (_, None) => return anchor_block.difficulty_target(),
+        };
+
+        // Determine the expected block time elapsed (in seconds) over the window.
+        let number_of_blocks_elapsed = (last_block.height() - first_block.height()) as i64;
+        let expected_block_time_elapsed = core::cmp::max(target_block_time.saturating_mul(number_of_blocks_elapsed), 1);
+
+        // Determine the block time elapsed (in seconds) over the window, bounded to limit each adjustment.
+        let block_time_elapsed = last_block.timestamp().saturating_sub(first_block.timestamp()).clamp(
+            core::cmp::max(expected_block_time_elapsed / Self::MAXIMUM_ADJUSTMENT_FACTOR, 1),
+            expected_block_time_elapsed.saturating_mul(Self::MAXIMUM_ADJUSTMENT_FACTOR),
+        );
+
+        // Compute the average difficulty target of the blocks mined within the window.
+        let average_difficulty_target =
+            recent_blocks[1..].iter().map(|block| block.difficulty_target() as u128).sum::<u128>()
+                / (recent_blocks.len() - 1) as u128;
+
+        // Scale the average difficulty target, where slower blocks lead to a larger (easier) difficulty target.
+        let candidate_difficulty_target =
+            average_difficulty_target.saturating_mul(block_time_elapsed as u128) / expected_block_time_elapsed as u128;
+
+        candidate_difficulty_target.clamp(1, u64::MAX as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+
+    const ANCHOR_TIMESTAMP: i64 = 1640179531;
+    const ANCHOR_DIFFICULTY_TARGET: u64 = u64::MAX / 1_000_000;
+
+    /// Replays the timestamps of a chain mined with the given hash rate (in hashes per second),
+    /// returning the average block time (in seconds) of the last `window` blocks.
+    /// If an RNG is given, block times are sampled from an exponential distribution.
+    fn simulate<A: DifficultyAlgorithm>(
+        hash_rate: f64,
+        target_block_time: i64,
+        num_blocks: u32,
+        window: u32,
+        mut rng: Option<&mut ChaChaRng>,
+    ) -> f64 {
+        let anchor_block = DifficultySample::new(0, ANCHOR_TIMESTAMP, ANCHOR_DIFFICULTY_TARGET);
+        let mut blocks = vec![anchor_block];
+
+        for height in 1..=num_blocks {
+            let previous_block = blocks[blocks.len() - 1];
+            let recent_blocks = &blocks[blocks.len().saturating_sub(A::NUM_RECENT_BLOCKS)..];
+
+            // Sample the time to find a block, relative to its expected time.
+            let relative_block_time = match rng.as_mut() {
+                Some(rng) => -(1.0 - rng.gen::<f64>()).ln(),
+                None => 1.0,
+            };
+
+            // The difficulty target may depend on the block timestamp, which in turn depends on
+            // the time to find a block at the difficulty target, so iterate to a consistent pair.
+            let mut timestamp = previous_block.timestamp() + target_block_time;
+            for _ in 0..4 {
+                let difficulty_target =
+                    A::compute_difficulty_target(&anchor_block, recent_blocks, timestamp, height, target_block_time);
+                let block_time = relative_block_time * (u64::MAX / difficulty_target) as f64 / hash_rate;
+                timestamp = previous_block.timestamp() + core::cmp::max(block_time.round() as i64, 1);
+            }
+            let difficulty_target =
+                A::compute_difficulty_target(&anchor_block, recent_blocks, timestamp, height, target_block_time);
+            blocks.push(DifficultySample::new(height, timestamp, difficulty_target));
+        }
+
+        let last_block = blocks[blocks.len() - 1];
+        let first_block = blocks[blocks.len() - 1 - window as usize];
+        (last_block.timestamp() - first_block.timestamp()) as f64 / window as f64
+    }
+
+    fn assert_converges<A: DifficultyAlgorithm>(num_blocks: u32, tolerance: f64) {
+        // The anchor difficulty target is calibrated to a block time of 1 second at 1,000,000 hashes per second.
+        for target_block_time in [5, 20, 60] {
+            for hash_rate in [100_000.0, 1_000_000.0, 25_000_000.0] {
+                let average_block_time = simulate::<A>(hash_rate, target_block_time, num_blocks, 100, None);
+                let error = (average_block_time - target_block_time as f64).abs() / target_block_time as f64;
+                assert!(
+                    error < tolerance,
+                    "Average block time is {} seconds with a target of {} seconds at {} hashes per second",
+                    average_block_time,
+                    target_block_time,
+                    hash_rate
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_asert_matches_retarget() {
+        let anchor_block = DifficultySample::new(72154, ANCHOR_TIMESTAMP, 101336179232188);
+        for i in 1..100 {
+            let (block_timestamp, block_height) = (ANCHOR_TIMESTAMP + i * 7, 72154 + i as u32);
+            assert_eq!(
+                ASERT::<64_800>::retarget(ANCHOR_TIMESTAMP, 101336179232188, 72154, block_timestamp, block_height, 20),
+                ASERT::<64_800>::compute_difficulty_target(&anchor_block, &[], block_timestamp, block_height, 20)
+            );
+        }
+    }
+
+    #[test]
+    fn test_asert_simulation() {
+        // A short half life allows the simulation to converge within tens of thousands of blocks.
+        assert_converges::<ASERT<3_600>>(20_000, 0.05);
+    }
+
+    #[test]
+    fn test_moving_average_simulation() {
+        assert_converges::<MovingAverage<72>>(2_000, 0.05);
+    }
+
+    #[test]
+    fn test_random_block_time_simulation() {
+        let rng = &mut ChaChaRng::seed_from_u64(1234);
+
+        let average_block_time = simulate::<ASERT<3_600>>(5_000_000.0, 20, 10_000, 1_000, Some(rng));
+        assert!((average_block_time - 20.0).abs() < 2.0, "Average block time is {}", average_block_time);
+
+        let average_block_time = simulate::<MovingAverage<72>>(5_000_000.0, 20, 10_000, 1_000, Some(rng));
+        assert!((average_block_time - 20.0).abs() < 2.0, "Average block time is {}", average_block_time);
+    }
+
+    #[test]
+    fn test_moving_average_adjustment_is_bounded() {
+        let blocks =
+            (0..=10).map(|i| DifficultySample::new(i, ANCHOR_TIMESTAMP + i as i64, 1_000_000)).collect::<Vec<_>>();
+        let anchor_block = blocks[0];
+
+        // Blocks found much faster than expected at most quadruple the difficulty.
+        let difficulty_target = MovingAverage::<10>::compute_difficulty_target(&anchor_block, &blocks, 0, 11, 1_000);
+        assert_eq!(250_000, difficulty_target);
+
+        // Without enough blocks to measure a block time, the latest difficulty target is used.
+        assert_eq!(1_000_000, MovingAverage::<10>::compute_difficulty_target(&anchor_block, &blocks[..1], 0, 1, 20));
+        assert_eq!(
+            ANCHOR_DIFFICULTY_TARGET,
+            MovingAverage::<10>::compute_difficulty_target(
+                &DifficultySample::new(0, ANCHOR_TIMESTAMP, ANCHOR_DIFFICULTY_TARGET),
+                &[],
+                0,
+                1,
+                20
+            )
+        );
+    }
+}
@@ -18,15 +18,18 @@ use crate::prelude::*;
 
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, Rng};
-use std::{collections::HashMap, sync::atomic::AtomicBool};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::AtomicBool,
+};
 use time::OffsetDateTime;
 
 #[derive(Clone, Debug)]
 pub struct Ledger<N: Network> {
     /// The canonical chain of blocks.
     canon_blocks: Blocks<N>,
-    /// The set of unknown orphan blocks.
-    orphan_blocks: HashMap<u32, Block<N>>,
+    /// The set of orphan blocks, keyed by their previous block hash.
+    orphan_blocks: HashMap<N::BlockHash, Vec<Block<N>>>,
     /// The pool of unconfirmed transactions.
     memory_pool: MemoryPool<N>,
 }
@@ -34,7 +37,12 @@ pub struct Ledger<N: Network> {
 impl<N: Network> Ledger<N> {
     /// Initializes a new instance of the ledger.
     pub fn new() -> Result<Self> {
-        Ok(Self { canon_blocks: Blocks::new()?, orphan_blocks: Default::default(), memory_pool: MemoryPool::new() })
+        Self::with_memory_pool(MemoryPool::new())
+    }
+
+    /// Initializes a new instance of the ledger with the given memory pool.
+    pub fn with_memory_pool(memory_pool: MemoryPool<N>) -> Result<Self> {
+        Ok(Self { canon_blocks: Blocks::new()?, orphan_blocks: Default::default(), memory_pool })
     }
 
     /// Returns the latest block height.
@@ -92,33 +100,91 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Returns the block locators of the canon chain.
+    pub fn latest_block_locators(&self) -> Result<BlockLocators<N>> {
+        self.canon_blocks.get_block_locators()
+    }
+
+    /// Returns the block height of the most recent block in the given block locators that is on the canon chain.
+    pub fn find_common_ancestor(&self, block_locators: &BlockLocators<N>) -> Result<u32> {
+        self.canon_blocks.find_common_ancestor(block_locators)
+    }
+
+    /// Returns the canon block headers from the given start height up to and including the end height.
+    pub fn get_block_headers(&self, start_height: u32, end_height: u32) -> Result<Vec<BlockHeader<N>>> {
+        self.canon_blocks.get_block_headers(start_height, end_height)
+    }
+
+    /// Ensures the given block headers form a valid chain extending the canon block with the given hash,
+    /// returning the block hash of each header. This allows a node to sync block headers first,
+    /// and only fetch the transactions of blocks whose headers are valid.
+    pub fn check_block_headers(
+        &self,
+        previous_block_hash: N::BlockHash,
+        block_headers: &[BlockHeader<N>],
+    ) -> Result<Vec<N::BlockHash>> {
+        self.canon_blocks.check_block_headers(previous_block_hash, block_headers)
+    }
+
+    /// Returns `true` if the given block hash exists in the orphan blocks.
+    pub fn contains_orphan_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+        self.orphan_blocks.values().flatten().any(|block| block.hash() == *block_hash)
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Attempt to insert the block into canon.
         self.canon_blocks.add_next(block)?;
 
+        // Remove the block from the orphan blocks, if it exists.
+        self.remove_orphan_block(block);
+
+        // Connect any orphan blocks that now extend the canon chain.
+        // Note: The given block remains canon, even if an invalid orphan branch is discarded.
+        self.update_canon_chain();
+
         Ok(())
     }
 
     /// Adds the given orphan block, if it is well-formed and does not already exist.
+    /// If the block completes a branch that is heavier than the canon chain, the ledger
+    /// reverts to the fork point and switches the canon chain to that branch.
     pub fn add_orphan_block(&mut self, block: &Block<N>) -> Result<()> {
         // Ensure the block does not exist in canon.
         if self.canon_blocks.contains_block_hash(&block.hash()) {
             return Err(anyhow!("Orphan block already exists in canon chain"));
         }
 
-        // Insert the block into the orphan blocks.
-        self.orphan_blocks.insert(block.height(), block.clone());
+        // Ensure the block does not exist in the orphan blocks.
+        if self.contains_orphan_block_hash(&block.hash()) {
+            return Err(anyhow!("Orphan block already exists in the orphan blocks"));
+        }
+
+        // Ensure the block is within the maximum fork depth of the canon chain.
+        if block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) <= self.latest_block_height() {
+            return Err(anyhow!("Orphan block is below the maximum fork depth"));
+        }
+
+        // Ensure the block itself is valid.
+        if !block.is_valid() {
+            return Err(anyhow!("The given orphan block is invalid"));
+        }
+
+        // Insert the block into the orphan blocks, keyed by its previous block hash.
+        self.orphan_blocks.entry(block.previous_block_hash()).or_default().push(block.clone());
+
+        // Switch to the heaviest branch, if it is heavier than the canon chain.
+        self.update_canon_chain();
 
         Ok(())
     }
 
     /// Adds the given unconfirmed transaction to the memory pool.
     pub fn add_unconfirmed_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
-        // Ensure the transaction contains ledger roots from the canon chain.
-        if !self.canon_blocks.contains_ledger_root(&transaction.ledger_root()) {
-            return Err(anyhow!("Transaction references a non-existent ledger root"));
+        // Ensure the transaction contains a recent ledger root from the canon chain.
+        if !self.recent_ledger_roots().contains(&transaction.ledger_root()) {
+            return Err(anyhow!("Transaction references a non-existent or expired ledger root"));
         }
 
         // Ensure the transaction does not contain serial numbers already in the canon chain.
@@ -159,8 +225,8 @@ impl<N: Network> Ledger<N> {
             std::cmp::max(OffsetDateTime::now_utc().unix_timestamp(), self.latest_block_timestamp()?.saturating_add(1));
 
         // Compute the block difficulty target.
-        let difficulty_target =
-            Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block_timestamp, block_height);
+        let recent_blocks = self.canon_blocks.get_difficulty_samples(self.latest_block_height())?;
+        let difficulty_target = Blocks::<N>::compute_difficulty_target(&recent_blocks, block_timestamp, block_height);
 
         // Compute the cumulative weight.
         let cumulative_weight = self.latest_cumulative_weight()?.saturating_add((u64::MAX / difficulty_target) as u128);
@@ -169,7 +235,9 @@ impl<N: Network> Ledger<N> {
         let amount = Block::<N>::block_reward(block_height);
         let (coinbase_transaction, coinbase_record) =
             Transaction::<N>::new_coinbase(recipient, amount, is_public, rng)?;
-        let transactions = Transactions::from(&[vec![coinbase_transaction], self.memory_pool.transactions()].concat())?;
+        let unconfirmed_transactions = self.memory_pool.select_for_block(N::ALEO_MAXIMUM_BLOCK_SIZE_IN_BYTES);
+        let transactions =
+            Transactions::from(&[vec![coinbase_transaction], unconfirmed_transactions.clone()].concat())?;
 
         // Retrieve the current ledger root.
         let previous_ledger_root = self.canon_blocks.latest_ledger_root();
@@ -192,12 +260,151 @@ impl<N: Network> Ledger<N> {
         // Attempt to add the block to the canon chain.
         self.add_next_block(&block)?;
 
-        // On success, clear the memory pool of its transactions.
-        self.memory_pool.clear_all_transactions();
+        // On success, clear the memory pool of the included transactions.
+        self.memory_pool.remove_transactions(&unconfirmed_transactions);
 
         Ok(coinbase_record)
     }
 
+    /// Switches the canon chain to the heaviest orphan branch, if it is heavier than the canon chain.
+    /// If a branch contains an invalid block, the block is discarded and the next heaviest branch is tried.
+    /// Note: Errors from orphan branches are logged, as they do not affect the blocks already in canon.
+    fn update_canon_chain(&mut self) {
+        loop {
+            let (fork_height, branch) = match self.find_heaviest_branch() {
+                Ok(Some(heaviest_branch)) => heaviest_branch,
+                Ok(None) => break,
+                Err(error) => {
+                    eprintln!("Failed to find the heaviest orphan branch: {}", error);
+                    break;
+                }
+            };
+            if let Err(error) = self.reorganize(fork_height, &branch) {
+                eprintln!("Discarded an invalid orphan branch: {}", error);
+            }
+        }
+
+        // Discard orphan blocks that can no longer be reorganized into the canon chain.
+        let latest_block_height = self.latest_block_height();
+        self.orphan_blocks.retain(|_, blocks| {
+            blocks.retain(|block| block.height().saturating_add(N::ALEO_MAXIMUM_FORK_DEPTH) > latest_block_height);
+            !blocks.is_empty()
+        });
+
+        // Discard unconfirmed transactions whose ledger root has fallen out of the window.
+        let recent_ledger_roots = self.recent_ledger_roots();
+        self.memory_pool.remove_expired_transactions(&recent_ledger_roots);
+    }
+
+    /// Returns the orphan branch with the heaviest cumulative weight, if it is heavier than
+    /// the canon chain, along with the canon block height it forks from.
+    fn find_heaviest_branch(&self) -> Result<Option<(u32, Vec<Block<N>>)>> {
+        let latest_block_height = self.latest_block_height();
+        let mut heaviest_cumulative_weight = self.latest_cumulative_weight()?;
+        let mut heaviest_tip = None;
+
+        // Track the visited orphan blocks by their block hash, to reconstruct the heaviest branch.
+        let mut visited_blocks = HashMap::new();
+
+        for (previous_hash, blocks) in &self.orphan_blocks {
+            // Only consider branches that fork from the canon chain within the maximum fork depth.
+            let fork_height = match self.canon_blocks.get_block_height(previous_hash) {
+                Ok(fork_height) if latest_block_height - fork_height <= N::ALEO_MAXIMUM_FORK_DEPTH => fork_height,
+                _ => continue,
+            };
+
+            // Visit every orphan block extending from the fork point once, tracking the heaviest tip.
+            let mut pending_blocks = blocks.iter().collect::<Vec<_>>();
+            while let Some(block) = pending_blocks.pop() {
+                if block.cumulative_weight() > heaviest_cumulative_weight {
+                    heaviest_cumulative_weight = block.cumulative_weight();
+                    heaviest_tip = Some((fork_height, block));
+                }
+                visited_blocks.insert(block.hash(), block);
+                pending_blocks.extend(self.orphan_blocks.get(&block.hash()).into_iter().flatten());
+            }
+        }
+
+        // Reconstruct the heaviest branch by walking back from its tip to the fork point.
+        Ok(heaviest_tip.map(|(fork_height, tip)| {
+            let mut branch = vec![tip.clone()];
+            while let Some(parent) = visited_blocks.get(&branch[branch.len() - 1].previous_block_hash()) {
+                branch.push((*parent).clone());
+            }
+            branch.reverse();
+            (fork_height, branch)
+        }))
+    }
+
+    /// Reverts the canon chain to the given fork height, and extends it with the given branch.
+    fn reorganize(&mut self, fork_height: u32, branch: &[Block<N>]) -> Result<()> {
+        // Apply the branch to a copy of the canon chain, so a failure leaves the ledger unchanged.
+        let mut canon_blocks = self.canon_blocks.clone();
+        let reverted_blocks = match canon_blocks.revert_to_height(fork_height) {
+            Ok(reverted_blocks) => reverted_blocks,
+            Err(error) => {
+                // Discard the branch, so it is not selected again.
+                self.remove_orphan_block(&branch[0]);
+                return Err(error);
+            }
+        };
+        for block in branch {
+            if let Err(error) = canon_blocks.add_next(block) {
+                // Discard the invalid block, so its branch is not selected again.
+                self.remove_orphan_block(block);
+                return Err(error);
+            }
+        }
+        self.canon_blocks = canon_blocks;
+
+        // Track the reverted blocks as orphans, so the ledger may switch back to them.
+        for block in branch {
+            self.remove_orphan_block(block);
+        }
+        for block in &reverted_blocks {
+            self.orphan_blocks.entry(block.previous_block_hash()).or_default().push(block.clone());
+        }
+
+        // Return the transactions of the reverted blocks to the memory pool.
+        for transaction in reverted_blocks.iter().flat_map(|block| block.transactions().iter()) {
+            // Note: Coinbase transactions and transactions that conflict with the new canon chain are rejected.
+            if !self.canon_blocks.contains_transaction(transaction) {
+                let _ = self.add_unconfirmed_transaction(transaction);
+            }
+        }
+
+        // Evict the unconfirmed transactions that are no longer valid on the new canon chain.
+        let invalid_transactions = self
+            .memory_pool
+            .transactions()
+            .into_iter()
+            .filter(|transaction| {
+                self.canon_blocks.contains_transaction(transaction)
+                    || transaction.serial_numbers().any(|serial| self.canon_blocks.contains_serial_number(serial))
+                    || transaction.commitments().any(|commitment| self.canon_blocks.contains_commitment(commitment))
+            })
+            .collect::<Vec<_>>();
+        self.memory_pool.remove_transactions(&invalid_transactions);
+
+        Ok(())
+    }
+
+    /// Returns the ledger roots that unconfirmed transactions may reference.
+    fn recent_ledger_roots(&self) -> HashSet<N::LedgerRoot> {
+        self.canon_blocks.get_recent_ledger_roots(self.memory_pool.ledger_root_window())
+    }
+
+    /// Removes the given block from the orphan blocks, if it exists.
+    fn remove_orphan_block(&mut self, block: &Block<N>) {
+        let previous_block_hash = block.previous_block_hash();
+        if let Some(blocks) = self.orphan_blocks.get_mut(&previous_block_hash) {
+            blocks.retain(|orphan| orphan.hash() != block.hash());
+            if blocks.is_empty() {
+                self.orphan_blocks.remove(&previous_block_hash);
+            }
+        }
+    }
+
     /// Returns the ledger tree.
     pub fn to_ledger_tree(&self) -> &LedgerTree<N> {
         self.canon_blocks.to_ledger_tree()
@@ -247,4 +454,81 @@ mod tests {
             assert_eq!(1, ledger.latest_block_height());
         }
     }
+
+    #[test]
+    fn test_reorganize_to_heavier_branch() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut competing_ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine one block on the ledger, and a longer competing branch from genesis.
+        ledger.mine_next_block(recipient.address(), true, &terminator, rng, 0).unwrap();
+        let genesis_ledger_root = competing_ledger.latest_ledger_root();
+        competing_ledger.mine_next_block(recipient.address(), true, &terminator, rng, 0).unwrap();
+        competing_ledger.mine_next_block(recipient.address(), true, &terminator, rng, 0).unwrap();
+        let reverted_block = ledger.latest_block().unwrap();
+
+        // Add the competing blocks out of order, to ensure branches are tracked by previous block hash.
+        let block_2 = competing_ledger.latest_block().unwrap();
+        ledger.add_orphan_block(&block_2).unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert!(ledger.contains_orphan_block_hash(&block_2.hash()));
+
+        let block_1 = competing_ledger.canon_blocks.get_block(1).unwrap();
+        ledger.add_orphan_block(&block_1).unwrap();
+
+        // The ledger switches to the heavier branch, and tracks the reverted block as an orphan.
+        assert_eq!(2, ledger.latest_block_height());
+        assert_eq!(competing_ledger.latest_block_hash(), ledger.latest_block_hash());
+        assert_eq!(competing_ledger.latest_ledger_root(), ledger.latest_ledger_root());
+        assert!(ledger.contains_block_hash(&block_1.hash()));
+        assert!(!ledger.contains_block_hash(&reverted_block.hash()));
+        assert!(ledger.contains_orphan_block_hash(&reverted_block.hash()));
+        for commitment in reverted_block.commitments() {
+            assert!(!ledger.canon_blocks.contains_commitment(commitment));
+        }
+
+        // Reverting the canon chain restores the previous ledger state.
+        let reverted_blocks = ledger.canon_blocks.revert_to_height(0).unwrap();
+        assert_eq!(vec![block_1, block_2], reverted_blocks);
+        assert_eq!(0, ledger.latest_block_height());
+        assert_eq!(genesis_ledger_root, ledger.latest_ledger_root());
+    }
+
+    #[test]
+    fn test_header_first_sync() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let peer_ledger = {
+            let mut peer_ledger = Ledger::<Testnet2>::new().unwrap();
+            let recipient = Account::<Testnet2>::new(rng);
+            for _ in 0..3 {
+                peer_ledger.mine_next_block(recipient.address(), true, &terminator, rng, 0).unwrap();
+            }
+            peer_ledger
+        };
+
+        // The peer finds the common ancestor from the block locators of the ledger.
+        let block_locators = ledger.latest_block_locators().unwrap();
+        assert_eq!(ledger.latest_block_hash(), block_locators.genesis_block_hash());
+        let common_ancestor = peer_ledger.find_common_ancestor(&block_locators).unwrap();
+        assert_eq!(0, common_ancestor);
+
+        // The ledger validates the block headers before fetching the blocks.
+        let block_headers =
+            peer_ledger.get_block_headers(common_ancestor + 1, peer_ledger.latest_block_height()).unwrap();
+        let block_hashes = ledger.check_block_headers(ledger.latest_block_hash(), &block_headers).unwrap();
+        assert_eq!(peer_ledger.latest_block_hash(), block_hashes[2]);
+        assert!(ledger.check_block_headers(ledger.latest_block_hash(), &block_headers[1..]).is_err());
+
+        for height in 1..=3 {
+            ledger.add_next_block(&peer_ledger.canon_blocks.get_block(height).unwrap()).unwrap();
+        }
+        assert_eq!(peer_ledger.latest_block_locators().unwrap(), ledger.latest_block_locators().unwrap());
+    }
 }
@@ -16,7 +16,7 @@
 
 use crate::prelude::*;
 use snarkvm_algorithms::{
-    merkle_tree::{MerklePath, MerkleTree},
+    merkle_tree::{MerklePath, MerkleTree, MerkleTreeDigest},
     prelude::*,
 };
 use snarkvm_utilities::has_duplicates;
@@ -112,6 +112,21 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
         Ok((start_index, end_index))
     }
 
+    /// Removes the last `num_block_hashes` block hashes from the tree, returning the new number of block hashes.
+    fn remove_last(&mut self, num_block_hashes: u32) -> Result<u32> {
+        let current_index = self
+            .current_index
+            .checked_sub(num_block_hashes)
+            .ok_or_else(|| anyhow!("The ledger tree contains fewer than {} block hashes", num_block_hashes))?;
+
+        // Rebuild the tree from the retained block hashes.
+        self.tree = Arc::new(self.tree.rebuild::<N::BlockHash>(current_index as usize, &[])?);
+        self.block_hashes.retain(|_, index| *index < current_index);
+        self.current_index = current_index;
+
+        Ok(current_index)
+    }
+
     /// Returns `true` if the given block hash exists.
     fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
         self.block_hashes.contains_key(block_hash)
@@ -136,6 +151,13 @@ impl<N: Network> LedgerTreeScheme<N> for LedgerTree<N> {
     }
 }
 
+impl<N: Network> LedgerTree<N> {
+    /// Returns the leaf hashes of the block hashes in the tree, in order of their index.
+    pub(crate) fn hashed_leaves(&self) -> &[MerkleTreeDigest<N::LedgerRootParameters>] {
+        &self.tree.hashed_leaves()[..self.current_index as usize]
+    }
+}
+
 impl<N: Network> Default for LedgerTree<N> {
     fn default() -> Self {
         Self::new().unwrap()
@@ -0,0 +1,466 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::{
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    prelude::*,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+type LedgerTreeDigest<N> = MerkleTreeDigest<<N as Network>::LedgerRootParameters>;
+
+/// The depth of the ledger tree.
+fn ledger_tree_depth<N: Network>() -> usize {
+    <N::LedgerRootParameters as MerkleParameters>::DEPTH
+}
+
+/// Returns the roots of the empty subtrees of the ledger tree, indexed by their depth.
+fn empty_roots<N: Network>(depth: usize) -> Result<Vec<LedgerTreeDigest<N>>> {
+    let parameters = N::ledger_root_parameters();
+
+    let mut empty_roots = Vec::with_capacity(depth + 1);
+    empty_roots.push(parameters.hash_empty()?);
+    for i in 0..depth {
+        empty_roots.push(parameters.hash_inner_node(&empty_roots[i], &empty_roots[i])?);
+    }
+    Ok(empty_roots)
+}
+
+/// Returns the depth of the smallest subtree that `LedgerTree` builds to hold the given number of leaves.
+fn subtree_depth(num_leaves: u32) -> usize {
+    (num_leaves as u64).next_power_of_two().trailing_zeros() as usize
+}
+
+///
+/// The frontier of a ledger tree, which is the root of each complete subtree on the right edge of the tree.
+///
+/// A frontier is a compact snapshot of a ledger tree, from which the ledger root is computed,
+/// and to which block hashes may be added, without holding the full set of block hashes.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerTreeFrontier<N: Network> {
+    /// The number of block hashes in the tree.
+    num_leaves: u32,
+    /// The root of the complete subtree of each depth, which is `Some` if the bit of `num_leaves` at that depth is set.
+    ommers: Vec<Option<LedgerTreeDigest<N>>>,
+}
+
+impl<N: Network> LedgerTreeFrontier<N> {
+    /// Initializes the frontier of an empty ledger tree.
+    pub fn new() -> Self {
+        Self { num_leaves: 0, ommers: vec![] }
+    }
+
+    /// Returns the number of block hashes in the tree.
+    pub fn num_leaves(&self) -> u32 {
+        self.num_leaves
+    }
+
+    /// Adds the given block hash to the tree, returning its index in the tree.
+    pub fn add(&mut self, block_hash: &N::BlockHash) -> Result<u32> {
+        self.add_leaf_hash(N::ledger_root_parameters().hash_leaf(block_hash)?)
+    }
+
+    ///
+    /// Adds the given block hash to the tree, returning a witness of its inclusion in the tree.
+    /// The witness must be given every block hash subsequently added to the tree, to remain up to date.
+    ///
+    pub fn add_witnessed(&mut self, block_hash: &N::BlockHash) -> Result<LedgerWitness<N>> {
+        let witness = LedgerWitness::<N> {
+            block_hash: *block_hash,
+            left: self.clone(),
+            filled: vec![],
+            cursor: LedgerTreeFrontier::new(),
+        };
+        self.add(block_hash)?;
+        Ok(witness)
+    }
+
+    /// Returns the ledger root.
+    pub fn root(&self) -> Result<N::LedgerRoot> {
+        let parameters = N::ledger_root_parameters();
+        let empty_hash = parameters.hash_empty()?;
+
+        // Compute the root of the smallest subtree holding all leaves, and pad it to the depth of the ledger tree.
+        let depth = subtree_depth(self.num_leaves);
+        let mut root = self.subtree_root(depth)?;
+        for _ in depth..ledger_tree_depth::<N>() {
+            root = parameters.hash_inner_node(&root, &empty_hash)?;
+        }
+        Ok(root.into())
+    }
+
+    /// Adds the given leaf hash to the tree, returning its index in the tree.
+    fn add_leaf_hash(&mut self, leaf_hash: LedgerTreeDigest<N>) -> Result<u32> {
+        let index = self.num_leaves;
+        let num_leaves =
+            index.checked_add(1).ok_or_else(|| anyhow!("The index exceeds the maximum number of allowed block hashes."))?;
+        if subtree_depth(num_leaves) > ledger_tree_depth::<N>() {
+            return Err(anyhow!("The ledger tree will reach its maximum size."));
+        }
+
+        // Merge the new leaf with each complete subtree on its left, from the bottom up.
+        let parameters = N::ledger_root_parameters();
+        let mut node = leaf_hash;
+        let mut depth = 0;
+        while (index >> depth) & 1 == 1 {
+            let ommer = self.ommers[depth].take().ok_or_else(|| anyhow!("Missing subtree in the ledger frontier"))?;
+            node = parameters.hash_inner_node(&ommer, &node)?;
+            depth += 1;
+        }
+
+        if self.ommers.len() <= depth {
+            self.ommers.resize(depth + 1, None);
+        }
+        self.ommers[depth] = Some(node);
+        self.num_leaves = num_leaves;
+
+        Ok(index)
+    }
+
+    /// Returns the root of the subtree of the given depth, whose leaves are the leaves of this tree
+    /// followed by empty leaves. The number of leaves must not exceed the capacity of the subtree.
+    fn subtree_root(&self, depth: usize) -> Result<LedgerTreeDigest<N>> {
+        if (self.num_leaves as u64) > (1u64 << depth) {
+            return Err(anyhow!("The ledger frontier does not fit in a subtree of depth {}", depth));
+        }
+
+        let ommer = |depth: usize| self.ommers.get(depth).copied().flatten();
+
+        // If the subtree is full, its root is the ommer at its depth.
+        if (self.num_leaves as u64) == (1u64 << depth) {
+            return ommer(depth).ok_or_else(|| anyhow!("Missing subtree in the ledger frontier"));
+        }
+
+        let parameters = N::ledger_root_parameters();
+        let empty_roots = empty_roots::<N>(depth)?;
+
+        let mut node = None;
+        for (i, empty_root) in empty_roots.iter().enumerate().take(depth) {
+            node = match (ommer(i), node) {
+                (Some(left), Some(right)) => Some(parameters.hash_inner_node(&left, &right)?),
+                (Some(left), None) => Some(parameters.hash_inner_node(&left, empty_root)?),
+                (None, Some(left)) => Some(parameters.hash_inner_node(&left, empty_root)?),
+                (None, None) => None,
+            };
+        }
+        Ok(node.unwrap_or(empty_roots[depth]))
+    }
+}
+
+impl<N: Network> Default for LedgerTreeFrontier<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FromBytes for LedgerTreeFrontier<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_leaves: u32 = FromBytes::read_le(&mut reader)?;
+
+        let mut ommers = Vec::with_capacity(32);
+        for depth in 0..(32 - num_leaves.leading_zeros()) {
+            match (num_leaves >> depth) & 1 == 1 {
+                true => ommers.push(Some(FromBytes::read_le(&mut reader)?)),
+                false => ommers.push(None),
+            }
+        }
+
+        Ok(Self { num_leaves, ommers })
+    }
+}
+
+impl<N: Network> ToBytes for LedgerTreeFrontier<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.num_leaves.write_le(&mut writer)?;
+        for ommer in self.ommers.iter().flatten() {
+            ommer.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// An incremental witness of a block hash in the ledger tree, in the style of Zcash's incremental Merkle witnesses.
+///
+/// A witness holds the authentication path of its block hash, and is updated as block hashes are added
+/// to the tree, so that a ledger proof can be built without holding the full set of block hashes.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerWitness<N: Network> {
+    /// The witnessed block hash.
+    block_hash: N::BlockHash,
+    /// The frontier of the tree before the witnessed block hash was added, which holds the left siblings of the path.
+    left: LedgerTreeFrontier<N>,
+    /// The roots of the completed right siblings of the path, in order of increasing depth.
+    filled: Vec<LedgerTreeDigest<N>>,
+    /// The frontier of the right sibling of the path that is currently being filled.
+    cursor: LedgerTreeFrontier<N>,
+}
+
+impl<N: Network> LedgerWitness<N> {
+    /// Returns the witnessed block hash.
+    pub fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the index of the witnessed block hash in the tree.
+    pub fn leaf_index(&self) -> u32 {
+        self.left.num_leaves()
+    }
+
+    /// Returns the number of block hashes in the tree.
+    pub fn num_leaves(&self) -> u32 {
+        let num_filled = self.right_sibling_depths().take(self.filled.len()).map(|depth| 1u32 << depth).sum::<u32>();
+        self.leaf_index() + 1 + num_filled + self.cursor.num_leaves()
+    }
+
+    /// Updates the witness with the given block hash, which is the next block hash added to the tree.
+    pub fn add(&mut self, block_hash: &N::BlockHash) -> Result<()> {
+        self.add_leaf_hash(N::ledger_root_parameters().hash_leaf(block_hash)?)
+    }
+
+    /// Returns the ledger root.
+    pub fn root(&self) -> Result<N::LedgerRoot> {
+        let parameters = N::ledger_root_parameters();
+        let path = self.to_ledger_inclusion_proof()?;
+
+        let mut node = parameters.hash_leaf(&self.block_hash)?;
+        for (depth, sibling) in path.path.iter().enumerate() {
+            node = match (self.leaf_index() >> depth) & 1 == 1 {
+                true => parameters.hash_inner_node(sibling, &node)?,
+                false => parameters.hash_inner_node(&node, sibling)?,
+            };
+        }
+        Ok(node.into())
+    }
+
+    /// Returns the Merkle path of the witnessed block hash in the tree.
+    pub fn to_ledger_inclusion_proof(&self) -> Result<MerklePath<N::LedgerRootParameters>> {
+        let parameters = N::ledger_root_parameters();
+        let leaf_index = self.leaf_index();
+
+        // Compute the siblings within the smallest subtree holding all leaves.
+        let depth = subtree_depth(self.num_leaves());
+        let empty_roots = empty_roots::<N>(depth)?;
+
+        let mut path = Vec::with_capacity(ledger_tree_depth::<N>());
+        let mut num_right_siblings = 0;
+        for (i, empty_root) in empty_roots.iter().enumerate().take(depth) {
+            match (leaf_index >> i) & 1 == 1 {
+                true => path.push(self.left.ommers[i].ok_or_else(|| anyhow!("Missing left sibling in the witness"))?),
+                false => {
+                    path.push(match num_right_siblings.cmp(&self.filled.len()) {
+                        std::cmp::Ordering::Less => self.filled[num_right_siblings],
+                        std::cmp::Ordering::Equal => self.cursor.subtree_root(i)?,
+                        std::cmp::Ordering::Greater => *empty_root,
+                    });
+                    num_right_siblings += 1;
+                }
+            }
+        }
+
+        // The siblings above the subtree are empty, as in `LedgerTree`.
+        path.resize(ledger_tree_depth::<N>(), parameters.hash_empty()?);
+
+        Ok(MerklePath::from(Arc::new(parameters.clone()), path, leaf_index as u64)?)
+    }
+
+    /// Returns a ledger proof of the given record proof, whose block hash is the witnessed block hash.
+    pub fn to_ledger_proof(&self, record_proof: RecordProof<N>) -> Result<LedgerProof<N>> {
+        LedgerProof::new(self.root()?, self.to_ledger_inclusion_proof()?, record_proof)
+    }
+
+    /// Adds the given leaf hash to the tree.
+    fn add_leaf_hash(&mut self, leaf_hash: LedgerTreeDigest<N>) -> Result<()> {
+        // Find the depth of the right sibling that is currently being filled.
+        let depth = self
+            .right_sibling_depths()
+            .nth(self.filled.len())
+            .ok_or_else(|| anyhow!("The ledger tree will reach its maximum size."))?;
+
+        self.cursor.add_leaf_hash(leaf_hash)?;
+
+        // If the right sibling is complete, move on to the next right sibling.
+        if (self.cursor.num_leaves() as u64) == (1u64 << depth) {
+            self.filled.push(self.cursor.subtree_root(depth)?);
+            self.cursor = LedgerTreeFrontier::new();
+        }
+        Ok(())
+    }
+
+    /// Returns the depths at which the path has a right sibling, in increasing order.
+    fn right_sibling_depths(&self) -> impl Iterator<Item = usize> {
+        let leaf_index = self.leaf_index();
+        (0..ledger_tree_depth::<N>()).filter(move |depth| ((leaf_index as u64) >> depth) & 1 == 0)
+    }
+}
+
+impl<N: Network> FromBytes for LedgerWitness<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        let left = FromBytes::read_le(&mut reader)?;
+
+        let num_filled: u8 = FromBytes::read_le(&mut reader)?;
+        let mut filled = Vec::with_capacity(num_filled as usize);
+        for _ in 0..num_filled {
+            filled.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let cursor = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { block_hash, left, filled, cursor })
+    }
+}
+
+impl<N: Network> ToBytes for LedgerWitness<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.block_hash.write_le(&mut writer)?;
+        self.left.write_le(&mut writer)?;
+        (self.filled.len() as u8).write_le(&mut writer)?;
+        self.filled.write_le(&mut writer)?;
+        self.cursor.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> LedgerTree<N> {
+    /// Returns the frontier of the ledger tree.
+    pub fn to_frontier(&self) -> Result<LedgerTreeFrontier<N>> {
+        let mut frontier = LedgerTreeFrontier::new();
+        for leaf_hash in self.hashed_leaves() {
+            frontier.add_leaf_hash(*leaf_hash)?;
+        }
+        Ok(frontier)
+    }
+
+    /// Returns an incremental witness of the given block hash in the ledger tree.
+    pub fn to_witness(&self, block_hash: &N::BlockHash) -> Result<LedgerWitness<N>> {
+        let index = match self.get_block_hash_index(block_hash) {
+            Some(index) => *index as usize,
+            None => return Err(MerkleError::MissingLeaf(format!("{}", block_hash)).into()),
+        };
+
+        let hashed_leaves = self.hashed_leaves();
+
+        let mut frontier = LedgerTreeFrontier::new();
+        for leaf_hash in &hashed_leaves[..index] {
+            frontier.add_leaf_hash(*leaf_hash)?;
+        }
+
+        let mut witness = frontier.add_witnessed(block_hash)?;
+        for leaf_hash in &hashed_leaves[index + 1..] {
+            witness.add_leaf_hash(*leaf_hash)?;
+        }
+        Ok(witness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_utilities::{to_bytes_le, Uniform};
+
+    use rand::thread_rng;
+
+    fn sample_block_hashes(num_block_hashes: usize) -> Vec<<Testnet2 as Network>::BlockHash> {
+        let rng = &mut thread_rng();
+        (0..num_block_hashes).map(|_| Uniform::rand(rng)).collect()
+    }
+
+    #[test]
+    fn test_frontier_root() {
+        let mut ledger_tree = LedgerTree::<Testnet2>::new().unwrap();
+        let mut frontier = LedgerTreeFrontier::<Testnet2>::new();
+        assert_eq!(ledger_tree.root(), frontier.root().unwrap());
+
+        for block_hash in sample_block_hashes(33) {
+            assert_eq!(ledger_tree.add(&block_hash).unwrap(), frontier.add(&block_hash).unwrap());
+            assert_eq!(ledger_tree.root(), frontier.root().unwrap());
+            assert_eq!(ledger_tree.to_frontier().unwrap(), frontier);
+        }
+    }
+
+    #[test]
+    fn test_witness_updates() {
+        let block_hashes = sample_block_hashes(40);
+
+        let mut ledger_tree = LedgerTree::<Testnet2>::new().unwrap();
+        let mut frontier = LedgerTreeFrontier::<Testnet2>::new();
+        let mut witnesses = Vec::<LedgerWitness<Testnet2>>::new();
+
+        for block_hash in &block_hashes {
+            for witness in witnesses.iter_mut() {
+                witness.add(block_hash).unwrap();
+            }
+            witnesses.push(frontier.add_witnessed(block_hash).unwrap());
+            ledger_tree.add(block_hash).unwrap();
+
+            // Ensure every witness matches the path from the full tree.
+            for witness in &witnesses {
+                let expected_path = ledger_tree.to_ledger_inclusion_proof(&witness.block_hash()).unwrap();
+                let candidate_path = witness.to_ledger_inclusion_proof().unwrap();
+                assert_eq!(expected_path, candidate_path);
+                assert_eq!(ledger_tree.root(), witness.root().unwrap());
+                assert!(candidate_path.verify(&ledger_tree.root(), &witness.block_hash()).unwrap());
+            }
+        }
+
+        // Ensure the witnesses match the witnesses derived from the full tree.
+        for witness in &witnesses {
+            assert_eq!(block_hashes.len() as u32, witness.num_leaves());
+            assert_eq!(ledger_tree.to_witness(&witness.block_hash()).unwrap(), *witness);
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut frontier = LedgerTreeFrontier::<Testnet2>::new();
+        let mut witness = None;
+        for (i, block_hash) in sample_block_hashes(21).iter().enumerate() {
+            match (i, witness.as_mut()) {
+                (5, _) => witness = Some(frontier.add_witnessed(block_hash).unwrap()),
+                (_, Some(witness)) => {
+                    witness.add(block_hash).unwrap();
+                    frontier.add(block_hash).unwrap();
+                }
+                (_, None) => {
+                    frontier.add(block_hash).unwrap();
+                }
+            }
+        }
+        let witness = witness.unwrap();
+
+        let frontier_bytes = to_bytes_le![frontier].unwrap();
+        assert_eq!(frontier, LedgerTreeFrontier::read_le(&frontier_bytes[..]).unwrap());
+
+        let witness_bytes = to_bytes_le![witness].unwrap();
+        let candidate_witness = LedgerWitness::<Testnet2>::read_le(&witness_bytes[..]).unwrap();
+        assert_eq!(witness, candidate_witness);
+        assert_eq!(frontier.root().unwrap(), candidate_witness.root().unwrap());
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// A light client stores only the block headers of the canon chain, and verifies
+/// that records exist on the ledger from ledger proofs, without the block transactions.
+#[derive(Clone, Debug)]
+pub struct LightClient<N: Network> {
+    /// The ledger tree of the canon block hashes.
+    ledger_tree: LedgerTree<N>,
+    /// The chain of block hashes.
+    block_hashes: Vec<N::BlockHash>,
+    /// The chain of block headers.
+    headers: Vec<BlockHeader<N>>,
+    /// The block height of each ledger root, which is the root after the block at that height was added.
+    ledger_roots: HashMap<N::LedgerRoot, u32>,
+}
+
+impl<N: Network> LightClient<N> {
+    /// Initializes a new instance of a light client with the genesis block header.
+    pub fn new() -> Result<Self> {
+        let genesis_block = N::genesis_block();
+
+        let mut ledger_tree = LedgerTree::<N>::new()?;
+        ledger_tree.add(&genesis_block.hash())?;
+
+        let mut ledger_roots = HashMap::new();
+        ledger_roots.insert(ledger_tree.root(), genesis_block.height());
+
+        Ok(Self {
+            ledger_tree,
+            block_hashes: vec![genesis_block.hash()],
+            headers: vec![genesis_block.header().clone()],
+            ledger_roots,
+        })
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_block_height(&self) -> u32 {
+        (self.headers.len() - 1) as u32
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_block_hash(&self) -> N::BlockHash {
+        self.block_hashes[self.block_hashes.len() - 1]
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_block_header(&self) -> &BlockHeader<N> {
+        &self.headers[self.headers.len() - 1]
+    }
+
+    /// Returns the latest ledger root.
+    pub fn latest_ledger_root(&self) -> N::LedgerRoot {
+        self.ledger_tree.root()
+    }
+
+    /// Returns the block hash for the given block height.
+    pub fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        match self.block_hashes.get(height as usize) {
+            Some(block_hash) => Ok(*block_hash),
+            None => Err(anyhow!("Block {} does not exist in the light client", height)),
+        }
+    }
+
+    /// Returns the block header for the given block height.
+    pub fn get_block_header(&self, height: u32) -> Result<&BlockHeader<N>> {
+        match self.headers.get(height as usize) {
+            Some(header) => Ok(header),
+            None => Err(anyhow!("Block {} does not exist in the light client", height)),
+        }
+    }
+
+    /// Returns the block height for the given block hash.
+    pub fn get_block_height(&self, block_hash: &N::BlockHash) -> Result<u32> {
+        match self.ledger_tree.get_block_hash_index(block_hash) {
+            Some(height) => Ok(*height),
+            None => Err(anyhow!("Block {} does not exist in the light client", block_hash)),
+        }
+    }
+
+    /// Returns `true` if the given block hash exists in the light client.
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+        self.ledger_tree.contains_block_hash(block_hash)
+    }
+
+    /// Returns `true` if the given ledger root exists in the light client.
+    pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
+        self.ledger_roots.contains_key(ledger_root)
+    }
+
+    /// Adds the given block header as the next block header in the chain.
+    /// This checks the proof of succinct work, difficulty target, cumulative weight,
+    /// timestamp, and previous ledger root of the block header.
+    pub fn add_next_block_header(&mut self, block_header: &BlockHeader<N>) -> Result<()> {
+        // Ensure the block header is a valid successor of the latest block header.
+        let num_recent_blocks = N::DifficultyAlgorithm::NUM_RECENT_BLOCKS;
+        let recent_blocks = self.headers[self.headers.len().saturating_sub(num_recent_blocks)..]
+            .iter()
+            .map(DifficultySample::from)
+            .collect::<Vec<_>>();
+        Blocks::<N>::check_next_block_header(self.latest_block_header(), &recent_blocks, block_header)?;
+
+        // Ensure the previous ledger root is the latest ledger root.
+        if block_header.previous_ledger_root() != self.latest_ledger_root() {
+            return Err(anyhow!("The given block header has an incorrect previous ledger root"));
+        }
+
+        // Ensure the block hash does not already exist.
+        let block_hash = Block::compute_block_hash(self.latest_block_hash(), block_header)?;
+        if self.contains_block_hash(&block_hash) {
+            return Err(anyhow!("The given block hash already exists in the light client"));
+        }
+
+        // Add the block header to the light client.
+        // Note: The block header is validated above, so only the ledger tree may fail, and it does so before mutating.
+        self.ledger_tree.add(&block_hash)?;
+        self.block_hashes.push(block_hash);
+        self.headers.push(block_header.clone());
+        self.ledger_roots.insert(self.ledger_tree.root(), block_header.height());
+
+        Ok(())
+    }
+
+    /// Adds the given block headers in order, as the next block headers in the chain.
+    /// If any block header is invalid, the block headers added from this batch are removed.
+    pub fn add_next_block_headers(&mut self, block_headers: &[BlockHeader<N>]) -> Result<()> {
+        let starting_height = self.latest_block_height();
+        for block_header in block_headers {
+            if let Err(error) = self.add_next_block_header(block_header) {
+                self.truncate_to_height(starting_height)?;
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes all block headers above the given block height.
+    /// Note: This method will not revert more than `N::ALEO_MAXIMUM_FORK_DEPTH` blocks.
+    pub fn revert_to_height(&mut self, height: u32) -> Result<()> {
+        let latest_block_height = self.latest_block_height();
+        if height > latest_block_height {
+            return Err(anyhow!("Cannot revert to a future block height ({})", height));
+        }
+
+        let num_blocks = latest_block_height - height;
+        if num_blocks > N::ALEO_MAXIMUM_FORK_DEPTH {
+            return Err(anyhow!("Cannot revert more than {} blocks", N::ALEO_MAXIMUM_FORK_DEPTH));
+        }
+
+        self.truncate_to_height(height)
+    }
+
+    /// Returns `true` if the given ledger proof shows its commitment exists in a block
+    /// of the canon chain, as verified against the block headers in the light client.
+    pub fn verify_ledger_proof(&self, ledger_proof: &LedgerProof<N>) -> Result<bool> {
+        // Ensure the ledger root is known, and contains the block hash.
+        if !self.contains_ledger_root(&ledger_proof.ledger_root()) {
+            return Ok(false);
+        }
+        let block_hash = ledger_proof.block_hash();
+        if !ledger_proof.ledger_root_inclusion_proof().verify(&ledger_proof.ledger_root(), &block_hash)? {
+            return Ok(false);
+        }
+
+        // Ensure the block hash and block header root match the stored block header.
+        let height = match self.ledger_tree.get_block_hash_index(&block_hash) {
+            Some(height) => *height,
+            None => return Ok(false),
+        };
+        let previous_block_hash = match height {
+            0 => N::genesis_block().previous_block_hash(),
+            _ => self.get_block_hash(height - 1)?,
+        };
+        let block_header = self.get_block_header(height)?;
+        if ledger_proof.previous_block_hash() != previous_block_hash
+            || ledger_proof.block_header_root() != block_header.to_header_root()?
+            || ledger_proof.transactions_root() != block_header.transactions_root()
+        {
+            return Ok(false);
+        }
+
+        // Ensure the record proof links the commitment to the block header.
+        Ok(ledger_proof
+            .block_header_inclusion_proof()
+            .verify(&ledger_proof.block_header_root(), &ledger_proof.transactions_root())?
+            && ledger_proof
+                .transactions_inclusion_proof()
+                .verify(&ledger_proof.transactions_root(), &ledger_proof.transaction_id())?
+            && ledger_proof
+                .transaction_inclusion_proof()
+                .verify(&ledger_proof.transaction_id(), &ledger_proof.transition_id())?
+            && ledger_proof
+                .transition_inclusion_proof()
+                .verify(&ledger_proof.transition_id(), &ledger_proof.commitment())?)
+    }
+
+    /// Removes all block headers above the given block height, without checking the maximum fork depth.
+    fn truncate_to_height(&mut self, height: u32) -> Result<()> {
+        let num_blocks = self.latest_block_height().saturating_sub(height);
+
+        self.ledger_tree.remove_last(num_blocks)?;
+        self.block_hashes.truncate(height as usize + 1);
+        self.headers.truncate(height as usize + 1);
+        self.ledger_roots.retain(|_, ledger_root_height| *ledger_root_height <= height);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_new() {
+        let light_client = LightClient::<Testnet2>::new().unwrap();
+        let ledger = Ledger::<Testnet2>::new().unwrap();
+
+        assert_eq!(0, light_client.latest_block_height());
+        assert_eq!(ledger.latest_block_hash(), light_client.latest_block_hash());
+        assert_eq!(ledger.latest_ledger_root(), light_client.latest_ledger_root());
+    }
+
+    #[test]
+    fn test_verify_ledger_proof() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        for _ in 0..2 {
+            ledger.mine_next_block(recipient.address(), true, &terminator, rng, 0).unwrap();
+        }
+
+        // The light client syncs the block headers.
+        let mut light_client = LightClient::<Testnet2>::new().unwrap();
+        let block_headers = ledger.get_block_headers(1, ledger.latest_block_height()).unwrap();
+        assert!(light_client.add_next_block_headers(&block_headers[1..]).is_err());
+        assert_eq!(0, light_client.latest_block_height());
+
+        // The light client removes the valid block headers of a batch that fails part way through.
+        let genesis_ledger_root = light_client.latest_ledger_root();
+        let invalid_batch = [block_headers[0].clone(), block_headers[0].clone()];
+        assert!(light_client.add_next_block_headers(&invalid_batch).is_err());
+        assert_eq!(0, light_client.latest_block_height());
+        assert_eq!(genesis_ledger_root, light_client.latest_ledger_root());
+        assert!(!light_client.contains_ledger_root(&block_headers[1].previous_ledger_root()));
+
+        light_client.add_next_block_headers(&block_headers).unwrap();
+        assert_eq!(ledger.latest_block_hash(), light_client.latest_block_hash());
+        assert_eq!(ledger.latest_ledger_root(), light_client.latest_ledger_root());
+
+        // The light client verifies the ledger proof of a record in the latest block.
+        let block = ledger.latest_block().unwrap();
+        let commitment = *block.commitments().next().unwrap();
+        let ledger_proof = ledger.to_ledger_proof(commitment).unwrap();
+        assert!(light_client.verify_ledger_proof(&ledger_proof).unwrap());
+
+        // The light client does not verify the ledger proof once the block is reverted.
+        light_client.revert_to_height(1).unwrap();
+        assert!(!light_client.verify_ledger_proof(&ledger_proof).unwrap());
+        assert!(!light_client.verify_ledger_proof(&LedgerProof::default()).unwrap());
+    }
+}
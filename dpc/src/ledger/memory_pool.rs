@@ -15,14 +15,18 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::prelude::*;
+use snarkvm_utilities::ToBytes;
 
 use anyhow::{anyhow, Result};
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
 #[derive(Clone, Debug)]
 pub struct MemoryPool<N: Network> {
-    /// The pool of unconfirmed transactions.
-    transactions: HashMap<N::TransactionID, Transaction<N>>,
+    /// The pool of unconfirmed transactions, along with their size in bytes.
+    transactions: HashMap<N::TransactionID, (Transaction<N>, usize)>,
     /// The list of unconfirmed serial numbers.
     serial_numbers: HashSet<N::SerialNumber>,
     /// The list of unconfirmed commitments.
@@ -30,16 +34,48 @@ pub struct MemoryPool<N: Network> {
     /// The set of open requests.
     #[allow(dead_code)]
     requests: HashSet<Request<N>>,
+    /// The total size of the unconfirmed transactions, in bytes.
+    size_in_bytes: usize,
+    /// The maximum number of unconfirmed transactions.
+    maximum_number_of_transactions: usize,
+    /// The maximum total size of the unconfirmed transactions, in bytes.
+    maximum_size_in_bytes: usize,
+    /// The number of most recent blocks whose ledger roots unconfirmed transactions may reference.
+    ledger_root_window: u32,
 }
 
 impl<N: Network> MemoryPool<N> {
+    /// The default maximum number of unconfirmed transactions.
+    pub const DEFAULT_MAXIMUM_NUMBER_OF_TRANSACTIONS: usize = 10_000;
+    /// The default maximum total size of the unconfirmed transactions, in bytes.
+    pub const DEFAULT_MAXIMUM_SIZE_IN_BYTES: usize = 64 * 1024 * 1024;
+    /// The default number of most recent blocks whose ledger roots unconfirmed transactions may reference.
+    pub const DEFAULT_LEDGER_ROOT_WINDOW: u32 = N::ALEO_MAXIMUM_FORK_DEPTH;
+
     /// Initializes a new instance of a memory pool.
     pub fn new() -> Self {
+        Self::with_limits(
+            Self::DEFAULT_MAXIMUM_NUMBER_OF_TRANSACTIONS,
+            Self::DEFAULT_MAXIMUM_SIZE_IN_BYTES,
+            Self::DEFAULT_LEDGER_ROOT_WINDOW,
+        )
+    }
+
+    /// Initializes a new instance of a memory pool with the given capacity and ledger root window.
+    pub fn with_limits(
+        maximum_number_of_transactions: usize,
+        maximum_size_in_bytes: usize,
+        ledger_root_window: u32,
+    ) -> Self {
         Self {
             transactions: Default::default(),
             serial_numbers: Default::default(),
             commitments: Default::default(),
             requests: Default::default(),
+            size_in_bytes: 0,
+            maximum_number_of_transactions,
+            maximum_size_in_bytes,
+            ledger_root_window,
         }
     }
 
@@ -50,10 +86,43 @@ impl<N: Network> MemoryPool<N> {
 
     /// Returns the transactions in the memory pool.
     pub fn transactions(&self) -> Vec<Transaction<N>> {
-        self.transactions.values().cloned().collect()
+        self.transactions.values().map(|(transaction, _)| transaction.clone()).collect()
+    }
+
+    /// Returns the number of transactions in the memory pool.
+    pub fn num_transactions(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns the total size of the transactions in the memory pool, in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of most recent blocks whose ledger roots unconfirmed transactions may reference.
+    pub fn ledger_root_window(&self) -> u32 {
+        self.ledger_root_window
+    }
+
+    /// Returns the transactions with the highest fee per byte that fit within `max_bytes`,
+    /// in order of decreasing priority.
+    pub fn select_for_block(&self, max_bytes: usize) -> Vec<Transaction<N>> {
+        let mut candidates = self.transactions.values().collect::<Vec<_>>();
+        candidates.sort_by(|(a, a_size), (b, b_size)| Self::compare_priority((b, *b_size), (a, *a_size)));
+
+        let mut selected_transactions = Vec::new();
+        let mut remaining_bytes = max_bytes;
+        for (transaction, size) in candidates {
+            if *size <= remaining_bytes {
+                remaining_bytes -= size;
+                selected_transactions.push(transaction.clone());
+            }
+        }
+        selected_transactions
     }
 
     /// Adds the given unconfirmed transaction to the memory pool.
+    /// If the memory pool is full, transactions with a lower fee per byte are evicted to make room.
     pub fn add_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the unconfirmed transaction itself is valid.
         if !transaction.is_valid() {
@@ -85,11 +154,38 @@ impl<N: Network> MemoryPool<N> {
             }
         }
 
+        // Ensure the transaction fits in the memory pool.
+        let size = transaction.to_bytes_le()?.len();
+        if size > self.maximum_size_in_bytes || self.maximum_number_of_transactions == 0 {
+            return Err(anyhow!("The unconfirmed transaction exceeds the memory pool capacity"));
+        }
+
         // Add the transaction to the memory pool. This code section executes atomically.
         {
             let mut memory_pool = self.clone();
 
-            memory_pool.transactions.insert(transaction_id, transaction.clone());
+            // Evict the transactions with the lowest fee per byte until the transaction fits.
+            while memory_pool.transactions.len() >= memory_pool.maximum_number_of_transactions
+                || memory_pool.size_in_bytes + size > memory_pool.maximum_size_in_bytes
+            {
+                let (lowest, lowest_size) = memory_pool
+                    .transactions
+                    .values()
+                    .min_by(|(a, a_size), (b, b_size)| Self::compare_priority((a, *a_size), (b, *b_size)))
+                    .ok_or_else(|| anyhow!("The memory pool is empty"))?;
+
+                // Ensure the transaction pays a higher fee per byte than the transaction it evicts.
+                // Note: Resident transactions win ties, so an equal fee per byte does not evict.
+                if Self::compare_fee_per_byte((transaction, size), (lowest, *lowest_size)) != Ordering::Greater {
+                    return Err(anyhow!("The memory pool is full, and the transaction fee per byte is too low"));
+                }
+
+                let lowest = lowest.clone();
+                memory_pool.remove_transaction(&lowest);
+            }
+
+            memory_pool.transactions.insert(transaction_id, (transaction.clone(), size));
+            memory_pool.size_in_bytes += size;
             for serial_number in transaction.serial_numbers() {
                 memory_pool.serial_numbers.insert(*serial_number);
             }
@@ -105,19 +201,7 @@ impl<N: Network> MemoryPool<N> {
 
     /// Clear a transaction (and associated state) from the memory pool.
     pub fn remove_transaction(&mut self, transaction: &Transaction<N>) {
-        // This code section executes atomically.
-
-        let mut memory_pool = self.clone();
-
-        memory_pool.transactions.remove(&transaction.transaction_id());
-        for serial_number in transaction.serial_numbers() {
-            memory_pool.serial_numbers.remove(serial_number);
-        }
-        for commitment in transaction.commitments() {
-            memory_pool.commitments.remove(commitment);
-        }
-
-        *self = memory_pool;
+        self.remove_transactions(std::slice::from_ref(transaction))
     }
 
     /// Clear a list of transactions (and associated state) from the memory pool.
@@ -127,7 +211,9 @@ impl<N: Network> MemoryPool<N> {
         let mut memory_pool = self.clone();
 
         for transaction in transactions {
-            memory_pool.transactions.remove(&transaction.transaction_id());
+            if let Some((_, size)) = memory_pool.transactions.remove(&transaction.transaction_id()) {
+                memory_pool.size_in_bytes -= size;
+            }
             for serial_number in transaction.serial_numbers() {
                 memory_pool.serial_numbers.remove(serial_number);
             }
@@ -139,11 +225,40 @@ impl<N: Network> MemoryPool<N> {
         *self = memory_pool;
     }
 
+    /// Clears the transactions whose ledger root is not in the given set of recent ledger roots,
+    /// returning the expired transactions.
+    pub fn remove_expired_transactions(&mut self, recent_ledger_roots: &HashSet<N::LedgerRoot>) -> Vec<Transaction<N>> {
+        let expired_transactions = self
+            .transactions
+            .values()
+            .filter(|(transaction, _)| !recent_ledger_roots.contains(&transaction.ledger_root()))
+            .map(|(transaction, _)| transaction.clone())
+            .collect::<Vec<_>>();
+
+        self.remove_transactions(&expired_transactions);
+        expired_transactions
+    }
+
     /// Clears all transactions (and associated state) from the memory pool.
     pub fn clear_all_transactions(&mut self) {
         self.transactions = Default::default();
         self.serial_numbers = Default::default();
         self.commitments = Default::default();
+        self.size_in_bytes = 0;
+    }
+
+    /// Compares the priority of the given transactions and their sizes in bytes.
+    /// Transactions are ranked by fee per byte, and ties are broken in favor of the lower transaction ID.
+    fn compare_priority(a: (&Transaction<N>, usize), b: (&Transaction<N>, usize)) -> Ordering {
+        Self::compare_fee_per_byte(a, b).then_with(|| (*b.0.transaction_id()).cmp(&*a.0.transaction_id()))
+    }
+
+    /// Compares the fee per byte of the given transactions and their sizes in bytes.
+    fn compare_fee_per_byte(a: (&Transaction<N>, usize), b: (&Transaction<N>, usize)) -> Ordering {
+        // Cross-multiply to compare `fee_a / size_a` with `fee_b / size_b` without losing precision.
+        let (fee_a, size_a) = (a.0.value_balance().as_i64() as i128, a.1 as i128);
+        let (fee_b, size_b) = (b.0.value_balance().as_i64() as i128, b.1 as i128);
+        (fee_a * size_b).cmp(&(fee_b * size_a))
     }
 }
 
@@ -152,3 +267,170 @@ impl<N: Network> Default for MemoryPool<N> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+    use std::sync::atomic::AtomicBool;
+
+    type CurrentNetwork = Testnet2;
+
+    /// Returns a transfer transaction paying each of the given fees, and the size of each transaction in bytes.
+    fn sample_transactions(fees: &[i64]) -> (Vec<Transaction<CurrentNetwork>>, usize) {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        // Mine a coinbase record for each transaction.
+        let mut ledger = Ledger::<CurrentNetwork>::new().unwrap();
+        let account = Account::<CurrentNetwork>::new(rng);
+        let records = fees
+            .iter()
+            .map(|_| ledger.mine_next_block(account.address(), true, &terminator, rng, 0).unwrap())
+            .collect::<Vec<_>>();
+
+        // Spend each coinbase record, paying the given fee.
+        let transactions = records
+            .into_iter()
+            .zip(fees)
+            .map(|(record, fee)| {
+                let ledger_proof = ledger.to_ledger_proof(record.commitment()).unwrap();
+                let amount = record.value().sub(AleoAmount::from_gate(*fee));
+                let request = Request::new_transfer(
+                    account.private_key(),
+                    vec![record],
+                    vec![ledger_proof],
+                    account.address(),
+                    amount,
+                    false,
+                    rng,
+                )
+                .unwrap();
+                Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure every transaction has the same size, so the fee determines the fee per byte.
+        let size = transactions[0].to_bytes_le().unwrap().len();
+        for transaction in &transactions {
+            assert_eq!(size, transaction.to_bytes_le().unwrap().len());
+        }
+        (transactions, size)
+    }
+
+    #[test]
+    fn test_evict_lowest_fee_per_byte() {
+        let (transactions, size) = sample_transactions(&[100, 300, 200]);
+
+        // Check eviction when the memory pool is limited by the number of transactions,
+        // and when the memory pool is limited by the total size in bytes.
+        for mut memory_pool in [
+            MemoryPool::<CurrentNetwork>::with_limits(2, usize::MAX, 10),
+            MemoryPool::<CurrentNetwork>::with_limits(10, 2 * size, 10),
+        ] {
+            memory_pool.add_transaction(&transactions[0]).unwrap();
+            memory_pool.add_transaction(&transactions[1]).unwrap();
+            assert_eq!(2, memory_pool.num_transactions());
+
+            // The newcomer evicts the transaction with the lowest fee per byte.
+            memory_pool.add_transaction(&transactions[2]).unwrap();
+            assert_eq!(2, memory_pool.num_transactions());
+            assert_eq!(2 * size, memory_pool.size_in_bytes());
+            assert!(!memory_pool.contains_transaction(&transactions[0]));
+            assert!(memory_pool.contains_transaction(&transactions[1]));
+            assert!(memory_pool.contains_transaction(&transactions[2]));
+
+            // The serial numbers of the evicted transaction may be used again.
+            memory_pool.remove_transaction(&transactions[2]);
+            memory_pool.add_transaction(&transactions[0]).unwrap();
+            assert!(memory_pool.contains_transaction(&transactions[0]));
+        }
+    }
+
+    #[test]
+    fn test_reject_lower_fee_per_byte() {
+        let (transactions, size) = sample_transactions(&[200, 300, 100]);
+
+        let mut memory_pool = MemoryPool::<CurrentNetwork>::with_limits(2, usize::MAX, 10);
+        memory_pool.add_transaction(&transactions[0]).unwrap();
+        memory_pool.add_transaction(&transactions[1]).unwrap();
+
+        // The newcomer pays less than the cheapest resident, so it is rejected and the memory pool is unchanged.
+        assert!(memory_pool.add_transaction(&transactions[2]).is_err());
+        assert_eq!(2, memory_pool.num_transactions());
+        assert_eq!(2 * size, memory_pool.size_in_bytes());
+        assert!(memory_pool.contains_transaction(&transactions[0]));
+        assert!(memory_pool.contains_transaction(&transactions[1]));
+        assert!(!memory_pool.contains_transaction(&transactions[2]));
+    }
+
+    #[test]
+    fn test_tie_breaking() {
+        let (transactions, size) = sample_transactions(&[200, 200, 200]);
+
+        let mut memory_pool = MemoryPool::<CurrentNetwork>::with_limits(2, usize::MAX, 10);
+        memory_pool.add_transaction(&transactions[0]).unwrap();
+        memory_pool.add_transaction(&transactions[1]).unwrap();
+
+        // The newcomer pays the same fee per byte as the residents, so the residents win the tie.
+        assert!(memory_pool.add_transaction(&transactions[2]).is_err());
+        assert!(memory_pool.contains_transaction(&transactions[0]));
+        assert!(memory_pool.contains_transaction(&transactions[1]));
+
+        // Transactions with the same fee per byte are selected in order of their transaction ID.
+        let mut expected = vec![transactions[0].clone(), transactions[1].clone()];
+        expected.sort_by_key(|transaction| *transaction.transaction_id());
+        assert_eq!(expected, memory_pool.select_for_block(2 * size));
+        assert_eq!(vec![expected[0].clone()], memory_pool.select_for_block(2 * size - 1));
+    }
+
+    #[test]
+    fn test_remove_expired_transactions() {
+        let (transactions, _) = sample_transactions(&[100, 200]);
+
+        let mut memory_pool = MemoryPool::<CurrentNetwork>::new();
+        for transaction in &transactions {
+            memory_pool.add_transaction(transaction).unwrap();
+        }
+
+        // Transactions referencing a recent ledger root are retained.
+        let recent_ledger_roots = transactions.iter().map(|transaction| transaction.ledger_root()).collect();
+        assert!(memory_pool.remove_expired_transactions(&recent_ledger_roots).is_empty());
+        assert_eq!(2, memory_pool.num_transactions());
+
+        // Transactions referencing a ledger root outside the window are removed.
+        let expired_transactions = memory_pool.remove_expired_transactions(&HashSet::new());
+        assert_eq!(2, expired_transactions.len());
+        assert_eq!(0, memory_pool.num_transactions());
+        assert_eq!(0, memory_pool.size_in_bytes());
+
+        // The serial numbers of the expired transactions may be used again.
+        memory_pool.add_transaction(&transactions[0]).unwrap();
+    }
+
+    #[test]
+    fn test_select_for_block() {
+        let (transactions, size) = sample_transactions(&[100, 300, 200]);
+
+        let mut memory_pool = MemoryPool::<CurrentNetwork>::new();
+        for transaction in &transactions {
+            memory_pool.add_transaction(transaction).unwrap();
+        }
+
+        // The transactions are selected in order of decreasing fee per byte.
+        let expected = vec![transactions[1].clone(), transactions[2].clone(), transactions[0].clone()];
+        assert_eq!(expected, memory_pool.select_for_block(3 * size));
+
+        // The selected transactions stay within the block size.
+        for max_bytes in [0, size - 1, size, 2 * size - 1, 2 * size, 3 * size - 1] {
+            let selected = memory_pool.select_for_block(max_bytes);
+            assert_eq!(max_bytes / size, selected.len());
+            assert_eq!(&expected[..selected.len()], &selected[..]);
+            let selected_size =
+                selected.iter().map(|transaction| transaction.to_bytes_le().unwrap().len()).sum::<usize>();
+            assert!(selected_size <= max_bytes);
+        }
+    }
+}
@@ -14,9 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod block_locators;
+pub use block_locators::*;
+
 pub mod blocks;
 pub use blocks::*;
 
+pub mod difficulty;
+pub use difficulty::*;
+
 pub mod ledger;
 pub use ledger::*;
 
@@ -26,12 +32,21 @@ pub use ledger_proof::*;
 pub mod ledger_tree;
 pub use ledger_tree::*;
 
+pub mod ledger_witness;
+pub use ledger_witness::*;
+
+pub mod light_client;
+pub use light_client::*;
+
 pub(crate) mod local_proof;
 pub(crate) use local_proof::*;
 
 pub mod memory_pool;
 pub use memory_pool::*;
 
+pub mod record_scanner;
+pub use record_scanner::*;
+
 pub(crate) mod record_proof;
 pub(crate) use record_proof::*;
 
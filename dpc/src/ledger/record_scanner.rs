@@ -0,0 +1,475 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashMap,
+    io::{Read, Result as IoResult, Write},
+};
+
+/// A record belonging to the scanning account, along with where it was found in the chain.
+#[derive(Clone, Derivative)]
+#[derivative(Debug(bound = "N: Network"), PartialEq(bound = "N: Network"), Eq(bound = "N: Network"))]
+pub struct OwnedRecord<N: Network> {
+    /// The decrypted record.
+    record: Record<N>,
+    /// The serial number of the record, if the scanner was given a compute key.
+    serial_number: Option<N::SerialNumber>,
+    /// The block height and transaction ID in which the record was created.
+    created_in: (u32, N::TransactionID),
+    /// The block height and transaction ID in which the record was spent, if it has been spent.
+    spent_in: Option<(u32, N::TransactionID)>,
+}
+
+impl<N: Network> OwnedRecord<N> {
+    /// Returns the decrypted record.
+    pub fn record(&self) -> &Record<N> {
+        &self.record
+    }
+
+    /// Returns the commitment of the record.
+    pub fn commitment(&self) -> N::Commitment {
+        self.record.commitment()
+    }
+
+    /// Returns the serial number of the record, if the scanner was given a compute key.
+    pub fn serial_number(&self) -> Option<N::SerialNumber> {
+        self.serial_number
+    }
+
+    /// Returns the block height and transaction ID in which the record was created.
+    pub fn created_in(&self) -> (u32, N::TransactionID) {
+        self.created_in
+    }
+
+    /// Returns the block height and transaction ID in which the record was spent, if it has been spent.
+    pub fn spent_in(&self) -> Option<(u32, N::TransactionID)> {
+        self.spent_in
+    }
+
+    /// Returns `true` if the record has been spent.
+    pub fn is_spent(&self) -> bool {
+        self.spent_in.is_some()
+    }
+}
+
+impl<N: Network> FromBytes for OwnedRecord<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let record: Record<N> = FromBytes::read_le(&mut reader)?;
+
+        let serial_number_exists: bool = FromBytes::read_le(&mut reader)?;
+        let serial_number = match serial_number_exists {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        let created_in = FromBytes::read_le(&mut reader)?;
+
+        let spent_in_exists: bool = FromBytes::read_le(&mut reader)?;
+        let spent_in = match spent_in_exists {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
+
+        Ok(Self { record, serial_number, created_in, spent_in })
+    }
+}
+
+impl<N: Network> ToBytes for OwnedRecord<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.record.write_le(&mut writer)?;
+
+        match &self.serial_number {
+            Some(serial_number) => {
+                true.write_le(&mut writer)?;
+                serial_number.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+
+        self.created_in.write_le(&mut writer)?;
+
+        match &self.spent_in {
+            Some(spent_in) => {
+                true.write_le(&mut writer)?;
+                spent_in.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+/// The progress of a record scanner, which may be persisted and used to resume scanning.
+#[derive(Clone, Derivative)]
+#[derivative(Debug(bound = "N: Network"), PartialEq(bound = "N: Network"), Eq(bound = "N: Network"))]
+pub struct RecordScannerCheckpoint<N: Network> {
+    /// The block height and hash of the most recently scanned block.
+    latest: (u32, N::BlockHash),
+    /// The records found so far, in the order they were found.
+    records: Vec<OwnedRecord<N>>,
+}
+
+impl<N: Network> RecordScannerCheckpoint<N> {
+    /// Initializes a new checkpoint at the given block, for an account that owns no records
+    /// up to and including this block.
+    pub fn new(block_height: u32, block_hash: N::BlockHash) -> Self {
+        Self { latest: (block_height, block_hash), records: vec![] }
+    }
+
+    /// Returns the block height and hash of the most recently scanned block.
+    pub fn latest(&self) -> (u32, N::BlockHash) {
+        self.latest
+    }
+
+    /// Returns the records found so far, in the order they were found.
+    pub fn records(&self) -> &Vec<OwnedRecord<N>> {
+        &self.records
+    }
+}
+
+impl<N: Network> FromBytes for RecordScannerCheckpoint<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let latest = FromBytes::read_le(&mut reader)?;
+
+        let num_records: u32 = FromBytes::read_le(&mut reader)?;
+        // Note: The number of records is untrusted, so the records are not preallocated.
+        let mut records = Vec::new();
+        for _ in 0..num_records {
+            records.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        Ok(Self { latest, records })
+    }
+}
+
+impl<N: Network> ToBytes for RecordScannerCheckpoint<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.latest.write_le(&mut writer)?;
+        (self.records.len() as u32).write_le(&mut writer)?;
+        self.records.write_le(&mut writer)
+    }
+}
+
+/// Scans blocks in order for the records of an account, tracking which of them have been spent.
+#[derive(Clone, Debug)]
+pub struct RecordScanner<N: Network> {
+    /// The decryption key of the account.
+    decryption_key: DecryptionKey<N>,
+    /// The compute key of the account, used to compute serial numbers, if it is given.
+    compute_key: Option<ComputeKey<N>>,
+    /// The block height and hash of the most recently scanned block, if any.
+    latest: Option<(u32, N::BlockHash)>,
+    /// The records found so far, in the order they were found.
+    records: Vec<OwnedRecord<N>>,
+    /// The index of each found record, by serial number.
+    serial_numbers: HashMap<N::SerialNumber, usize>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner, starting at the genesis block.
+    ///
+    /// If a compute key is given, the scanner computes the serial number of each record
+    /// and detects when a record is spent. Otherwise, it only finds the records of the account.
+    /// A console view key may be given by converting it with `ViewKey::from`.
+    pub fn new(view_key: &ViewKey<N>, compute_key: Option<ComputeKey<N>>) -> Self {
+        Self {
+            decryption_key: view_key.into(),
+            compute_key,
+            latest: None,
+            records: vec![],
+            serial_numbers: Default::default(),
+        }
+    }
+
+    /// Initializes a record scanner that resumes from the given checkpoint.
+    pub fn from_checkpoint(
+        view_key: &ViewKey<N>,
+        compute_key: Option<ComputeKey<N>>,
+        checkpoint: RecordScannerCheckpoint<N>,
+    ) -> Result<Self> {
+        let mut scanner = Self::new(view_key, compute_key);
+        scanner.latest = Some(checkpoint.latest);
+
+        for mut owned_record in checkpoint.records {
+            // Compute the serial number, if it was not computed when the checkpoint was made.
+            if owned_record.serial_number.is_none() {
+                owned_record.serial_number = scanner.to_serial_number(&owned_record.record)?;
+            }
+            scanner.insert(owned_record);
+        }
+
+        Ok(scanner)
+    }
+
+    /// Returns the block height and hash of the most recently scanned block, if any.
+    pub fn latest(&self) -> Option<(u32, N::BlockHash)> {
+        self.latest
+    }
+
+    /// Returns the records found so far, in the order they were found.
+    pub fn records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.records.iter()
+    }
+
+    /// Returns the records found so far that have not been spent.
+    pub fn unspent_records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.records.iter().filter(|owned_record| !owned_record.is_spent())
+    }
+
+    /// Returns the records found so far that have been spent.
+    pub fn spent_records(&self) -> impl Iterator<Item = &OwnedRecord<N>> + '_ {
+        self.records.iter().filter(|owned_record| owned_record.is_spent())
+    }
+
+    /// Returns the total value of the records that have not been spent.
+    pub fn balance(&self) -> AleoAmount {
+        self.unspent_records().fold(AleoAmount::ZERO, |balance, owned_record| balance.add(owned_record.record.value()))
+    }
+
+    /// Returns a checkpoint of the progress of the scanner, if any block has been scanned.
+    pub fn checkpoint(&self) -> Option<RecordScannerCheckpoint<N>> {
+        self.latest.map(|latest| RecordScannerCheckpoint { latest, records: self.records.clone() })
+    }
+
+    /// Scans the given blocks in order of increasing block height.
+    pub fn scan_blocks<'a, I: IntoIterator<Item = &'a Block<N>>>(&mut self, blocks: I) -> Result<()> {
+        blocks.into_iter().try_for_each(|block| self.scan_block(block))
+    }
+
+    /// Scans the given block, which must be the successor of the most recently scanned block.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<()> {
+        // Ensure the block is the next block to scan.
+        match self.latest {
+            Some((height, block_hash)) => {
+                if height + 1 != block.height() {
+                    return Err(anyhow!("Expected to scan block {}, found block {}", height + 1, block.height()));
+                }
+                if block_hash != block.previous_block_hash() {
+                    return Err(anyhow!("The given block does not extend the most recently scanned block"));
+                }
+            }
+            None => {
+                if !block.is_genesis() {
+                    return Err(anyhow!("The first scanned block must be the genesis block"));
+                }
+            }
+        }
+
+        // Decrypt the records of the account, before updating any state.
+        let mut owned_records = Vec::new();
+        for transaction in block.transactions().iter() {
+            for record in transaction.to_decrypted_records(&self.decryption_key) {
+                let serial_number = self.to_serial_number(&record)?;
+                owned_records.push(OwnedRecord {
+                    record,
+                    serial_number,
+                    created_in: (block.height(), transaction.transaction_id()),
+                    spent_in: None,
+                });
+            }
+        }
+
+        // Mark the records spent in this block.
+        for transaction in block.transactions().iter() {
+            for serial_number in transaction.serial_numbers() {
+                if let Some(index) = self.serial_numbers.get(serial_number) {
+                    self.records[*index].spent_in = Some((block.height(), transaction.transaction_id()));
+                }
+            }
+        }
+
+        // Add the records created in this block, and check if any of them were spent in the same block.
+        for mut owned_record in owned_records {
+            if let Some(serial_number) = owned_record.serial_number {
+                owned_record.spent_in = block.transactions().iter().find_map(|transaction| {
+                    match transaction.contains_serial_number(&serial_number) {
+                        true => Some((block.height(), transaction.transaction_id())),
+                        false => None,
+                    }
+                });
+            }
+            self.insert(owned_record);
+        }
+
+        self.latest = Some((block.height(), block.hash()));
+        Ok(())
+    }
+
+    /// Reverts the scanner to the given block height and hash, forgetting the records created
+    /// and the spends observed above it. This is used when the scanned chain is reorganized.
+    pub fn revert_to(&mut self, block_height: u32, block_hash: N::BlockHash) -> Result<()> {
+        match self.latest {
+            Some((height, _)) if block_height <= height => {}
+            _ => return Err(anyhow!("Cannot revert the scanner to an unscanned block height ({})", block_height)),
+        }
+
+        let records = std::mem::take(&mut self.records);
+        self.serial_numbers.clear();
+        for mut owned_record in records.into_iter().filter(|owned_record| owned_record.created_in.0 <= block_height) {
+            if matches!(owned_record.spent_in, Some((height, _)) if height > block_height) {
+                owned_record.spent_in = None;
+            }
+            self.insert(owned_record);
+        }
+
+        self.latest = Some((block_height, block_hash));
+        Ok(())
+    }
+
+    /// Returns the serial number of the given record, if the scanner has a compute key.
+    fn to_serial_number(&self, record: &Record<N>) -> Result<Option<N::SerialNumber>> {
+        match &self.compute_key {
+            Some(compute_key) => Ok(Some(record.to_serial_number(compute_key)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Adds the given record to the found records.
+    fn insert(&mut self, owned_record: OwnedRecord<N>) {
+        if let Some(serial_number) = owned_record.serial_number {
+            self.serial_numbers.insert(serial_number, self.records.len());
+        }
+        self.records.push(owned_record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::{thread_rng, Rng};
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn test_scan_blocks() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let account = Account::<Testnet2>::new(rng);
+        let compute_key = ComputeKey::from_private_key(account.private_key());
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let mut blocks = vec![ledger.latest_block().unwrap()];
+        for _ in 0..2 {
+            ledger.mine_next_block(account.address(), true, &terminator, rng, 0).unwrap();
+            blocks.push(ledger.latest_block().unwrap());
+        }
+
+        // The scanner finds the coinbase record of each mined block.
+        let mut scanner = RecordScanner::new(account.view_key(), Some(compute_key.clone()));
+        scanner.scan_blocks(&blocks).unwrap();
+        assert_eq!(Some((2, ledger.latest_block_hash())), scanner.latest());
+        assert_eq!(2, scanner.unspent_records().count());
+        assert_eq!(0, scanner.spent_records().count());
+        for owned_record in scanner.records() {
+            assert_eq!(account.address(), owned_record.record().owner());
+            assert!(ledger.to_ledger_proof(owned_record.commitment()).is_ok());
+            let expected_serial_number = owned_record.record().to_serial_number(&compute_key).unwrap();
+            assert_eq!(Some(expected_serial_number), owned_record.serial_number());
+        }
+
+        // The scanner rejects a block that does not extend the most recently scanned block.
+        assert!(scanner.scan_block(&blocks[1]).is_err());
+
+        // The scanner resumes from a checkpoint.
+        let checkpoint = scanner.checkpoint().unwrap();
+        let candidate_checkpoint = RecordScannerCheckpoint::read_le(&checkpoint.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(checkpoint, candidate_checkpoint);
+
+        let mut resumed_scanner = RecordScanner::new(account.view_key(), None);
+        resumed_scanner.scan_block(&blocks[0]).unwrap();
+        resumed_scanner.scan_block(&blocks[1]).unwrap();
+        let mut resumed_scanner =
+            RecordScanner::from_checkpoint(account.view_key(), Some(compute_key), resumed_scanner.checkpoint().unwrap())
+                .unwrap();
+        resumed_scanner.scan_block(&blocks[2]).unwrap();
+        assert_eq!(scanner.records().collect::<Vec<_>>(), resumed_scanner.records().collect::<Vec<_>>());
+
+        // Reverting the scanner forgets the records above the given block height.
+        scanner.revert_to(1, blocks[1].hash()).unwrap();
+        assert_eq!(1, scanner.records().count());
+        scanner.scan_block(&blocks[2]).unwrap();
+        assert_eq!(2, scanner.records().count());
+
+        // Spend the first record in a later block.
+        let spent_record = scanner.unspent_records().next().unwrap().record().clone();
+        let ledger_proof = ledger.to_ledger_proof(spent_record.commitment()).unwrap();
+        let amount = spent_record.value();
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![spent_record.clone()],
+            vec![ledger_proof],
+            account.address(),
+            amount,
+            false,
+            rng,
+        )
+        .unwrap();
+        let transaction = Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap();
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger.mine_next_block(account.address(), true, &terminator, rng, 0).unwrap();
+        blocks.push(ledger.latest_block().unwrap());
+
+        // The scanner moves the spent record to the spent records, and finds the new records of the account.
+        let balance = scanner.balance();
+        scanner.scan_block(&blocks[3]).unwrap();
+        let spent_records = scanner.spent_records().collect::<Vec<_>>();
+        assert_eq!(1, spent_records.len());
+        assert_eq!(&spent_record, spent_records[0].record());
+        assert_eq!(Some((3, transaction.transaction_id())), spent_records[0].spent_in());
+        assert!(scanner.unspent_records().all(|owned_record| owned_record.record() != &spent_record));
+        assert!(scanner.records().filter(|owned_record| owned_record.created_in().0 == 3).count() >= 2);
+        assert!(scanner.balance() > balance);
+
+        // A scanner without a compute key can not detect the spend.
+        let mut view_only_scanner = RecordScanner::new(account.view_key(), None);
+        view_only_scanner.scan_blocks(&blocks).unwrap();
+        assert_eq!(0, view_only_scanner.spent_records().count());
+    }
+
+    #[test]
+    fn test_checkpoint_serialization() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let records = (0..3)
+            .map(|i| OwnedRecord {
+                record: Record::new_noop(account.address(), rng).unwrap(),
+                serial_number: if i == 0 { None } else { Some(rng.gen()) },
+                created_in: (i, rng.gen()),
+                spent_in: if i == 2 { Some((i + 1, rng.gen())) } else { None },
+            })
+            .collect();
+        let expected_checkpoint = RecordScannerCheckpoint::<Testnet2> { latest: (5, rng.gen()), records };
+
+        let candidate_checkpoint =
+            RecordScannerCheckpoint::read_le(&expected_checkpoint.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(expected_checkpoint, candidate_checkpoint);
+
+        // A checkpoint claiming more records than it contains is rejected, without preallocating the records.
+        let mut malformed_bytes = expected_checkpoint.latest.to_bytes_le().unwrap();
+        malformed_bytes.extend(u32::MAX.to_bytes_le().unwrap());
+        assert!(RecordScannerCheckpoint::<Testnet2>::read_le(&malformed_bytes[..]).is_err());
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
     posw::PoSW,
     AleoLocator,
     AleoObject,
+    ASERT,
     Block,
     Ciphertext,
     InputPublicVariables,
@@ -143,6 +144,7 @@ impl Network for Testnet2 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_MAXIMUM_BLOCK_SIZE_IN_BYTES: usize = 2_097_152;
 
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
@@ -169,6 +171,8 @@ impl Network for Testnet2 {
     type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }>;
     type PoSW = PoSW<Self>;
 
+    type DifficultyAlgorithm = ASERT<64_800>;
+
     type AccountEncryptionScheme = ECIESPoseidonEncryption<Self::ProgramCurveParameters>;
     type AccountEncryptionGadget = ECIESPoseidonEncryptionGadget<Self::ProgramCurveParameters, Self::InnerScalarField>;
 
@@ -0,0 +1,343 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Multithreaded CPU PoSW miner, which partitions the nonce space across worker threads.
+
+use crate::{
+    posw::PoSWCircuit,
+    BlockHeader,
+    BlockHeaderMetadata,
+    BlockTemplate,
+    Network,
+    PoSWError,
+    PoSWProof,
+    PoSWScheme,
+};
+use snarkvm_algorithms::traits::SNARK;
+use snarkvm_utilities::Uniform;
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{sync::Mutex, time::Instant};
+
+/// The hash-rate metrics of a PoSW mining run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PoSWMiningStatistics {
+    /// The number of PoSW proofs computed across all worker threads.
+    attempts: u64,
+    /// The wall-clock duration of the mining run.
+    elapsed: Duration,
+}
+
+impl PoSWMiningStatistics {
+    /// Returns the number of PoSW proofs computed across all worker threads.
+    pub fn attempts(&self) -> u64 {
+        self.attempts
+    }
+
+    /// Returns the wall-clock duration of the mining run.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of PoSW proofs computed per second.
+    pub fn attempts_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            elapsed if elapsed > 0.0 => self.attempts as f64 / elapsed,
+            _ => 0.0,
+        }
+    }
+}
+
+/// A PoSW proof and nonce that satisfy the difficulty target of a block template.
+#[derive(Clone, Debug)]
+pub struct PoSWSolution<N: Network> {
+    /// The nonce of the PoSW proof.
+    nonce: N::PoSWNonce,
+    /// The PoSW proof.
+    proof: PoSWProof<N>,
+    /// The position of the nonce in the order the nonce space is searched.
+    nonce_index: u64,
+    /// The hash-rate metrics of the mining run.
+    statistics: PoSWMiningStatistics,
+}
+
+impl<N: Network> PoSWSolution<N> {
+    /// Returns the nonce of the PoSW proof.
+    pub fn nonce(&self) -> N::PoSWNonce {
+        self.nonce
+    }
+
+    /// Returns a reference to the PoSW proof.
+    pub fn proof(&self) -> &PoSWProof<N> {
+        &self.proof
+    }
+
+    /// Returns the position of the nonce in the order the nonce space is searched.
+    pub fn nonce_index(&self) -> u64 {
+        self.nonce_index
+    }
+
+    /// Returns the hash-rate metrics of the mining run.
+    pub fn statistics(&self) -> PoSWMiningStatistics {
+        self.statistics
+    }
+
+    /// Returns the block header for the given block template with this solution.
+    pub fn to_block_header(&self, block_template: &BlockTemplate<N>) -> Result<BlockHeader<N>, PoSWError> {
+        Ok(BlockHeader::from(
+            block_template.previous_ledger_root(),
+            block_template.transactions().transactions_root(),
+            BlockHeaderMetadata::new(block_template),
+            self.nonce,
+            self.proof.clone(),
+        )?)
+    }
+}
+
+///
+/// A CPU miner that searches for a PoSW solution on `num_threads` worker threads.
+///
+/// The nonce space is searched in a fixed order starting from a nonce sampled from the seed,
+/// where the nonce at index `i` is `start_nonce + i` and is assigned to worker `i % num_threads`.
+/// The miner returns the valid nonce with the lowest index, so the winning nonce for a given seed
+/// is the same regardless of the number of worker threads or their scheduling. The proof itself
+/// is not reproducible across thread counts, as each worker draws its proving randomness from its
+/// own RNG, which is seeded by the worker index and advanced by every nonce the worker has tried.
+///
+#[derive(Debug)]
+pub struct PoSWMiner<N: Network> {
+    /// The number of worker threads.
+    num_threads: usize,
+    /// The seed of the starting nonce and of the proving randomness.
+    seed: u64,
+    /// The number of PoSW proofs computed in the current mining run.
+    attempts: AtomicU64,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> PoSWMiner<N> {
+    /// Initializes a new instance of a PoSW miner with the given number of worker threads and seed.
+    pub fn new(num_threads: usize, seed: u64) -> Result<Self, PoSWError> {
+        if num_threads == 0 {
+            return Err(PoSWError::Message("The PoSW miner requires at least one worker thread".to_string()));
+        }
+        Ok(Self { num_threads, seed, attempts: AtomicU64::new(0), _network: PhantomData })
+    }
+
+    /// Returns the number of worker threads.
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Returns the seed of the starting nonce and of the proving randomness.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of PoSW proofs computed in the current (or last) mining run.
+    /// This method may be called from another thread to monitor the hash rate while mining.
+    pub fn attempts(&self) -> u64 {
+        self.attempts.load(Ordering::SeqCst)
+    }
+
+    /// Returns the nonce at the given index in the order the nonce space is searched.
+    pub fn nonce_at(&self, nonce_index: u64) -> N::PoSWNonce {
+        let start_nonce: N::InnerScalarField = Uniform::rand(&mut StdRng::seed_from_u64(self.seed));
+        (start_nonce + N::InnerScalarField::from(nonce_index)).into()
+    }
+
+    ///
+    /// Given the block template, searches the nonce space on all worker threads,
+    /// and returns the solution with the lowest nonce index that satisfies the difficulty target.
+    /// If the terminator is set, mining stops and the SNARK termination error is returned.
+    ///
+    pub fn mine(
+        &self,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+    ) -> Result<PoSWSolution<N>, PoSWError> {
+        if N::posw().proving_key().is_none() {
+            return Err(PoSWError::Message("Tried to mine without a PoSW proving key".to_string()));
+        }
+
+        // Instantiate the circuit, which is cloned by each worker thread.
+        let circuit = PoSWCircuit::<N>::new(block_template, self.nonce_at(0))?;
+
+        // The solution with the lowest nonce index found so far.
+        let best_solution: Mutex<Option<(u64, N::PoSWNonce, PoSWProof<N>)>> = Mutex::new(None);
+        // Set if any worker fails, to stop the remaining workers.
+        let abort = AtomicBool::new(false);
+
+        self.attempts.store(0, Ordering::SeqCst);
+        let start = Instant::now();
+
+        let results = std::thread::scope(|scope| {
+            let workers = (0..self.num_threads)
+                .map(|worker| {
+                    let (circuit, abort, best_solution) = (circuit.clone(), &abort, &best_solution);
+                    scope.spawn(move || {
+                        let result = self.run_worker(worker, circuit, block_template, terminator, abort, best_solution);
+                        if result.is_err() {
+                            abort.store(true, Ordering::SeqCst);
+                        }
+                        result
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker.join().unwrap_or_else(|_| Err(PoSWError::Message("A PoSW worker panicked".to_string())))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let statistics = PoSWMiningStatistics { attempts: self.attempts(), elapsed: start.elapsed() };
+
+        match best_solution.into_inner().map_err(|_| PoSWError::Message("A PoSW worker panicked".to_string()))? {
+            Some((nonce_index, nonce, proof)) => Ok(PoSWSolution { nonce, proof, nonce_index, statistics }),
+            None => {
+                // Return the first error reported by a worker thread.
+                results.into_iter().collect::<Result<Vec<_>, _>>()?;
+                Err(PoSWError::Message("Failed to find a PoSW solution".to_string()))
+            }
+        }
+    }
+
+    /// Searches the nonces assigned to the given worker until a solution is found
+    /// with a nonce index lower than that of the worker's next nonce.
+    fn run_worker(
+        &self,
+        worker: usize,
+        mut circuit: PoSWCircuit<N>,
+        block_template: &BlockTemplate<N>,
+        terminator: &AtomicBool,
+        abort: &AtomicBool,
+        best_solution: &Mutex<Option<(u64, N::PoSWNonce, PoSWProof<N>)>>,
+    ) -> Result<(), PoSWError> {
+        let pk = N::posw().proving_key().as_ref().expect("tried to mine without a PK set up");
+
+        // Each worker samples its proving randomness from a distinct seed.
+        let mut rng_seed = [0u8; 32];
+        rng_seed[..8].copy_from_slice(&self.seed.to_le_bytes());
+        rng_seed[8..16].copy_from_slice(&(worker as u64 + 1).to_le_bytes());
+        let rng = &mut StdRng::from_seed(rng_seed);
+
+        let mut nonce_index = worker as u64;
+        loop {
+            // Stop if another worker has failed.
+            if abort.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+
+            // Stop if the worker can no longer find a solution with a lower nonce index.
+            if let Some((best_nonce_index, _, _)) = *best_solution.lock().unwrap() {
+                if best_nonce_index < nonce_index {
+                    return Ok(());
+                }
+            }
+
+            // Run one iteration of PoSW.
+            circuit.set_nonce(self.nonce_at(nonce_index));
+            let proof = PoSWProof::<N>::new(
+                <N::PoSWSNARK as SNARK>::prove_with_terminator(pk, &circuit, terminator, rng, 0)?.into(),
+            );
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+
+            // Record the solution if it is valid and has the lowest nonce index so far.
+            if N::posw().verify(block_template.difficulty_target(), &circuit.to_public_inputs(), &proof) {
+                let mut best_solution = best_solution.lock().unwrap();
+                match *best_solution {
+                    Some((best_nonce_index, _, _)) if best_nonce_index < nonce_index => (),
+                    _ => *best_solution = Some((nonce_index, circuit.nonce(), proof)),
+                }
+                return Ok(());
+            }
+
+            nonce_index += self.num_threads as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use snarkvm_algorithms::errors::SNARKError;
+
+    fn genesis_block_template() -> BlockTemplate<Testnet2> {
+        let block = Testnet2::genesis_block();
+        BlockTemplate::new(
+            block.previous_block_hash(),
+            block.height(),
+            block.timestamp(),
+            block.difficulty_target(),
+            block.cumulative_weight(),
+            block.previous_ledger_root(),
+            block.transactions().clone(),
+            block.to_coinbase_transaction().unwrap().to_records().next().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_nonce_partitioning() {
+        let miner = PoSWMiner::<Testnet2>::new(4, 1234).unwrap();
+        let start_nonce = *miner.nonce_at(0);
+        for nonce_index in 0..100u64 {
+            let expected_nonce = start_nonce + <Testnet2 as Network>::InnerScalarField::from(nonce_index);
+            assert_eq!(expected_nonce, *miner.nonce_at(nonce_index));
+        }
+
+        // The starting nonce depends only on the seed.
+        assert_eq!(miner.nonce_at(7), PoSWMiner::<Testnet2>::new(1, 1234).unwrap().nonce_at(7));
+        assert_ne!(miner.nonce_at(7), PoSWMiner::<Testnet2>::new(4, 4321).unwrap().nonce_at(7));
+
+        assert!(PoSWMiner::<Testnet2>::new(0, 1234).is_err());
+    }
+
+    #[test]
+    fn test_parallel_mine() {
+        let block_template = genesis_block_template();
+
+        let miner = PoSWMiner::<Testnet2>::new(4, 1234).unwrap();
+        let solution = miner.mine(&block_template, &AtomicBool::new(false)).unwrap();
+
+        // The genesis difficulty target is met by any proof, so the first nonce is the solution.
+        assert_eq!(0, solution.nonce_index());
+        assert_eq!(miner.nonce_at(0), solution.nonce());
+        assert!(solution.statistics().attempts() >= 1);
+        assert!(solution.statistics().attempts() <= 4);
+        assert_eq!(miner.attempts(), solution.statistics().attempts());
+
+        let block_header = solution.to_block_header(&block_template).unwrap();
+        assert!(Testnet2::posw().verify_from_block_header(&block_header));
+    }
+
+    #[test]
+    fn test_parallel_mine_terminate() {
+        let miner = PoSWMiner::<Testnet2>::new(2, 1234).unwrap();
+        let result = miner.mine(&genesis_block_template(), &AtomicBool::new(true));
+
+        assert!(matches!(result, Err(PoSWError::SNARKError(SNARKError::Terminated))));
+    }
+}
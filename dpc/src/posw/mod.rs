@@ -17,6 +17,9 @@
 pub mod circuit;
 pub use circuit::*;
 
+pub mod miner;
+pub use miner::*;
+
 mod posw;
 pub(crate) use posw::PoSW;
 
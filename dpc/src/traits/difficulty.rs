@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DifficultySample;
+
+pub trait DifficultyAlgorithm: 'static + Send + Sync {
+    /// The number of most recent blocks required to compute the next difficulty target.
+    const NUM_RECENT_BLOCKS: usize;
+
+    ///
+    /// Returns the difficulty target of the next block, given the anchor block, the (up to)
+    /// `NUM_RECENT_BLOCKS` most recent blocks in order of increasing block height,
+    /// the timestamp and height of the next block, and the target block time in seconds.
+    ///
+    fn compute_difficulty_target(
+        anchor_block: &DifficultySample,
+        recent_blocks: &[DifficultySample],
+        block_timestamp: i64,
+        block_height: u32,
+        target_block_time: i64,
+    ) -> u64;
+}
@@ -30,6 +30,9 @@ pub trait LedgerTreeScheme<N: Network>: Sized {
     /// Adds all given block hashes to the tree, returning the start and ending index in the tree.
     fn add_all(&mut self, block_hashes: &[N::BlockHash]) -> Result<(u32, u32)>;
 
+    /// Removes the last `num_block_hashes` block hashes from the tree, returning the new number of block hashes.
+    fn remove_last(&mut self, num_block_hashes: u32) -> Result<u32>;
+
     /// Returns `true` if the given block hash exists.
     fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool;
 
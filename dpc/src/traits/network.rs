@@ -17,6 +17,7 @@
 use crate::{
     Block,
     Ciphertext,
+    DifficultyAlgorithm,
     InputPublicVariables,
     OutputPublicVariables,
     PoSWScheme,
@@ -168,6 +169,9 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     /// The maximum number of blocks that a fork can be.
     const ALEO_MAXIMUM_FORK_DEPTH: u32;
 
+    /// The maximum size of the transactions a miner selects for a block, in bytes.
+    const ALEO_MAXIMUM_BLOCK_SIZE_IN_BYTES: usize;
+
     /// Inner curve type declarations.
     type InnerCurve: PairingEngine<Fr = Self::InnerScalarField, Fq = Self::InnerBaseField>;
     type InnerScalarField: PrimeField;
@@ -199,6 +203,9 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type PoSWProof: Bech32Object<<Self::PoSWSNARK as SNARK>::Proof>;
     type PoSW: PoSWScheme<Self>;
 
+    /// Difficulty retargeting algorithm for the next block.
+    type DifficultyAlgorithm: DifficultyAlgorithm;
+
     /// Encryption scheme for accounts. Invoked only over `Self::InnerScalarField`.
     type AccountEncryptionScheme: EncryptionScheme<PrivateKey = Self::ProgramScalarField, PublicKey = Self::ProgramAffineCurve, MessageType = Self::InnerScalarField, CiphertextRandomizer = Self::InnerScalarField, SymmetricKeyCommitment = Self::InnerScalarField>;
     type AccountEncryptionGadget: EncryptionGadget<Self::AccountEncryptionScheme, Self::InnerScalarField>;
@@ -70,6 +70,10 @@ impl<P: Fp256Parameters> Fp256<P> {
         Fp256::<P>(element, PhantomData)
     }
 
+    /// Returns `true` if the modulus leaves enough unused bits in the most significant limb
+    /// for the multiplication to skip the final carries.
+    const CAN_SKIP_CARRIES: bool = P::MODULUS.0[3] < (u64::MAX >> 1);
+
     #[inline]
     fn is_valid(&self) -> bool {
         self.0 < P::MODULUS
@@ -129,12 +133,33 @@ impl<P: Fp256Parameters> Fp256<P> {
         r4 = fa::mac_with_carry(r4, k, P::MODULUS.0[1], &mut carry);
         r5 = fa::mac_with_carry(r5, k, P::MODULUS.0[2], &mut carry);
         r6 = fa::mac_with_carry(r6, k, P::MODULUS.0[3], &mut carry);
-        fa::adc(&mut r7, carry2, carry);
+        let carry = fa::adc(&mut r7, carry2, carry);
         (self.0).0[0] = r4;
         (self.0).0[1] = r5;
         (self.0).0[2] = r6;
         (self.0).0[3] = r7;
-        self.reduce();
+        // If the modulus uses the most significant bit, the result may exceed the backing capacity.
+        match carry != 0 {
+            true => {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+            false => self.reduce(),
+        }
+    }
+
+    /// Multiplies `self` by `other`, for a modulus that uses the most significant bit.
+    #[inline]
+    fn mul_assign_with_carries(&mut self, other: &Self) {
+        // Compute the full 512-bit product, and reduce it with the Montgomery reduction.
+        let mut r = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0;
+            for j in 0..4 {
+                r[i + j] = fa::mac_with_carry(r[i + j], (self.0).0[i], (other.0).0[j], &mut carry);
+            }
+            r[i + 4] = carry;
+        }
+        self.mont_reduce(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7]);
     }
 }
 
@@ -191,10 +216,16 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn double_in_place(&mut self) {
-        // This cannot exceed the backing capacity.
+        // This can only exceed the backing capacity if the modulus uses the most significant bit.
+        let carry = (self.0).0[3] >> 63 == 1;
         self.0.mul2();
-        // However, it may need to be reduced.
-        self.reduce();
+        // In either case, it may need to be reduced.
+        match carry {
+            true => {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+            false => self.reduce(),
+        }
     }
 
     #[inline]
@@ -271,8 +302,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if b.0.is_even() {
                         b.0.div2();
                     } else {
-                        b.0.add_nocarry(&P::MODULUS);
+                        let carry = b.0.add_nocarry(&P::MODULUS);
                         b.0.div2();
+                        // Restore the carry bit, if the modulus uses the most significant bit.
+                        (b.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -282,8 +315,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if c.0.is_even() {
                         c.0.div2();
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
+                        let carry = c.0.add_nocarry(&P::MODULUS);
                         c.0.div2();
+                        // Restore the carry bit, if the modulus uses the most significant bit.
+                        (c.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -616,10 +651,15 @@ impl<'a, P: Fp256Parameters> Div<&'a Fp256<P>> for Fp256<P> {
 impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
-        self.0.add_nocarry(&other.0);
-        // However, it may need to be reduced.
-        self.reduce();
+        // This can only exceed the backing capacity if the modulus uses the most significant bit.
+        let carry = self.0.add_nocarry(&other.0);
+        // In either case, it may need to be reduced.
+        match carry {
+            true => {
+                self.0.sub_noborrow(&P::MODULUS);
+            }
+            false => self.reduce(),
+        }
     }
 }
 
@@ -638,6 +678,11 @@ impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> MulAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
+        // The multiplication below skips the final carries, which requires unused bits in the modulus.
+        if !Self::CAN_SKIP_CARRIES {
+            return self.mul_assign_with_carries(other);
+        }
+
         let mut r = [0u64; 4];
         let mut carry1 = 0u64;
         let mut carry2 = 0u64;
@@ -19,5 +19,9 @@ pub mod crh;
 pub mod crypto_hash;
 pub mod encryption;
 pub mod merkle_tree;
+#[cfg(feature = "nonnative")]
+pub mod polycommit;
 pub mod prf;
 pub mod signature;
+#[cfg(feature = "nonnative")]
+pub mod snark;
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    nonnative::NonNativeFieldVar,
+    traits::{alloc::AllocGadget, curves::PairingGadget},
+};
+use snarkvm_algorithms::polycommit::kzg10;
+use snarkvm_curves::PairingEngine;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use core::borrow::Borrow;
+
+/// Constraints for the KZG10 verifier key.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct VerifierKeyVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The generator of G1.
+    pub g: PG::G1Gadget,
+    /// The generator of G1 that is used for making a commitment hiding.
+    pub gamma_g: PG::G1Gadget,
+    /// The generator of G2, prepared for use in pairings.
+    pub prepared_h: PG::G2PreparedGadget,
+    /// \beta times the generator of G2, prepared for use in pairings.
+    pub prepared_beta_h: PG::G2PreparedGadget,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<kzg10::VerifierKey<E>, E::Fq> for VerifierKeyVar<E, PG> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<kzg10::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        Ok(Self {
+            g: PG::G1Gadget::alloc_constant(cs.ns(|| "g"), || Ok(vk.g))?,
+            gamma_g: PG::G1Gadget::alloc_constant(cs.ns(|| "gamma_g"), || Ok(vk.gamma_g))?,
+            prepared_h: PG::G2PreparedGadget::alloc_constant(cs.ns(|| "prepared_h"), || Ok(&vk.prepared_h))?,
+            prepared_beta_h: PG::G2PreparedGadget::alloc_constant(cs.ns(|| "prepared_beta_h"), || {
+                Ok(&vk.prepared_beta_h)
+            })?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<kzg10::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        Ok(Self {
+            g: PG::G1Gadget::alloc(cs.ns(|| "g"), || Ok(vk.g))?,
+            gamma_g: PG::G1Gadget::alloc(cs.ns(|| "gamma_g"), || Ok(vk.gamma_g))?,
+            prepared_h: PG::G2PreparedGadget::alloc(cs.ns(|| "prepared_h"), || Ok(&vk.prepared_h))?,
+            prepared_beta_h: PG::G2PreparedGadget::alloc(cs.ns(|| "prepared_beta_h"), || Ok(&vk.prepared_beta_h))?,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<kzg10::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        Ok(Self {
+            g: PG::G1Gadget::alloc_input(cs.ns(|| "g"), || Ok(vk.g))?,
+            gamma_g: PG::G1Gadget::alloc_input(cs.ns(|| "gamma_g"), || Ok(vk.gamma_g))?,
+            prepared_h: PG::G2PreparedGadget::alloc_input(cs.ns(|| "prepared_h"), || Ok(&vk.prepared_h))?,
+            prepared_beta_h: PG::G2PreparedGadget::alloc_input(cs.ns(|| "prepared_beta_h"), || {
+                Ok(&vk.prepared_beta_h)
+            })?,
+        })
+    }
+}
+
+/// Constraints for a KZG10 evaluation proof.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct ProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The commitment to the witness polynomial.
+    pub w: PG::G1Gadget,
+    /// The evaluation of the random masking polynomial, if the proof is hiding.
+    pub random_v: Option<NonNativeFieldVar<E::Fr, E::Fq>>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<kzg10::Proof<E>, E::Fq> for ProofVar<E, PG> {
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<kzg10::Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+
+        let w = PG::G1Gadget::alloc_constant(cs.ns(|| "w"), || Ok(proof.w))?;
+        let random_v = match proof.random_v {
+            Some(random_v) => Some(NonNativeFieldVar::alloc_constant(cs.ns(|| "random_v"), || Ok(random_v))?),
+            None => None,
+        };
+
+        Ok(Self { w, random_v })
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<kzg10::Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+
+        let w = PG::G1Gadget::alloc(cs.ns(|| "w"), || Ok(proof.w))?;
+        let random_v = match proof.random_v {
+            Some(random_v) => Some(NonNativeFieldVar::alloc_checked(cs.ns(|| "random_v"), || Ok(random_v))?),
+            None => None,
+        };
+
+        Ok(Self { w, random_v })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<kzg10::Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+
+        let w = PG::G1Gadget::alloc_input(cs.ns(|| "w"), || Ok(proof.w))?;
+        let random_v = match proof.random_v {
+            Some(random_v) => Some(NonNativeFieldVar::alloc_input(cs.ns(|| "random_v"), || Ok(random_v))?),
+            None => None,
+        };
+
+        Ok(Self { w, random_v })
+    }
+}
@@ -0,0 +1,563 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::{polycommit::kzg10, snark::marlin::FiatShamirAlgebraicSpongeRngVar},
+    bits::{Boolean, ToBitsLEGadget},
+    nonnative::NonNativeFieldVar,
+    traits::{
+        alloc::AllocGadget,
+        curves::{GroupGadget, PairingGadget},
+        eq::EqGadget,
+        fields::{FieldGadget, ToConstraintFieldGadget},
+        AlgebraicSpongeVar,
+    },
+};
+use snarkvm_algorithms::{
+    crypto_hash::hash_to_curve,
+    polycommit::sonic_pc::{self, LCTerm},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::One;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::{borrow::Borrow, marker::PhantomData};
+use itertools::Itertools;
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+/// The query set, as a map from each polynomial label to its `(point_name, point)`.
+/// Every polynomial is assumed to be queried at a single point, as is the case in Marlin.
+pub type QuerySetVar<TargetField, BaseField> = BTreeMap<String, (String, NonNativeFieldVar<TargetField, BaseField>)>;
+
+/// The claimed evaluations, as a map from each polynomial label to its evaluation at its query point.
+pub type EvaluationsVar<TargetField, BaseField> = BTreeMap<String, NonNativeFieldVar<TargetField, BaseField>>;
+
+/// Constraints for the Sonic verifier key.
+///
+/// The pairing check is only meaningful when the verifier key is bound to the universal setup,
+/// so the key should be allocated as a constant unless it is otherwise constrained by the caller.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct VerifierKeyVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The verifier key for the underlying KZG10 scheme.
+    pub vk: kzg10::VerifierKeyVar<E, PG>,
+    /// Pairs a degree bound with its corresponding prepared G2 shift power.
+    pub degree_bounds_and_prepared_neg_powers_of_h: Vec<(usize, PG::G2PreparedGadget)>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> VerifierKeyVar<E, PG> {
+    /// Returns the prepared shift power for the given degree bound, if it is supported.
+    pub fn get_prepared_shift_power(&self, degree_bound: usize) -> Option<PG::G2PreparedGadget> {
+        self.degree_bounds_and_prepared_neg_powers_of_h
+            .binary_search_by(|(d, _)| d.cmp(&degree_bound))
+            .ok()
+            .map(|i| self.degree_bounds_and_prepared_neg_powers_of_h[i].1.clone())
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<sonic_pc::VerifierKey<E>, E::Fq> for VerifierKeyVar<E, PG> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<sonic_pc::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let mut degree_bounds_and_prepared_neg_powers_of_h = Vec::new();
+        for (i, (degree_bound, shift_power)) in
+            vk.degree_bounds_and_prepared_neg_powers_of_h.iter().flatten().enumerate()
+        {
+            let shift_power =
+                PG::G2PreparedGadget::alloc_constant(cs.ns(|| format!("shift_power_{}", i)), || Ok(shift_power))?;
+            degree_bounds_and_prepared_neg_powers_of_h.push((*degree_bound, shift_power));
+        }
+
+        Ok(Self {
+            vk: kzg10::VerifierKeyVar::alloc_constant(cs.ns(|| "vk"), || Ok(&vk.vk))?,
+            degree_bounds_and_prepared_neg_powers_of_h,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<sonic_pc::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let mut degree_bounds_and_prepared_neg_powers_of_h = Vec::new();
+        for (i, (degree_bound, shift_power)) in
+            vk.degree_bounds_and_prepared_neg_powers_of_h.iter().flatten().enumerate()
+        {
+            let shift_power = PG::G2PreparedGadget::alloc(cs.ns(|| format!("shift_power_{}", i)), || Ok(shift_power))?;
+            degree_bounds_and_prepared_neg_powers_of_h.push((*degree_bound, shift_power));
+        }
+
+        Ok(Self { vk: kzg10::VerifierKeyVar::alloc(cs.ns(|| "vk"), || Ok(&vk.vk))?, degree_bounds_and_prepared_neg_powers_of_h })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<sonic_pc::VerifierKey<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let mut degree_bounds_and_prepared_neg_powers_of_h = Vec::new();
+        for (i, (degree_bound, shift_power)) in
+            vk.degree_bounds_and_prepared_neg_powers_of_h.iter().flatten().enumerate()
+        {
+            let shift_power =
+                PG::G2PreparedGadget::alloc_input(cs.ns(|| format!("shift_power_{}", i)), || Ok(shift_power))?;
+            degree_bounds_and_prepared_neg_powers_of_h.push((*degree_bound, shift_power));
+        }
+
+        Ok(Self {
+            vk: kzg10::VerifierKeyVar::alloc_input(cs.ns(|| "vk"), || Ok(&vk.vk))?,
+            degree_bounds_and_prepared_neg_powers_of_h,
+        })
+    }
+}
+
+/// Constraints for an evaluation proof at a query set, with one KZG10 proof per query point.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct BatchProofVar<E: PairingEngine, PG: PairingGadget<E>>(pub Vec<kzg10::ProofVar<E, PG>>);
+
+impl<E: PairingEngine, PG: PairingGadget<E>> BatchProofVar<E, PG> {
+    /// Returns `true` if any of the evaluation proofs is hiding.
+    pub fn is_hiding(&self) -> bool {
+        self.0.iter().any(|proof| proof.random_v.is_some())
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<sonic_pc::BatchProof<E>, E::Fq> for BatchProofVar<E, PG> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<sonic_pc::BatchProof<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proofs = proof
+            .borrow()
+            .proofs()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| kzg10::ProofVar::alloc_constant(cs.ns(|| format!("proof_{}", i)), || Ok(p)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(proofs))
+    }
+
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<sonic_pc::BatchProof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proofs = proof
+            .borrow()
+            .proofs()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| kzg10::ProofVar::alloc(cs.ns(|| format!("proof_{}", i)), || Ok(p)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(proofs))
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<sonic_pc::BatchProof<E>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proofs = proof
+            .borrow()
+            .proofs()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| kzg10::ProofVar::alloc_input(cs.ns(|| format!("proof_{}", i)), || Ok(p)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(proofs))
+    }
+}
+
+/// Constraints for a commitment along with information about its degree bound (if any).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct LabeledCommitmentVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// The label of the committed polynomial.
+    pub label: String,
+    /// The commitment to the polynomial.
+    pub commitment: PG::G1Gadget,
+    /// The degree bound of the committed polynomial, if any.
+    pub degree_bound: Option<usize>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> LabeledCommitmentVar<E, PG> {
+    /// Instantiates a new labeled commitment.
+    pub fn new(label: String, commitment: PG::G1Gadget, degree_bound: Option<usize>) -> Self {
+        Self { label, commitment, degree_bound }
+    }
+}
+
+/// Constraints for a linear combination of polynomials, with coefficients in the nonnative scalar field.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct LinearCombinationVar<E: PairingEngine> {
+    /// The label of the linear combination.
+    pub label: String,
+    /// The terms of the linear combination.
+    pub terms: Vec<(NonNativeFieldVar<E::Fr, E::Fq>, LCTerm)>,
+}
+
+impl<E: PairingEngine> LinearCombinationVar<E> {
+    /// Instantiates a new linear combination from a label and a list of terms.
+    pub fn new(
+        label: impl Into<String>,
+        terms: impl IntoIterator<Item = (NonNativeFieldVar<E::Fr, E::Fq>, impl Into<LCTerm>)>,
+    ) -> Self {
+        Self { label: label.into(), terms: terms.into_iter().map(|(c, t)| (c, t.into())).collect() }
+    }
+
+    /// Instantiates an empty linear combination with the given label.
+    pub fn empty(label: impl Into<String>) -> Self {
+        Self { label: label.into(), terms: Vec::new() }
+    }
+
+    /// Adds a term to the linear combination.
+    pub fn add(&mut self, coeff: NonNativeFieldVar<E::Fr, E::Fq>, term: impl Into<LCTerm>) -> &mut Self {
+        self.terms.push((coeff, term.into()));
+        self
+    }
+}
+
+/// A commitment expressed as a linear combination of committed G1 elements.
+struct CombinedCommitmentVar<'a, E: PairingEngine, PG: PairingGadget<E>> {
+    terms: Vec<(NonNativeFieldVar<E::Fr, E::Fq>, &'a PG::G1Gadget)>,
+    degree_bound: Option<usize>,
+}
+
+/// Accumulates scalar multiples of G1 elements on top of a fixed offset point.
+///
+/// The G1 gadget uses incomplete addition formulas, so the running sum starts at an offset of
+/// unknown discrete logarithm, which keeps it away from the identity with overwhelming probability.
+struct G1AccumulatorVar<E: PairingEngine, PG: PairingGadget<E>> {
+    acc: PG::G1Gadget,
+    offset: E::G1Affine,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> G1AccumulatorVar<E, PG> {
+    fn new<CS: ConstraintSystem<E::Fq>>(cs: CS) -> Result<Self, SynthesisError> {
+        let (offset, _, _) = hash_to_curve::<E::G1Affine>("SonicKZG10Gadget Accumulator Offset");
+        Ok(Self { acc: PG::G1Gadget::alloc_constant(cs, || Ok(offset))?, offset })
+    }
+
+    fn add<CS: ConstraintSystem<E::Fq>>(&mut self, cs: CS, base: &PG::G1Gadget) -> Result<(), SynthesisError> {
+        self.acc = self.acc.add(cs, base)?;
+        Ok(())
+    }
+
+    fn add_scaled<CS: ConstraintSystem<E::Fq>>(
+        &mut self,
+        cs: CS,
+        base: &PG::G1Gadget,
+        scalar_bits_le: &[Boolean],
+    ) -> Result<(), SynthesisError> {
+        self.acc = base.mul_bits(cs, &self.acc, scalar_bits_le.iter().copied())?;
+        Ok(())
+    }
+
+    fn finalize<CS: ConstraintSystem<E::Fq>>(self, cs: CS) -> Result<PG::G1Gadget, SynthesisError> {
+        self.acc.sub_constant(cs, &self.offset)
+    }
+}
+
+/// The constraint analogue of the `SonicKZG10` verifier.
+pub struct SonicKZG10Gadget<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    PS: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    S: AlgebraicSpongeVar<E::Fq, PS, 6, 1>,
+>(PhantomData<(E, PG, PS, S)>);
+
+impl<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    PS: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    S: AlgebraicSpongeVar<E::Fq, PS, 6, 1>,
+> SonicKZG10Gadget<E, PG, PS, S>
+{
+    /// Outputs a `Boolean` that is true iff `evaluations` are the evaluations at `query_set`
+    /// of the polynomials committed in `commitments`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_check<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        vk: &VerifierKeyVar<E, PG>,
+        commitments: &[LabeledCommitmentVar<E, PG>],
+        query_set: &QuerySetVar<E::Fr, E::Fq>,
+        evaluations: &EvaluationsVar<E::Fr, E::Fq>,
+        proof: &BatchProofVar<E, PG>,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>,
+    ) -> Result<Boolean, SynthesisError> {
+        let commitments = commitments
+            .iter()
+            .map(|c| {
+                let terms = vec![(NonNativeFieldVar::Constant(E::Fr::one()), &c.commitment)];
+                (c.label.clone(), CombinedCommitmentVar { terms, degree_bound: c.degree_bound })
+            })
+            .collect();
+
+        Self::batch_check_combined(cs, vk, &commitments, query_set, evaluations, proof, fs_rng)
+    }
+
+    /// Outputs a `Boolean` that is true iff `evaluations` are the evaluations at `query_set`
+    /// of the linear combinations of the polynomials committed in `commitments`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_combinations<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        vk: &VerifierKeyVar<E, PG>,
+        linear_combinations: &[LinearCombinationVar<E>],
+        commitments: &[LabeledCommitmentVar<E, PG>],
+        query_set: &QuerySetVar<E::Fr, E::Fq>,
+        evaluations: &EvaluationsVar<E::Fr, E::Fq>,
+        proof: &BatchProofVar<E, PG>,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>,
+    ) -> Result<Boolean, SynthesisError> {
+        let label_comm_map = commitments.iter().map(|c| (c.label.as_str(), c)).collect::<BTreeMap<_, _>>();
+
+        let mut evaluations = evaluations.clone();
+        let mut lc_commitments = BTreeMap::new();
+
+        for (i, lc) in linear_combinations.iter().enumerate() {
+            let num_polys = lc.terms.len();
+
+            let mut degree_bound = None;
+            let mut terms = Vec::with_capacity(num_polys);
+
+            for (j, (coeff, term)) in lc.terms.iter().enumerate() {
+                match term {
+                    LCTerm::One => {
+                        if let Some(eval) = evaluations.get_mut(&lc.label) {
+                            *eval = eval.sub(cs.ns(|| format!("adjust_evaluation_{}_{}", i, j)), coeff)?;
+                        }
+                    }
+                    LCTerm::PolyLabel(label) => {
+                        let cur_comm = label_comm_map
+                            .get(label.as_str())
+                            .ok_or_else(|| anyhow!("Missing commitment for polynomial {}", label))?;
+
+                        if num_polys == 1 && cur_comm.degree_bound.is_some() {
+                            if !matches!(coeff, NonNativeFieldVar::Constant(c) if c.is_one()) {
+                                return Err(anyhow!("Coefficient must be one for degree-bounded equations").into());
+                            }
+                            degree_bound = cur_comm.degree_bound;
+                        } else if cur_comm.degree_bound.is_some() {
+                            return Err(anyhow!("Equation {} has degree bounds", lc.label).into());
+                        }
+                        terms.push((coeff.clone(), &cur_comm.commitment));
+                    }
+                }
+            }
+
+            lc_commitments.insert(lc.label.clone(), CombinedCommitmentVar { terms, degree_bound });
+        }
+
+        Self::batch_check_combined(cs.ns(|| "batch_check"), vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Mirrors `SonicKZG10::batch_check`, folding the linear combination coefficients of each commitment
+    /// into its opening challenge, so that every committed G1 element is scaled exactly once.
+    #[allow(clippy::too_many_arguments)]
+    fn batch_check_combined<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        vk: &VerifierKeyVar<E, PG>,
+        commitments: &BTreeMap<String, CombinedCommitmentVar<E, PG>>,
+        query_set: &QuerySetVar<E::Fr, E::Fq>,
+        evaluations: &EvaluationsVar<E::Fr, E::Fq>,
+        proof: &BatchProofVar<E, PG>,
+        fs_rng: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>,
+    ) -> Result<Boolean, SynthesisError> {
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name.as_str()).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label.as_str());
+        }
+
+        if proof.0.len() != query_to_labels_map.len() {
+            return Err(anyhow!("Expected {} opening proofs, found {}", query_to_labels_map.len(), proof.0.len()).into());
+        }
+
+        // Derive the randomizers from a separate sponge, which absorbs the entire claim.
+        let mut batch_fs_rng = FiatShamirAlgebraicSpongeRngVar::<E::Fr, E::Fq, PS, S>::new(cs.ns(|| "batch_fs_rng"));
+        let points = query_set.values().map(|(_, point)| point.clone()).collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative(cs.ns(|| "absorb_query_points"), &points)?;
+        let values = evaluations.values().cloned().collect::<Vec<_>>();
+        batch_fs_rng.absorb_nonnative(cs.ns(|| "absorb_values"), &values)?;
+        for (i, p) in proof.0.iter().enumerate() {
+            let w = p.w.to_constraint_field(cs.ns(|| format!("w_to_constraint_field_{}", i)))?;
+            batch_fs_rng.absorb_native(cs.ns(|| format!("absorb_w_{}", i)), &w)?;
+            if let Some(random_v) = &p.random_v {
+                batch_fs_rng.absorb_nonnative(cs.ns(|| format!("absorb_random_v_{}", i)), core::slice::from_ref(random_v))?;
+            }
+        }
+
+        let zero = NonNativeFieldVar::<E::Fr, E::Fq>::Constant(Default::default());
+
+        let mut combined_comms = BTreeMap::<Option<usize>, G1AccumulatorVar<E, PG>>::new();
+        let mut combined_witness = G1AccumulatorVar::<E, PG>::new(cs.ns(|| "combined_witness"))?;
+        let mut combined_adjusted_witness = G1AccumulatorVar::<E, PG>::new(cs.ns(|| "combined_adjusted_witness"))?;
+        let mut combined_values_scalar = zero.clone();
+        let mut combined_random_v_scalar = None;
+
+        // The first randomizer is one, and is represented as `None`.
+        let mut randomizer: Option<(NonNativeFieldVar<E::Fr, E::Fq>, Vec<Boolean>)> = None;
+
+        for (i, ((_point_name, (point, labels)), p)) in query_to_labels_map.into_iter().zip_eq(&proof.0).enumerate() {
+            let mut cs = cs.ns(|| format!("query_point_{}", i));
+
+            let mut combined_values = zero.clone();
+            for (j, label) in labels.into_iter().enumerate() {
+                let mut cs = cs.ns(|| format!("label_{}", j));
+
+                let commitment =
+                    commitments.get(label).ok_or_else(|| anyhow!("Missing commitment for polynomial {}", label))?;
+                let value =
+                    evaluations.get(label).ok_or_else(|| anyhow!("Missing evaluation for polynomial {}", label))?;
+
+                let (challenges, challenges_bits) = fs_rng.squeeze_short_nonnative_and_bits(cs.ns(|| "challenge"), 1)?;
+                let (challenge, challenge_bits) = (&challenges[0], &challenges_bits[0]);
+
+                let value_times_challenge = value.mul(cs.ns(|| "value_times_challenge"), challenge)?;
+                combined_values = combined_values.add(cs.ns(|| "combined_values"), &value_times_challenge)?;
+
+                let challenge = match &randomizer {
+                    Some((randomizer, _)) => challenge.mul(cs.ns(|| "challenge_times_randomizer"), randomizer)?,
+                    None => challenge.clone(),
+                };
+
+                let combined_comm = match combined_comms.entry(commitment.degree_bound) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(G1AccumulatorVar::new(cs.ns(|| "combined_comm"))?),
+                };
+
+                for (k, (coeff, comm)) in commitment.terms.iter().enumerate() {
+                    let scalar_bits = match (coeff, &randomizer) {
+                        (NonNativeFieldVar::Constant(c), None) if c.is_one() => challenge_bits.clone(),
+                        _ => challenge
+                            .mul(cs.ns(|| format!("scalar_{}", k)), coeff)?
+                            .to_bits_le(cs.ns(|| format!("scalar_to_bits_{}", k)))?,
+                    };
+                    combined_comm.add_scaled(cs.ns(|| format!("scale_commitment_{}", k)), comm, &scalar_bits)?;
+                }
+            }
+
+            // Accumulate `randomizer * (g * combined_values - w * point + gamma_g * random_v)`,
+            // deferring the fixed-base multiplications until every query point has been processed.
+            let (combined_values, point, random_v) = match &randomizer {
+                Some((randomizer, _)) => (
+                    combined_values.mul(cs.ns(|| "randomize_combined_values"), randomizer)?,
+                    point.mul(cs.ns(|| "randomize_point"), randomizer)?,
+                    match &p.random_v {
+                        Some(random_v) => Some(random_v.mul(cs.ns(|| "randomize_random_v"), randomizer)?),
+                        None => None,
+                    },
+                ),
+                None => (combined_values, point.clone(), p.random_v.clone()),
+            };
+            combined_values_scalar = combined_values_scalar.add(cs.ns(|| "combined_values_scalar"), &combined_values)?;
+            if let Some(random_v) = random_v {
+                combined_random_v_scalar = Some(match combined_random_v_scalar {
+                    Some(scalar) => random_v.add(cs.ns(|| "combined_random_v_scalar"), &scalar)?,
+                    None => random_v,
+                });
+            }
+
+            let neg_point_bits =
+                point.negate(cs.ns(|| "negate_point"))?.to_bits_le(cs.ns(|| "negated_point_to_bits"))?;
+            combined_adjusted_witness.add_scaled(cs.ns(|| "scale_witness_by_point"), &p.w, &neg_point_bits)?;
+
+            match &randomizer {
+                Some((_, randomizer_bits)) => {
+                    combined_witness.add_scaled(cs.ns(|| "randomize_witness"), &p.w, randomizer_bits)?
+                }
+                None => combined_witness.add(cs.ns(|| "add_witness"), &p.w)?,
+            }
+
+            let (randomizers, randomizers_bits) =
+                batch_fs_rng.squeeze_short_nonnative_and_bits(cs.ns(|| "randomizer"), 1)?;
+            randomizer = Some((randomizers[0].clone(), randomizers_bits[0].clone()));
+        }
+
+        let combined_values_bits = combined_values_scalar.to_bits_le(cs.ns(|| "combined_values_to_bits"))?;
+        combined_adjusted_witness.add_scaled(cs.ns(|| "scale_g"), &vk.vk.g, &combined_values_bits)?;
+        if let Some(combined_random_v_scalar) = combined_random_v_scalar {
+            let combined_random_v_bits = combined_random_v_scalar.to_bits_le(cs.ns(|| "combined_random_v_to_bits"))?;
+            combined_adjusted_witness.add_scaled(cs.ns(|| "scale_gamma_g"), &vk.vk.gamma_g, &combined_random_v_bits)?;
+        }
+
+        // Check the pairing product equation.
+        let mut g1_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+
+        for (i, (degree_bound, combined_comm)) in combined_comms.into_iter().enumerate() {
+            let shift_power = match degree_bound {
+                Some(degree_bound) => vk
+                    .get_prepared_shift_power(degree_bound)
+                    .ok_or_else(|| anyhow!("Unsupported degree bound {}", degree_bound))?,
+                None => vk.vk.prepared_h.clone(),
+            };
+
+            let combined_comm = combined_comm.finalize(cs.ns(|| format!("finalize_combined_comm_{}", i)))?;
+            g1_prepared_elems.push(PG::prepare_g1(cs.ns(|| format!("prepare_combined_comm_{}", i)), combined_comm)?);
+            g2_prepared_elems.push(shift_power);
+        }
+
+        let combined_adjusted_witness = combined_adjusted_witness
+            .finalize(cs.ns(|| "finalize_combined_adjusted_witness"))?
+            .negate(cs.ns(|| "negate_combined_adjusted_witness"))?;
+        g1_prepared_elems.push(PG::prepare_g1(cs.ns(|| "prepare_combined_adjusted_witness"), combined_adjusted_witness)?);
+        g2_prepared_elems.push(vk.vk.prepared_h.clone());
+
+        let combined_witness = combined_witness
+            .finalize(cs.ns(|| "finalize_combined_witness"))?
+            .negate(cs.ns(|| "negate_combined_witness"))?;
+        g1_prepared_elems.push(PG::prepare_g1(cs.ns(|| "prepare_combined_witness"), combined_witness)?);
+        g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+
+        let product = PG::product_of_pairings(cs.ns(|| "product_of_pairings"), &g1_prepared_elems, &g2_prepared_elems)?;
+        let one = PG::GTGadget::one(cs.ns(|| "one"))?;
+        product.is_eq(cs.ns(|| "product_is_one"), &one)
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::polycommit::sonic_pc::LinearCombinationVar,
+    nonnative::NonNativeFieldVar,
+    traits::fields::FieldGadget,
+};
+use snarkvm_algorithms::{
+    fft::EvaluationDomain,
+    polycommit::sonic_pc::LCTerm,
+    snark::marlin::{ahp::indexer::CircuitInfo, witness_label, AHPForR1CS, MarlinMode},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+type NonNativeVar<E> = NonNativeFieldVar<<E as PairingEngine>::Fr, <E as PairingEngine>::Fq>;
+
+/// The state of the AHP verifier, with the verifier messages derived in-circuit.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct VerifierStateVar<E: PairingEngine, MM: MarlinMode> {
+    pub batch_size: usize,
+    pub constraint_domain: EvaluationDomain<E::Fr>,
+    pub non_zero_a_domain: EvaluationDomain<E::Fr>,
+    pub non_zero_b_domain: EvaluationDomain<E::Fr>,
+    pub non_zero_c_domain: EvaluationDomain<E::Fr>,
+
+    /// Query for the random polynomial.
+    pub alpha: NonNativeVar<E>,
+    /// Randomizer for the lincheck for `B`.
+    pub eta_b: NonNativeVar<E>,
+    /// Randomizer for the lincheck for `C`.
+    pub eta_c: NonNativeVar<E>,
+    /// Randomizers for combining vectors from the batch.
+    pub batch_combiners: Vec<NonNativeVar<E>>,
+    /// Query for the second round of polynomials.
+    pub beta: NonNativeVar<E>,
+    /// Randomizer for the matrix sumcheck for `B`.
+    pub r_b: NonNativeVar<E>,
+    /// Randomizer for the matrix sumcheck for `C`.
+    pub r_c: NonNativeVar<E>,
+    /// Query for the third round of polynomials.
+    pub gamma: NonNativeVar<E>,
+
+    #[doc(hidden)]
+    pub mode: PhantomData<MM>,
+}
+
+impl<E: PairingEngine, MM: MarlinMode> VerifierStateVar<E, MM> {
+    /// Returns the query set of the AHP, as a map from each polynomial label to its `(point_name, point)`.
+    pub fn query_set(&self) -> BTreeMap<String, (String, NonNativeVar<E>)> {
+        let beta = ("beta".to_string(), self.beta.clone());
+        let gamma = ("gamma".to_string(), self.gamma.clone());
+
+        let mut query_set = BTreeMap::new();
+        for i in 0..self.batch_size {
+            query_set.insert(witness_label("z_b", i), beta.clone());
+        }
+        query_set.insert("g_1".into(), beta.clone());
+        query_set.insert("lincheck_sumcheck".into(), beta);
+
+        query_set.insert("g_a".into(), gamma.clone());
+        query_set.insert("g_b".into(), gamma.clone());
+        query_set.insert("g_c".into(), gamma.clone());
+        query_set.insert("matrix_sumcheck".into(), gamma);
+        query_set
+    }
+}
+
+/// The constraint analogue of the AHP verifier for R1CS.
+pub struct AHPForR1CSGadget<E: PairingEngine, MM: MarlinMode>(PhantomData<(E, MM)>);
+
+impl<E: PairingEngine, MM: MarlinMode> AHPForR1CSGadget<E, MM> {
+    /// Returns the evaluation domains of the indexed circuit, checking that the R1CS is square.
+    pub fn domains(
+        circuit_info: &CircuitInfo<E::Fr>,
+    ) -> Result<[EvaluationDomain<E::Fr>; 4], SynthesisError> {
+        // Check that the R1CS is a square matrix.
+        if circuit_info.num_constraints != circuit_info.num_variables {
+            return Err(anyhow!("Marlin only supports square constraint matrices").into());
+        }
+
+        let degree_too_large = || anyhow!("The polynomial degree is too large");
+        let constraint_domain = AHPForR1CS::<E::Fr, MM>::constraint_domain(circuit_info).ok_or_else(degree_too_large)?;
+        let [non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            AHPForR1CS::<E::Fr, MM>::non_zero_domains(circuit_info).ok_or_else(degree_too_large)?;
+        Ok([constraint_domain, non_zero_a_domain, non_zero_b_domain, non_zero_c_domain])
+    }
+
+    /// Construct the linear combinations that are checked by the AHP.
+    /// Public input should be formatted and padded, as it is absorbed into the Fiat-Shamir sponge.
+    #[allow(non_snake_case)]
+    pub fn construct_linear_combinations<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        public_inputs: &[Vec<NonNativeVar<E>>],
+        evals: &BTreeMap<String, NonNativeVar<E>>,
+        sums: [&NonNativeVar<E>; 3],
+        state: &VerifierStateVar<E, MM>,
+    ) -> Result<Vec<LinearCombinationVar<E>>, SynthesisError> {
+        assert!(!public_inputs.is_empty());
+        let constraint_domain = &state.constraint_domain;
+
+        let largest_non_zero_domain = [state.non_zero_a_domain, state.non_zero_b_domain, state.non_zero_c_domain]
+            .into_iter()
+            .max_by_key(|d| d.size())
+            .unwrap();
+
+        let input_domain = EvaluationDomain::<E::Fr>::new(public_inputs[0].len())
+            .ok_or_else(|| anyhow!("The polynomial degree is too large"))?;
+
+        let get_eval = |label: &str| evals.get(label).ok_or_else(|| anyhow!("Missing evaluation for {}", label));
+
+        let alpha = &state.alpha;
+        let eta_b = &state.eta_b;
+        let eta_c = &state.eta_c;
+        let batch_combiners = &state.batch_combiners;
+        let [sum_a, sum_b, sum_c] = sums;
+        let beta = &state.beta;
+        let gamma = &state.gamma;
+
+        // Since `eta_a` is one, it is omitted from the products below.
+        let t_at_beta = {
+            let mut cs = cs.ns(|| "t_at_beta");
+            let a = sum_a.mul_by_constant(cs.ns(|| "a"), &state.non_zero_a_domain.size_as_field_element)?;
+            let b = sum_b
+                .mul_by_constant(cs.ns(|| "size_b"), &state.non_zero_b_domain.size_as_field_element)?
+                .mul(cs.ns(|| "b"), eta_b)?;
+            let c = sum_c
+                .mul_by_constant(cs.ns(|| "size_c"), &state.non_zero_c_domain.size_as_field_element)?
+                .mul(cs.ns(|| "c"), eta_c)?;
+            a.add(cs.ns(|| "a_plus_b"), &b)?.add(cs.ns(|| "a_plus_b_plus_c"), &c)?
+        };
+
+        let mut linear_combinations = Vec::new();
+
+        // Lincheck sumcheck:
+        let v_H_at_alpha = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H_at_alpha"), constraint_domain, alpha)?;
+        let v_H_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H_at_beta"), constraint_domain, beta)?;
+        let v_X_at_beta = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_X_at_beta"), &input_domain, beta)?;
+        let r_alpha_at_beta = {
+            let mut cs = cs.ns(|| "r_alpha_at_beta");
+            let numerator = v_H_at_alpha.sub(cs.ns(|| "numerator"), &v_H_at_beta)?;
+            let denominator = alpha.sub(cs.ns(|| "denominator"), beta)?;
+            let denominator_inverse = denominator.inverse(cs.ns(|| "denominator_inverse"))?;
+            numerator.mul(cs.ns(|| "quotient"), &denominator_inverse)?
+        };
+
+        let z_b_s_at_beta =
+            (0..state.batch_size).map(|i| get_eval(&witness_label("z_b", i))).collect::<Result<Vec<_>, _>>()?;
+        let g_1_at_beta = get_eval("g_1")?;
+
+        let mut batch_z_b_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+        for (i, (z_b_at_beta, combiner)) in z_b_s_at_beta.iter().zip_eq(batch_combiners).enumerate() {
+            let term = z_b_at_beta.mul(cs.ns(|| format!("batch_z_b_term_{}", i)), combiner)?;
+            batch_z_b_at_beta = batch_z_b_at_beta.add(cs.ns(|| format!("batch_z_b_{}", i)), &term)?;
+        }
+
+        let lag_at_beta =
+            Self::evaluate_all_lagrange_coefficients(cs.ns(|| "lag_at_beta"), &input_domain, beta, &v_X_at_beta)?;
+        let mut combined_x_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+        for (j, (combiner, x)) in batch_combiners.iter().zip_eq(public_inputs).enumerate() {
+            let mut cs = cs.ns(|| format!("combined_x_at_beta_{}", j));
+            let mut x_at_beta = NonNativeFieldVar::Constant(E::Fr::zero());
+            for (i, (x, l)) in x.iter().zip_eq(&lag_at_beta).enumerate() {
+                // Skip the padding of the public input.
+                if matches!(x, NonNativeFieldVar::Constant(x) if x.is_zero()) {
+                    continue;
+                }
+                let term = x.mul(cs.ns(|| format!("term_{}", i)), l)?;
+                x_at_beta = x_at_beta.add(cs.ns(|| format!("sum_{}", i)), &term)?;
+            }
+            let x_at_beta = x_at_beta.mul(cs.ns(|| "combine"), combiner)?;
+            combined_x_at_beta = combined_x_at_beta.add(cs.ns(|| "sum"), &x_at_beta)?;
+        }
+
+        let lincheck_sumcheck = {
+            let mut cs = cs.ns(|| "lincheck_sumcheck");
+            let neg_t_at_beta = t_at_beta.negate(cs.ns(|| "neg_t_at_beta"))?;
+            let neg_t_v_X_at_beta = neg_t_at_beta.mul(cs.ns(|| "neg_t_v_X_at_beta"), &v_X_at_beta)?;
+
+            let mut lincheck_sumcheck = LinearCombinationVar::empty("lincheck_sumcheck");
+            if MM::ZK {
+                lincheck_sumcheck.add(NonNativeFieldVar::Constant(E::Fr::one()), "mask_poly");
+            }
+            for (i, (z_b_i_at_beta, combiner)) in z_b_s_at_beta.iter().zip_eq(batch_combiners).enumerate() {
+                let z_a_coeff = eta_c
+                    .mul(cs.ns(|| format!("eta_c_z_b_{}", i)), z_b_i_at_beta)?
+                    .add_constant(cs.ns(|| format!("eta_a_plus_eta_c_z_b_{}", i)), &E::Fr::one())?
+                    .mul(cs.ns(|| format!("combine_z_a_{}", i)), combiner)?
+                    .mul(cs.ns(|| format!("z_a_coeff_{}", i)), &r_alpha_at_beta)?;
+                let w_coeff = neg_t_v_X_at_beta.mul(cs.ns(|| format!("w_coeff_{}", i)), combiner)?;
+                lincheck_sumcheck.add(z_a_coeff, witness_label("z_a", i)).add(w_coeff, witness_label("w", i));
+            }
+            let batch_z_b_coeff = r_alpha_at_beta
+                .mul(cs.ns(|| "r_alpha_eta_b"), eta_b)?
+                .mul(cs.ns(|| "batch_z_b_coeff"), &batch_z_b_at_beta)?;
+            let combined_x_coeff = neg_t_at_beta.mul(cs.ns(|| "combined_x_coeff"), &combined_x_at_beta)?;
+            let g_1_coeff = beta.mul(cs.ns(|| "beta_g_1"), g_1_at_beta)?.negate(cs.ns(|| "g_1_coeff"))?;
+            lincheck_sumcheck
+                .add(batch_z_b_coeff, LCTerm::One)
+                .add(combined_x_coeff, LCTerm::One)
+                .add(v_H_at_beta.negate(cs.ns(|| "h_1_coeff"))?, "h_1")
+                .add(g_1_coeff, LCTerm::One);
+            lincheck_sumcheck
+        };
+
+        for i in 0..state.batch_size {
+            let z_b_i = witness_label("z_b", i);
+            linear_combinations.push(LinearCombinationVar::new(z_b_i.clone(), [(
+                NonNativeFieldVar::Constant(E::Fr::one()),
+                z_b_i,
+            )]));
+        }
+        linear_combinations
+            .push(LinearCombinationVar::new("g_1", [(NonNativeFieldVar::Constant(E::Fr::one()), "g_1")]));
+        linear_combinations.push(lincheck_sumcheck);
+
+        //  Matrix sumcheck:
+        let mut matrix_sumcheck = LinearCombinationVar::empty("matrix_sumcheck");
+
+        let v_H_at_alpha_beta = v_H_at_alpha.mul(cs.ns(|| "v_H_at_alpha_beta"), &v_H_at_beta)?;
+        let alpha_beta = alpha.mul(cs.ns(|| "alpha_beta"), beta)?;
+        let v_K_at_gamma =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_K_at_gamma"), &largest_non_zero_domain, gamma)?;
+
+        for (label, domain, sum, randomizer) in [
+            ("a", &state.non_zero_a_domain, sum_a, None),
+            ("b", &state.non_zero_b_domain, sum_b, Some(&state.r_b)),
+            ("c", &state.non_zero_c_domain, sum_c, Some(&state.r_c)),
+        ] {
+            let mut cs = cs.ns(|| format!("lhs_{}", label));
+
+            let g_label = format!("g_{}", label);
+            let g_at_gamma = get_eval(&g_label)?;
+            let selector = Self::evaluate_selector_polynomial(
+                cs.ns(|| "selector"),
+                &largest_non_zero_domain,
+                domain,
+                gamma,
+                &v_K_at_gamma,
+            )?;
+            let selector = match randomizer {
+                Some(randomizer) => selector.mul(cs.ns(|| "randomize_selector"), randomizer)?,
+                None => selector,
+            };
+
+            Self::construct_lhs(
+                cs.ns(|| "construct_lhs"),
+                &mut matrix_sumcheck,
+                label,
+                alpha,
+                beta,
+                gamma,
+                &alpha_beta,
+                &v_H_at_alpha_beta,
+                g_at_gamma,
+                sum,
+                &selector,
+            )?;
+
+            linear_combinations
+                .push(LinearCombinationVar::new(g_label.clone(), [(NonNativeFieldVar::Constant(E::Fr::one()), g_label)]));
+        }
+
+        matrix_sumcheck.add(v_K_at_gamma.negate(cs.ns(|| "h_2_coeff"))?, "h_2");
+        linear_combinations.push(matrix_sumcheck);
+
+        Ok(linear_combinations)
+    }
+
+    /// Adds `selector * (v_H(alpha) * v_H(beta) * val - (gamma * g(gamma) + sum) * denom)` to `lc`,
+    /// where `denom = alpha * beta - alpha * row - beta * col + row_col`.
+    #[allow(clippy::too_many_arguments)]
+    fn construct_lhs<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        lc: &mut LinearCombinationVar<E>,
+        label: &str,
+        alpha: &NonNativeVar<E>,
+        beta: &NonNativeVar<E>,
+        gamma: &NonNativeVar<E>,
+        alpha_beta: &NonNativeVar<E>,
+        v_h_at_alpha_beta: &NonNativeVar<E>,
+        g_at_gamma: &NonNativeVar<E>,
+        sum: &NonNativeVar<E>,
+        selector_at_gamma: &NonNativeVar<E>,
+    ) -> Result<(), SynthesisError> {
+        let val_coeff = v_h_at_alpha_beta.mul(cs.ns(|| "val_coeff"), selector_at_gamma)?;
+
+        // The coefficient of `denom`, negated.
+        let neg_b = gamma
+            .mul(cs.ns(|| "gamma_g_at_gamma"), g_at_gamma)?
+            .add(cs.ns(|| "plus_sum"), sum)?
+            .mul(cs.ns(|| "times_selector"), selector_at_gamma)?
+            .negate(cs.ns(|| "negate"))?;
+
+        lc.add(val_coeff, format!("val_{}", label))
+            .add(neg_b.mul(cs.ns(|| "one_coeff"), alpha_beta)?, LCTerm::One)
+            .add(neg_b.mul(cs.ns(|| "row_coeff"), alpha)?.negate(cs.ns(|| "negate_row_coeff"))?, format!("row_{}", label))
+            .add(neg_b.mul(cs.ns(|| "col_coeff"), beta)?.negate(cs.ns(|| "negate_col_coeff"))?, format!("col_{}", label))
+            .add(neg_b, format!("row_col_{}", label));
+        Ok(())
+    }
+
+    /// Evaluates the vanishing polynomial `x^|domain| - 1` of `domain` at `x`.
+    fn evaluate_vanishing_polynomial<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        x: &NonNativeVar<E>,
+    ) -> Result<NonNativeVar<E>, SynthesisError> {
+        x.pow_by_constant(cs.ns(|| "pow"), [domain.size])?.sub_constant(cs.ns(|| "minus_one"), &E::Fr::one())
+    }
+
+    /// Evaluates the selector polynomial of `other` within `domain` at `point`,
+    /// given the evaluation of the vanishing polynomial of `domain` at `point`.
+    fn evaluate_selector_polynomial<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        other: &EvaluationDomain<E::Fr>,
+        point: &NonNativeVar<E>,
+        v_at_point: &NonNativeVar<E>,
+    ) -> Result<NonNativeVar<E>, SynthesisError> {
+        if domain.size() == other.size() {
+            return Ok(NonNativeFieldVar::Constant(E::Fr::one()));
+        }
+        let v_other_at_point = Self::evaluate_vanishing_polynomial(cs.ns(|| "v_other"), other, point)?;
+        let ratio = other.size_as_field_element / domain.size_as_field_element;
+        let v_other_at_point_inverse = v_other_at_point.inverse(cs.ns(|| "v_other_inverse"))?;
+        v_at_point
+            .mul(cs.ns(|| "quotient"), &v_other_at_point_inverse)?
+            .mul_by_constant(cs.ns(|| "scale"), &ratio)
+    }
+
+    /// Evaluates all of the Lagrange basis polynomials of `domain` at `tau`,
+    /// given the evaluation of the vanishing polynomial of `domain` at `tau`.
+    ///
+    /// The `i`-th Lagrange polynomial is evaluated as `v(tau) / |domain| * omega^i / (tau - omega^i)`,
+    /// which requires `tau` to lie outside of `domain`.
+    fn evaluate_all_lagrange_coefficients<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        domain: &EvaluationDomain<E::Fr>,
+        tau: &NonNativeVar<E>,
+        v_at_tau: &NonNativeVar<E>,
+    ) -> Result<Vec<NonNativeVar<E>>, SynthesisError> {
+        let v_at_tau_over_size = v_at_tau.mul_by_constant(cs.ns(|| "v_at_tau_over_size"), &domain.size_inv)?;
+
+        domain
+            .elements()
+            .enumerate()
+            .map(|(i, omega_i)| {
+                let mut cs = cs.ns(|| format!("lagrange_coefficient_{}", i));
+                let denominator = tau.sub_constant(cs.ns(|| "tau_minus_omega_i"), &omega_i)?;
+                let denominator_inverse = denominator.inverse(cs.ns(|| "denominator_inverse"))?;
+                v_at_tau_over_size
+                    .mul_by_constant(cs.ns(|| "times_omega_i"), &omega_i)?
+                    .mul(cs.ns(|| "quotient"), &denominator_inverse)
+            })
+            .collect()
+    }
+}
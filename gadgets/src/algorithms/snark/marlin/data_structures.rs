@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::polycommit::sonic_pc::{BatchProofVar, VerifierKeyVar},
+    nonnative::NonNativeFieldVar,
+    traits::{alloc::AllocGadget, curves::PairingGadget},
+};
+use snarkvm_algorithms::snark::marlin::{ahp::indexer::CircuitInfo, CircuitVerifyingKey, MarlinMode, Proof};
+use snarkvm_curves::PairingEngine;
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use core::{borrow::Borrow, marker::PhantomData};
+
+type NonNativeVar<E> = NonNativeFieldVar<<E as PairingEngine>::Fr, <E as PairingEngine>::Fq>;
+
+/// Constraints for the verification key for a specific index (i.e., R1CS matrices).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct CircuitVerifyingKeyVar<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> {
+    /// Stores information about the size of the circuit, which is always a constant.
+    pub circuit_info: CircuitInfo<E::Fr>,
+    /// Commitments to the indexed polynomials.
+    pub circuit_commitments: Vec<PG::G1Gadget>,
+    /// The verifier key for this index, trimmed from the universal SRS.
+    pub verifier_key: VerifierKeyVar<E, PG>,
+    #[doc(hidden)]
+    pub mode: PhantomData<MM>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>, MM: MarlinMode> AllocGadget<CircuitVerifyingKey<E, MM>, E::Fq>
+    for CircuitVerifyingKeyVar<E, PG, MM>
+{
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let circuit_commitments = vk
+            .circuit_commitments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| PG::G1Gadget::alloc_constant(cs.ns(|| format!("circuit_commitment_{}", i)), || Ok(c.0)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let verifier_key = VerifierKeyVar::alloc_constant(cs.ns(|| "verifier_key"), || Ok(&vk.verifier_key))?;
+
+        Ok(Self { circuit_info: vk.circuit_info, circuit_commitments, verifier_key, mode: PhantomData })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let circuit_commitments = vk
+            .circuit_commitments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| PG::G1Gadget::alloc(cs.ns(|| format!("circuit_commitment_{}", i)), || Ok(c.0)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let verifier_key = VerifierKeyVar::alloc(cs.ns(|| "verifier_key"), || Ok(&vk.verifier_key))?;
+
+        Ok(Self { circuit_info: vk.circuit_info, circuit_commitments, verifier_key, mode: PhantomData })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<CircuitVerifyingKey<E, MM>>,
+        CS: ConstraintSystem<E::Fq>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = value_gen()?;
+        let vk = vk.borrow();
+
+        let circuit_commitments = vk
+            .circuit_commitments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| PG::G1Gadget::alloc_input(cs.ns(|| format!("circuit_commitment_{}", i)), || Ok(c.0)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let verifier_key = VerifierKeyVar::alloc_input(cs.ns(|| "verifier_key"), || Ok(&vk.verifier_key))?;
+
+        Ok(Self { circuit_info: vk.circuit_info, circuit_commitments, verifier_key, mode: PhantomData })
+    }
+}
+
+/// Constraints for the commitments to the `w`, `z_a`, and `z_b` polynomials.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct WitnessCommitmentsVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// Commitment to the `w` polynomial.
+    pub w: PG::G1Gadget,
+    /// Commitment to the `z_a` polynomial.
+    pub z_a: PG::G1Gadget,
+    /// Commitment to the `z_b` polynomial.
+    pub z_b: PG::G1Gadget,
+}
+
+/// Constraints for the commitments to the prover polynomials.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct CommitmentsVar<E: PairingEngine, PG: PairingGadget<E>> {
+    pub witness_commitments: Vec<WitnessCommitmentsVar<E, PG>>,
+    /// Commitment to the masking polynomial.
+    pub mask_poly: Option<PG::G1Gadget>,
+    /// Commitment to the `g_1` polynomial.
+    pub g_1: PG::G1Gadget,
+    /// Commitment to the `h_1` polynomial.
+    pub h_1: PG::G1Gadget,
+    /// Commitment to the `g_a` polynomial.
+    pub g_a: PG::G1Gadget,
+    /// Commitment to the `g_b` polynomial.
+    pub g_b: PG::G1Gadget,
+    /// Commitment to the `g_c` polynomial.
+    pub g_c: PG::G1Gadget,
+    /// Commitment to the `h_2` polynomial.
+    pub h_2: PG::G1Gadget,
+}
+
+/// Constraints for the evaluations of some of the committed polynomials.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct EvaluationsVar<E: PairingEngine> {
+    /// Evaluation of `z_b_i`'s at `beta`.
+    pub z_b_evals: Vec<NonNativeVar<E>>,
+    /// Evaluation of `g_1` at `beta`.
+    pub g_1_eval: NonNativeVar<E>,
+    /// Evaluation of `g_a` at `gamma`.
+    pub g_a_eval: NonNativeVar<E>,
+    /// Evaluation of `g_b` at `gamma`.
+    pub g_b_eval: NonNativeVar<E>,
+    /// Evaluation of `g_c` at `gamma`.
+    pub g_c_eval: NonNativeVar<E>,
+}
+
+impl<E: PairingEngine> EvaluationsVar<E> {
+    /// Returns the evaluations in the order in which they are absorbed into the Fiat-Shamir sponge.
+    pub fn to_vec(&self) -> Vec<NonNativeVar<E>> {
+        let mut evaluations = self.z_b_evals.clone();
+        evaluations.extend([
+            self.g_1_eval.clone(),
+            self.g_a_eval.clone(),
+            self.g_b_eval.clone(),
+            self.g_c_eval.clone(),
+        ]);
+        evaluations
+    }
+}
+
+/// Constraints for the prover message in the third round.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct ThirdMessageVar<E: PairingEngine> {
+    pub sum_a: NonNativeVar<E>,
+    pub sum_b: NonNativeVar<E>,
+    pub sum_c: NonNativeVar<E>,
+}
+
+/// Constraints for a zkSNARK proof.
+///
+/// Every nonnative field element of the proof is range-checked upon allocation,
+/// so that its limbs are bound to the value absorbed into the Fiat-Shamir sponge.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct ProofVar<E: PairingEngine, PG: PairingGadget<E>> {
+    /// Commitments to prover polynomials.
+    pub commitments: CommitmentsVar<E, PG>,
+    /// Evaluations of some of the committed polynomials.
+    pub evaluations: EvaluationsVar<E>,
+    /// Prover message: sum_a, sum_b, sum_c
+    pub msg: ThirdMessageVar<E>,
+    /// An evaluation proof from the polynomial commitment.
+    pub pc_proof: BatchProofVar<E, PG>,
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> ProofVar<E, PG> {
+    /// Returns the number of instances being proven in this proof.
+    pub fn batch_size(&self) -> usize {
+        self.commitments.witness_commitments.len()
+    }
+}
+
+impl<E: PairingEngine, PG: PairingGadget<E>> AllocGadget<Proof<E>, E::Fq> for ProofVar<E, PG> {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+        let comms = &proof.commitments;
+        let evals = &proof.evaluations;
+
+        let mut witness_commitments = Vec::with_capacity(proof.batch_size());
+        for (i, c) in comms.witness_commitments.iter().enumerate() {
+            witness_commitments.push(WitnessCommitmentsVar {
+                w: PG::G1Gadget::alloc(cs.ns(|| format!("w_{}", i)), || Ok(c.w.0))?,
+                z_a: PG::G1Gadget::alloc(cs.ns(|| format!("z_a_{}", i)), || Ok(c.z_a.0))?,
+                z_b: PG::G1Gadget::alloc(cs.ns(|| format!("z_b_{}", i)), || Ok(c.z_b.0))?,
+            });
+        }
+        let mask_poly = match &comms.mask_poly {
+            Some(c) => Some(PG::G1Gadget::alloc(cs.ns(|| "mask_poly"), || Ok(c.0))?),
+            None => None,
+        };
+        let commitments = CommitmentsVar {
+            witness_commitments,
+            mask_poly,
+            g_1: PG::G1Gadget::alloc(cs.ns(|| "g_1"), || Ok(comms.g_1.0))?,
+            h_1: PG::G1Gadget::alloc(cs.ns(|| "h_1"), || Ok(comms.h_1.0))?,
+            g_a: PG::G1Gadget::alloc(cs.ns(|| "g_a"), || Ok(comms.g_a.0))?,
+            g_b: PG::G1Gadget::alloc(cs.ns(|| "g_b"), || Ok(comms.g_b.0))?,
+            g_c: PG::G1Gadget::alloc(cs.ns(|| "g_c"), || Ok(comms.g_c.0))?,
+            h_2: PG::G1Gadget::alloc(cs.ns(|| "h_2"), || Ok(comms.h_2.0))?,
+        };
+
+        let evaluations = EvaluationsVar {
+            z_b_evals: evals
+                .z_b_evals
+                .iter()
+                .enumerate()
+                .map(|(i, e)| NonNativeFieldVar::alloc_checked(cs.ns(|| format!("z_b_eval_{}", i)), || Ok(e)))
+                .collect::<Result<Vec<_>, _>>()?,
+            g_1_eval: NonNativeFieldVar::alloc_checked(cs.ns(|| "g_1_eval"), || Ok(evals.g_1_eval))?,
+            g_a_eval: NonNativeFieldVar::alloc_checked(cs.ns(|| "g_a_eval"), || Ok(evals.g_a_eval))?,
+            g_b_eval: NonNativeFieldVar::alloc_checked(cs.ns(|| "g_b_eval"), || Ok(evals.g_b_eval))?,
+            g_c_eval: NonNativeFieldVar::alloc_checked(cs.ns(|| "g_c_eval"), || Ok(evals.g_c_eval))?,
+        };
+
+        let msg = ThirdMessageVar {
+            sum_a: NonNativeFieldVar::alloc_checked(cs.ns(|| "sum_a"), || Ok(proof.msg.sum_a))?,
+            sum_b: NonNativeFieldVar::alloc_checked(cs.ns(|| "sum_b"), || Ok(proof.msg.sum_b))?,
+            sum_c: NonNativeFieldVar::alloc_checked(cs.ns(|| "sum_c"), || Ok(proof.msg.sum_c))?,
+        };
+
+        let pc_proof = BatchProofVar::alloc(cs.ns(|| "pc_proof"), || Ok(&proof.pc_proof.proof))?;
+
+        Ok(Self { commitments, evaluations, msg, pc_proof })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Proof<E>>, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+        let comms = &proof.commitments;
+        let evals = &proof.evaluations;
+
+        let mut witness_commitments = Vec::with_capacity(proof.batch_size());
+        for (i, c) in comms.witness_commitments.iter().enumerate() {
+            witness_commitments.push(WitnessCommitmentsVar {
+                w: PG::G1Gadget::alloc_input(cs.ns(|| format!("w_{}", i)), || Ok(c.w.0))?,
+                z_a: PG::G1Gadget::alloc_input(cs.ns(|| format!("z_a_{}", i)), || Ok(c.z_a.0))?,
+                z_b: PG::G1Gadget::alloc_input(cs.ns(|| format!("z_b_{}", i)), || Ok(c.z_b.0))?,
+            });
+        }
+        let mask_poly = match &comms.mask_poly {
+            Some(c) => Some(PG::G1Gadget::alloc_input(cs.ns(|| "mask_poly"), || Ok(c.0))?),
+            None => None,
+        };
+        let commitments = CommitmentsVar {
+            witness_commitments,
+            mask_poly,
+            g_1: PG::G1Gadget::alloc_input(cs.ns(|| "g_1"), || Ok(comms.g_1.0))?,
+            h_1: PG::G1Gadget::alloc_input(cs.ns(|| "h_1"), || Ok(comms.h_1.0))?,
+            g_a: PG::G1Gadget::alloc_input(cs.ns(|| "g_a"), || Ok(comms.g_a.0))?,
+            g_b: PG::G1Gadget::alloc_input(cs.ns(|| "g_b"), || Ok(comms.g_b.0))?,
+            g_c: PG::G1Gadget::alloc_input(cs.ns(|| "g_c"), || Ok(comms.g_c.0))?,
+            h_2: PG::G1Gadget::alloc_input(cs.ns(|| "h_2"), || Ok(comms.h_2.0))?,
+        };
+
+        let evaluations = EvaluationsVar {
+            z_b_evals: evals
+                .z_b_evals
+                .iter()
+                .enumerate()
+                .map(|(i, e)| NonNativeFieldVar::alloc_input(cs.ns(|| format!("z_b_eval_{}", i)), || Ok(e)))
+                .collect::<Result<Vec<_>, _>>()?,
+            g_1_eval: NonNativeFieldVar::alloc_input(cs.ns(|| "g_1_eval"), || Ok(evals.g_1_eval))?,
+            g_a_eval: NonNativeFieldVar::alloc_input(cs.ns(|| "g_a_eval"), || Ok(evals.g_a_eval))?,
+            g_b_eval: NonNativeFieldVar::alloc_input(cs.ns(|| "g_b_eval"), || Ok(evals.g_b_eval))?,
+            g_c_eval: NonNativeFieldVar::alloc_input(cs.ns(|| "g_c_eval"), || Ok(evals.g_c_eval))?,
+        };
+
+        let msg = ThirdMessageVar {
+            sum_a: NonNativeFieldVar::alloc_input(cs.ns(|| "sum_a"), || Ok(proof.msg.sum_a))?,
+            sum_b: NonNativeFieldVar::alloc_input(cs.ns(|| "sum_b"), || Ok(proof.msg.sum_b))?,
+            sum_c: NonNativeFieldVar::alloc_input(cs.ns(|| "sum_c"), || Ok(proof.msg.sum_c))?,
+        };
+
+        let pc_proof = BatchProofVar::alloc_input(cs.ns(|| "pc_proof"), || Ok(&proof.pc_proof.proof))?;
+
+        Ok(Self { commitments, evaluations, msg, pc_proof })
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::{Boolean, ToBitsBEGadget},
+    fields::{AllocatedFp, FpGadget},
+    integers::uint::UInt8,
+    nonnative::{AllocatedNonNativeFieldVar, NonNativeFieldVar},
+    traits::{AlgebraicSpongeVar, FieldGadget},
+};
+use snarkvm_algorithms::{
+    overhead,
+    snark::marlin::params::{get_params, OptimizationType},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{ConstraintSystem, ConstraintVariable, LinearCombination, SynthesisError};
+
+use core::marker::PhantomData;
+
+/// The number of bits in a short challenge, matching the native Fiat-Shamir RNG.
+const SHORT_CHALLENGE_BITS: usize = 168;
+
+/// The constraint analogue of `FiatShamirAlgebraicSpongeRng`.
+///
+/// Every method mirrors its native counterpart call for call, so that a transcript
+/// absorbed in-circuit yields exactly the challenges derived by the native verifier.
+#[derive(Clone)]
+pub struct FiatShamirAlgebraicSpongeRngVar<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PS: DefaultCapacityAlgebraicSponge<BaseField, 6>,
+    S: AlgebraicSpongeVar<BaseField, PS, 6, 1>,
+> {
+    /// The algebraic sponge gadget.
+    pub s: S,
+    #[doc(hidden)]
+    _phantom: PhantomData<(TargetField, BaseField, PS)>,
+}
+
+impl<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PS: DefaultCapacityAlgebraicSponge<BaseField, 6>,
+    S: AlgebraicSpongeVar<BaseField, PS, 6, 1>,
+> FiatShamirAlgebraicSpongeRngVar<TargetField, BaseField, PS, S>
+{
+    /// Initializes the sponge with the default parameters of the native sponge.
+    pub fn new<CS: ConstraintSystem<BaseField>>(cs: CS) -> Self {
+        Self { s: S::with_parameters(cs, &PS::sample_parameters()), _phantom: PhantomData }
+    }
+
+    /// Absorbs the given nonnative field elements, as limbs, into the sponge.
+    pub fn absorb_nonnative<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[NonNativeFieldVar<TargetField, BaseField>],
+    ) -> Result<(), SynthesisError> {
+        let ty = OptimizationType::Weight;
+
+        let mut src_limbs = Vec::<FpGadget<BaseField>>::new();
+        for elem in elems {
+            match elem {
+                NonNativeFieldVar::Constant(c) => {
+                    let limbs = AllocatedNonNativeFieldVar::<TargetField, BaseField>::get_limbs_representations(c, ty)?;
+                    src_limbs.extend(limbs.into_iter().map(FpGadget::Constant));
+                }
+                NonNativeFieldVar::Var(v) => src_limbs.extend_from_slice(&v.limbs),
+            }
+        }
+
+        let dest_limbs = Self::compress_gadgets(cs.ns(|| "compress"), &src_limbs, ty)?;
+        self.s.absorb(cs.ns(|| "absorb"), dest_limbs.iter())
+    }
+
+    /// Absorbs the given native field elements into the sponge.
+    pub fn absorb_native<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        elems: &[FpGadget<BaseField>],
+    ) -> Result<(), SynthesisError> {
+        self.s.absorb(cs, elems.iter())
+    }
+
+    /// Absorbs the given bytes into the sponge, packing their big-endian bits into native field elements.
+    pub fn absorb_bytes<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[UInt8],
+    ) -> Result<(), SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        let bits = elems.iter().flat_map(|byte| byte.bits.iter().rev().copied()).collect::<Vec<_>>();
+
+        let elements =
+            bits.chunks(capacity).map(|chunk| Self::bits_be_to_fp::<CS>(chunk)).collect::<Result<Vec<_>, _>>()?;
+        self.s.absorb(cs.ns(|| "absorb"), elements.iter())
+    }
+
+    /// Squeezes `num` native field elements from the sponge.
+    pub fn squeeze_native<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        self.s.squeeze(cs, num)
+    }
+
+    /// Squeezes `num` nonnative field elements, along with their little-endian bits.
+    pub fn squeeze_nonnative_and_bits<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<(Vec<NonNativeFieldVar<TargetField, BaseField>>, Vec<Vec<Boolean>>), SynthesisError> {
+        self.squeeze_with_bit_size(cs, num, TargetField::size_in_bits() - 1)
+    }
+
+    /// Squeezes `num` short nonnative field elements, along with their little-endian bits.
+    pub fn squeeze_short_nonnative_and_bits<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<(Vec<NonNativeFieldVar<TargetField, BaseField>>, Vec<Vec<Boolean>>), SynthesisError> {
+        self.squeeze_with_bit_size(cs, num, SHORT_CHALLENGE_BITS)
+    }
+
+    /// Squeezes `num` nonnative field elements.
+    pub fn squeeze_nonnative<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        Ok(self.squeeze_nonnative_and_bits(cs, num)?.0)
+    }
+
+    /// Squeezes `num` short nonnative field elements.
+    pub fn squeeze_short_nonnative<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<NonNativeFieldVar<TargetField, BaseField>>, SynthesisError> {
+        Ok(self.squeeze_short_nonnative_and_bits(cs, num)?.0)
+    }
+
+    /// Squeezes `num_bits` bits from the sponge, discarding the highest bit of each native element.
+    pub fn squeeze_bits<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let bits_per_element = BaseField::size_in_bits() - 1;
+        let num_elements = num_bits.div_ceil(bits_per_element);
+
+        let src_elements = self.s.squeeze(cs.ns(|| "squeeze"), num_elements)?;
+        let mut dest_bits = Vec::<Boolean>::with_capacity(num_elements * bits_per_element);
+
+        for (i, elem) in src_elements.iter().enumerate() {
+            let elem_bits = elem.to_bits_be_strict(cs.ns(|| format!("to_bits_{}", i)))?;
+            // Discard the highest bit, as in the native implementation.
+            dest_bits.extend_from_slice(&elem_bits[1..]);
+        }
+        dest_bits.truncate(num_bits);
+
+        Ok(dest_bits)
+    }
+
+    /// Squeezes `num` nonnative field elements of `num_bits_per_nonnative` bits each.
+    fn squeeze_with_bit_size<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        num: usize,
+        num_bits_per_nonnative: usize,
+    ) -> Result<(Vec<NonNativeFieldVar<TargetField, BaseField>>, Vec<Vec<Boolean>>), SynthesisError> {
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), OptimizationType::Weight);
+        let total_bits = params.num_limbs * params.bits_per_limb;
+        debug_assert!(num_bits_per_nonnative < TargetField::size_in_bits());
+
+        let bits = self.squeeze_bits(cs.ns(|| "squeeze_bits"), num * num_bits_per_nonnative)?;
+
+        let mut elements = Vec::with_capacity(num);
+        let mut elements_bits_le = Vec::with_capacity(num);
+        for per_nonnative_bits in bits.chunks_exact(num_bits_per_nonnative) {
+            // Pad the big-endian bits so that they split evenly into limbs.
+            let mut padded_bits = vec![Boolean::constant(false); total_bits - num_bits_per_nonnative];
+            padded_bits.extend_from_slice(per_nonnative_bits);
+
+            let limbs = padded_bits
+                .chunks_exact(params.bits_per_limb)
+                .map(|chunk| Self::bits_be_to_fp::<CS>(chunk))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The element has fewer bits than the modulus, so its limbs are already in the normal form.
+            elements.push(NonNativeFieldVar::Var(AllocatedNonNativeFieldVar {
+                limbs,
+                num_of_additions_over_normal_form: BaseField::zero(),
+                is_in_the_normal_form: true,
+                target_phantom: PhantomData,
+            }));
+            elements_bits_le.push(per_nonnative_bits.iter().rev().copied().collect());
+        }
+        debug_assert_eq!(elements.len(), num);
+
+        Ok((elements, elements_bits_le))
+    }
+
+    /// Compresses every two limbs into one native field element, whenever they fit.
+    /// This mirrors `FiatShamirAlgebraicSpongeRng::compress_elements`, which assumes one addition over each limb.
+    fn compress_gadgets<CS: ConstraintSystem<BaseField>>(
+        mut cs: CS,
+        src_limbs: &[FpGadget<BaseField>],
+        ty: OptimizationType,
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        let capacity = BaseField::size_in_bits() - 1;
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), ty);
+        let max_bits_per_limb = params.bits_per_limb + overhead!(BaseField::one() + BaseField::one());
+
+        let mut dest_limbs = Vec::<FpGadget<BaseField>>::new();
+
+        let mut i = 0;
+        while i < src_limbs.len() {
+            let first = &src_limbs[i];
+            match src_limbs.get(i + 1) {
+                Some(second) if 2 * max_bits_per_limb <= capacity => {
+                    let mut adjustment_factor = BaseField::one();
+                    for _ in 0..max_bits_per_limb {
+                        adjustment_factor.double_in_place();
+                    }
+
+                    let shifted = first.mul_by_constant(cs.ns(|| format!("shift_{}", i)), &adjustment_factor)?;
+                    dest_limbs.push(shifted.add(cs.ns(|| format!("combine_{}", i)), second)?);
+                    i += 2;
+                }
+                _ => {
+                    dest_limbs.push(first.clone());
+                    i += 1;
+                }
+            }
+        }
+
+        Ok(dest_limbs)
+    }
+
+    /// Packs big-endian bits into a native field element, as a linear combination of the bits.
+    fn bits_be_to_fp<CS: ConstraintSystem<BaseField>>(bits: &[Boolean]) -> Result<FpGadget<BaseField>, SynthesisError> {
+        debug_assert!(bits.len() < BaseField::size_in_bits());
+
+        let mut lc = LinearCombination::<BaseField>::zero();
+        let mut value = Some(BaseField::zero());
+        let mut is_constant = true;
+        let mut coeff = BaseField::one();
+
+        for bit in bits.iter().rev() {
+            lc = lc + bit.lc(CS::one(), coeff);
+            value = match (value, bit.get_value()) {
+                (Some(value), Some(true)) => Some(value + coeff),
+                (Some(value), Some(false)) => Some(value),
+                _ => None,
+            };
+            is_constant &= matches!(bit, Boolean::Constant(_));
+            coeff.double_in_place();
+        }
+
+        match is_constant {
+            true => Ok(FpGadget::Constant(value.ok_or(SynthesisError::AssignmentMissing)?)),
+            false => Ok(FpGadget::Variable(AllocatedFp { value, variable: ConstraintVariable::LC(lc) })),
+        }
+    }
+}
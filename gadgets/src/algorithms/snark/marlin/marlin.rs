@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::{
+        polycommit::sonic_pc::{LabeledCommitmentVar, SonicKZG10Gadget},
+        snark::marlin::{
+            AHPForR1CSGadget,
+            CircuitVerifyingKeyVar,
+            FiatShamirAlgebraicSpongeRngVar,
+            ProofVar,
+            VerifierStateVar,
+        },
+    },
+    bits::Boolean,
+    integers::uint::UInt8,
+    nonnative::NonNativeFieldVar,
+    traits::{curves::PairingGadget, fields::ToConstraintFieldGadget, AlgebraicSpongeVar},
+};
+use snarkvm_algorithms::{
+    fft::EvaluationDomain,
+    snark::marlin::{witness_label, AHPForR1CS, FiatShamirAlgebraicSpongeRng, MarlinMode, MarlinSNARK},
+    traits::DefaultCapacityAlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use anyhow::anyhow;
+use core::marker::PhantomData;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+/// The constraint analogue of the Marlin verifier, instantiated with the Sonic KZG10 polynomial commitment scheme.
+///
+/// The gadget derives the verifier challenges with the in-circuit counterpart of
+/// `FiatShamirAlgebraicSpongeRng<E::Fr, E::Fq, PS>`, and therefore accepts exactly the proofs that are
+/// produced by `MarlinSNARK` with that Fiat-Shamir RNG.
+pub struct MarlinVerificationGadget<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    PS: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    S: AlgebraicSpongeVar<E::Fq, PS, 6, 1>,
+    MM: MarlinMode,
+>(PhantomData<(E, PG, PS, S, MM)>);
+
+impl<
+    E: PairingEngine,
+    PG: PairingGadget<E>,
+    PS: DefaultCapacityAlgebraicSponge<E::Fq, 6>,
+    S: AlgebraicSpongeVar<E::Fq, PS, 6, 1>,
+    MM: MarlinMode,
+> MarlinVerificationGadget<E, PG, PS, S, MM>
+{
+    /// Outputs a `Boolean` that is true iff `proof` is a valid proof for `public_input`.
+    pub fn verify<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        verifying_key: &CircuitVerifyingKeyVar<E, PG, MM>,
+        public_input: &[NonNativeFieldVar<E::Fr, E::Fq>],
+        proof: &ProofVar<E, PG>,
+    ) -> Result<Boolean, SynthesisError> {
+        Self::verify_batch(cs, verifying_key, &[public_input.to_vec()], proof)
+    }
+
+    /// Outputs a `Boolean` that is true iff `proof` is a valid proof for the batch of `public_inputs`.
+    pub fn verify_batch<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        verifying_key: &CircuitVerifyingKeyVar<E, PG, MM>,
+        public_inputs: &[Vec<NonNativeFieldVar<E::Fr, E::Fq>>],
+        proof: &ProofVar<E, PG>,
+    ) -> Result<Boolean, SynthesisError> {
+        if public_inputs.is_empty() {
+            return Err(anyhow!("Expected a non-empty batch of public inputs").into());
+        }
+        let batch_size = public_inputs.len();
+        if proof.batch_size() != batch_size {
+            return Err(anyhow!("Expected a proof for {} instances, found {}", batch_size, proof.batch_size()).into());
+        }
+
+        // The hiding mode of a proof is public, so it is checked outside of the circuit.
+        let comms = &proof.commitments;
+        let proof_has_correct_zk_mode = if MM::ZK {
+            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+        } else {
+            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+        };
+        if !proof_has_correct_zk_mode {
+            return Ok(Boolean::Constant(false));
+        }
+
+        let circuit_info = &verifying_key.circuit_info;
+        let [constraint_domain, non_zero_a_domain, non_zero_b_domain, non_zero_c_domain] =
+            AHPForR1CSGadget::<E, MM>::domains(circuit_info)?;
+
+        let input_domain = EvaluationDomain::<E::Fr>::new(circuit_info.num_public_inputs)
+            .ok_or_else(|| anyhow!("The polynomial degree is too large"))?;
+
+        let padded_public_inputs = public_inputs
+            .iter()
+            .map(|input| {
+                let mut new_input = vec![NonNativeFieldVar::Constant(E::Fr::one())];
+                new_input.extend_from_slice(input);
+                new_input.resize(input.len().max(input_domain.size()), NonNativeFieldVar::Constant(E::Fr::zero()));
+                new_input
+            })
+            .collect::<Vec<_>>();
+
+        let mut sponge = Self::init_sponge(
+            cs.ns(|| "init_sponge"),
+            batch_size,
+            &verifying_key.circuit_commitments,
+            &padded_public_inputs,
+        )?;
+
+        // --------------------------------------------------------------------
+        // First round
+        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
+        let mut first_commitments = comms
+            .witness_commitments
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| [(witness_label("w", i), &c.w), (witness_label("z_a", i), &c.z_a), (witness_label("z_b", i), &c.z_b)])
+            .collect::<Vec<_>>();
+        if let Some(mask_poly) = &comms.mask_poly {
+            first_commitments.push(("mask_poly".to_string(), mask_poly));
+        }
+        let first_commitments = first_commitments
+            .into_iter()
+            .map(|(label, c)| {
+                let degree_bound = first_round_info[&label].degree_bound();
+                LabeledCommitmentVar::new(label, c.clone(), degree_bound)
+            })
+            .collect::<Vec<_>>();
+        Self::absorb_labeled(cs.ns(|| "absorb_first_commitments"), &first_commitments, &mut sponge)?;
+
+        let elems = sponge.squeeze_nonnative(cs.ns(|| "squeeze_first_round"), 3 + batch_size - 1)?;
+        let (first, rest) = elems.split_at(3);
+        let (alpha, eta_b, eta_c) = (first[0].clone(), first[1].clone(), first[2].clone());
+        let mut batch_combiners = vec![NonNativeFieldVar::Constant(E::Fr::one())];
+        batch_combiners.extend_from_slice(rest);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        let second_round_info = AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(circuit_info);
+        let second_commitments = [("g_1", &comms.g_1), ("h_1", &comms.h_1)]
+            .into_iter()
+            .map(|(label, c)| LabeledCommitmentVar::new(label.into(), c.clone(), second_round_info[label].degree_bound()))
+            .collect::<Vec<_>>();
+        Self::absorb_labeled(cs.ns(|| "absorb_second_commitments"), &second_commitments, &mut sponge)?;
+
+        let beta = sponge.squeeze_nonnative(cs.ns(|| "squeeze_second_round"), 1)?.remove(0);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        let third_round_info = AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(circuit_info);
+        let third_commitments = [("g_a", &comms.g_a), ("g_b", &comms.g_b), ("g_c", &comms.g_c)]
+            .into_iter()
+            .map(|(label, c)| LabeledCommitmentVar::new(label.into(), c.clone(), third_round_info[label].degree_bound()))
+            .collect::<Vec<_>>();
+        Self::absorb_labeled(cs.ns(|| "absorb_third_commitments"), &third_commitments, &mut sponge)?;
+        let msg = &proof.msg;
+        sponge.absorb_nonnative(cs.ns(|| "absorb_third_message"), &[
+            msg.sum_a.clone(),
+            msg.sum_b.clone(),
+            msg.sum_c.clone(),
+        ])?;
+
+        let elems = sponge.squeeze_nonnative(cs.ns(|| "squeeze_third_round"), 2)?;
+        let (r_b, r_c) = (elems[0].clone(), elems[1].clone());
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+        let fourth_commitments =
+            vec![LabeledCommitmentVar::new("h_2".into(), comms.h_2.clone(), fourth_round_info["h_2"].degree_bound())];
+        Self::absorb_labeled(cs.ns(|| "absorb_fourth_commitments"), &fourth_commitments, &mut sponge)?;
+
+        let gamma = sponge.squeeze_nonnative(cs.ns(|| "squeeze_fourth_round"), 1)?.remove(0);
+        // --------------------------------------------------------------------
+
+        let verifier_state = VerifierStateVar::<E, MM> {
+            batch_size,
+            constraint_domain,
+            non_zero_a_domain,
+            non_zero_b_domain,
+            non_zero_c_domain,
+            alpha,
+            eta_b,
+            eta_c,
+            batch_combiners,
+            beta,
+            r_b,
+            r_c,
+            gamma,
+            mode: PhantomData,
+        };
+
+        // Gather commitments in one vector. Indexed polynomials have *no*
+        // degree bounds because we know the committed index polynomial has the
+        // correct degree.
+        let commitments = verifying_key
+            .circuit_commitments
+            .iter()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitmentVar::new(info.label().to_string(), c.clone(), info.degree_bound()))
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
+            .chain(fourth_commitments)
+            .collect::<Vec<_>>();
+
+        let query_set = verifier_state.query_set();
+
+        sponge.absorb_nonnative(cs.ns(|| "absorb_evaluations"), &proof.evaluations.to_vec())?;
+
+        let mut proof_evaluations = BTreeMap::new();
+        for (i, z_b_eval) in proof.evaluations.z_b_evals.iter().enumerate() {
+            proof_evaluations.insert(witness_label("z_b", i), z_b_eval.clone());
+        }
+        proof_evaluations.insert("g_1".to_string(), proof.evaluations.g_1_eval.clone());
+        proof_evaluations.insert("g_a".to_string(), proof.evaluations.g_a_eval.clone());
+        proof_evaluations.insert("g_b".to_string(), proof.evaluations.g_b_eval.clone());
+        proof_evaluations.insert("g_c".to_string(), proof.evaluations.g_c_eval.clone());
+
+        let mut evaluations = BTreeMap::new();
+        for label in query_set.keys() {
+            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                evaluations.insert(label.clone(), NonNativeFieldVar::Constant(E::Fr::zero()));
+            } else {
+                let eval =
+                    proof_evaluations.get(label).ok_or_else(|| anyhow!("Missing evaluation for {}", label))?;
+                evaluations.insert(label.clone(), eval.clone());
+            }
+        }
+
+        let lc_s = AHPForR1CSGadget::<E, MM>::construct_linear_combinations(
+            cs.ns(|| "construct_linear_combinations"),
+            &padded_public_inputs,
+            &evaluations,
+            [&msg.sum_a, &msg.sum_b, &msg.sum_c],
+            &verifier_state,
+        )?;
+
+        SonicKZG10Gadget::<E, PG, PS, S>::check_combinations(
+            cs.ns(|| "check_combinations"),
+            &verifying_key.verifier_key,
+            &lc_s,
+            &commitments,
+            &query_set,
+            &evaluations,
+            &proof.pc_proof,
+            &mut sponge,
+        )
+    }
+
+    fn init_sponge<CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        batch_size: usize,
+        circuit_commitments: &[PG::G1Gadget],
+        inputs: &[Vec<NonNativeFieldVar<E::Fr, E::Fq>>],
+    ) -> Result<FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>, SynthesisError> {
+        let protocol_name =
+            MarlinSNARK::<E, FiatShamirAlgebraicSpongeRng<E::Fr, E::Fq, PS>, MM, [E::Fr]>::PROTOCOL_NAME;
+
+        let mut sponge = FiatShamirAlgebraicSpongeRngVar::new(cs.ns(|| "new"));
+        sponge.absorb_bytes(cs.ns(|| "absorb_protocol_name"), &UInt8::constant_vec(&to_bytes_le![&protocol_name]?))?;
+        sponge.absorb_bytes(cs.ns(|| "absorb_batch_size"), &UInt8::constant_vec(&batch_size.to_le_bytes()))?;
+        Self::absorb(cs.ns(|| "absorb_circuit_commitments"), circuit_commitments.iter(), &mut sponge)?;
+        for (i, input) in inputs.iter().enumerate() {
+            sponge.absorb_nonnative(cs.ns(|| format!("absorb_input_{}", i)), input)?;
+        }
+        Ok(sponge)
+    }
+
+    fn absorb_labeled<CS: ConstraintSystem<E::Fq>>(
+        cs: CS,
+        comms: &[LabeledCommitmentVar<E, PG>],
+        sponge: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>,
+    ) -> Result<(), SynthesisError> {
+        Self::absorb(cs, comms.iter().map(|c| &c.commitment), sponge)
+    }
+
+    fn absorb<'a, CS: ConstraintSystem<E::Fq>>(
+        mut cs: CS,
+        commitments: impl Iterator<Item = &'a PG::G1Gadget>,
+        sponge: &mut FiatShamirAlgebraicSpongeRngVar<E::Fr, E::Fq, PS, S>,
+    ) -> Result<(), SynthesisError>
+    where
+        PG::G1Gadget: 'a,
+    {
+        let mut elems = Vec::new();
+        for (i, commitment) in commitments.enumerate() {
+            elems.extend(commitment.to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))?);
+        }
+        sponge.absorb_native(cs.ns(|| "absorb"), &elems)
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::{
+        crypto_hash::PoseidonSpongeGadget,
+        snark::marlin::{CircuitVerifyingKeyVar, FiatShamirAlgebraicSpongeRngVar, MarlinVerificationGadget, ProofVar},
+    },
+    curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
+    integers::uint::UInt8,
+    nonnative::NonNativeFieldVar,
+    AllocGadget,
+    FpGadget,
+};
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    snark::marlin::{
+        params::OptimizationType,
+        FiatShamirAlgebraicSpongeRng,
+        FiatShamirRng,
+        MarlinHidingMode,
+        MarlinSNARK,
+    },
+    SNARK,
+    SRS,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, TestConstraintSystem};
+use snarkvm_utilities::{test_crypto_rng, test_rng, Uniform};
+
+use core::ops::MulAssign;
+
+type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq, 6, 1>>;
+type FSGadget = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq, 6, 1>, PoseidonSpongeGadget<Fq, 6, 1>>;
+
+type TestSNARK = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, Vec<Fr>>;
+type TestSNARKGadget = MarlinVerificationGadget<
+    Bls12_377,
+    Bls12_377PairingGadget,
+    PoseidonSponge<Fq, 6, 1>,
+    PoseidonSpongeGadget<Fq, 6, 1>,
+    MarlinHidingMode,
+>;
+
+#[derive(Copy, Clone)]
+struct Circuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+    num_constraints: usize,
+    num_variables: usize,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<ConstraintF> {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || {
+                let mut a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+
+                a.mul_assign(&b);
+                Ok(a)
+            },
+        )?;
+
+        for i in 0..(self.num_variables - 3) {
+            let _ = cs.alloc(|| format!("var {}", i), || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        }
+
+        for i in 0..(self.num_constraints - 1) {
+            cs.enforce(|| format!("constraint {}", i), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fiat_shamir_sponge() {
+    let mut rng = test_rng();
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let nonnative_elements: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+    let native_elements: Vec<Fq> = (0..3).map(|_| Fq::rand(&mut rng)).collect();
+    let bytes: Vec<u8> = (0..100).map(|_| u8::rand(&mut rng)).collect();
+
+    let mut native_sponge = FS::new();
+    native_sponge.absorb_nonnative_field_elements(nonnative_elements.clone(), OptimizationType::Weight);
+    native_sponge.absorb_native_field_elements(&native_elements);
+    native_sponge.absorb_bytes(&bytes);
+    let native_squeeze = native_sponge.squeeze_nonnative_field_elements(3, OptimizationType::Weight).unwrap();
+    let native_short_squeeze = native_sponge.squeeze_short_nonnative_field_elements(2).unwrap();
+
+    let nonnative_elements_var = nonnative_elements
+        .iter()
+        .enumerate()
+        .map(|(i, e)| NonNativeFieldVar::alloc_checked(cs.ns(|| format!("alloc_nonnative_{}", i)), || Ok(e)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let native_elements_var = native_elements
+        .iter()
+        .enumerate()
+        .map(|(i, e)| FpGadget::alloc(cs.ns(|| format!("alloc_native_{}", i)), || Ok(e)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let bytes_var = UInt8::alloc_vec(cs.ns(|| "alloc_bytes"), &bytes).unwrap();
+
+    let mut constraint_sponge = FSGadget::new(cs.ns(|| "new"));
+    constraint_sponge.absorb_nonnative(cs.ns(|| "absorb_nonnative"), &nonnative_elements_var).unwrap();
+    constraint_sponge.absorb_native(cs.ns(|| "absorb_native"), &native_elements_var).unwrap();
+    constraint_sponge.absorb_bytes(cs.ns(|| "absorb_bytes"), &bytes_var).unwrap();
+    let constraint_squeeze = constraint_sponge.squeeze_nonnative(cs.ns(|| "squeeze"), 3).unwrap();
+    let constraint_short_squeeze = constraint_sponge.squeeze_short_nonnative(cs.ns(|| "squeeze_short"), 2).unwrap();
+
+    for (native, constraint) in native_squeeze.iter().zip(&constraint_squeeze) {
+        assert_eq!(*native, constraint.value().unwrap());
+    }
+    for (native, constraint) in native_short_squeeze.iter().zip(&constraint_short_squeeze) {
+        assert_eq!(*native, constraint.value().unwrap());
+    }
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_marlin_verification_gadget() {
+    let mut rng = test_crypto_rng();
+
+    let a = Fr::rand(&mut rng);
+    let b = Fr::rand(&mut rng);
+    let mut c = a;
+    c.mul_assign(&b);
+
+    let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 25, num_variables: 25 };
+
+    let (pk, vk) = TestSNARK::setup(&circuit, &mut SRS::CircuitSpecific(&mut rng)).unwrap();
+    let proof = TestSNARK::prove(&pk, &circuit, &mut rng).unwrap();
+    assert!(TestSNARK::verify(&vk, vec![c], &proof).unwrap());
+
+    for (public_input, expected) in [(c, true), (a, false)] {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let vk_var = CircuitVerifyingKeyVar::alloc_constant(cs.ns(|| "vk"), || Ok(&vk)).unwrap();
+        let proof_var = ProofVar::alloc(cs.ns(|| "proof"), || Ok(&proof)).unwrap();
+        let public_input_var =
+            vec![NonNativeFieldVar::alloc_input(cs.ns(|| "public_input"), || Ok(public_input)).unwrap()];
+
+        let result = TestSNARKGadget::verify(cs.ns(|| "verify"), &vk_var, &public_input_var, &proof_var).unwrap();
+
+        assert_eq!(result.get_value(), Some(expected));
+        assert!(cs.is_satisfied());
+    }
+}
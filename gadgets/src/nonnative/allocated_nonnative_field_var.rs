@@ -860,6 +860,37 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocGadget<TargetField, Ba
         Ok(Self { limbs, num_of_additions_over_normal_form, is_in_the_normal_form: true, target_phantom: PhantomData })
     }
 
+    #[inline]
+    fn alloc_checked<FN, T, CS: ConstraintSystem<BaseField>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TargetField>,
+    {
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), OptimizationType::Weight);
+
+        let mut allocated = Self::alloc(cs.ns(|| "alloc"), value_gen)?;
+
+        // Enforce that every limb fits in its designated number of bits.
+        for (i, limb) in allocated.limbs.iter().rev().take(params.num_limbs - 1).enumerate() {
+            Reducer::<TargetField, BaseField>::limb_to_bits_be(
+                &mut cs.ns(|| format!("limb_to_bits_{}", i)),
+                limb,
+                params.bits_per_limb,
+            )?;
+        }
+
+        Reducer::<TargetField, BaseField>::limb_to_bits_be(
+            &mut cs.ns(|| "top_limb_to_bits"),
+            &allocated.limbs[0],
+            TargetField::size_in_bits() - (params.num_limbs - 1) * params.bits_per_limb,
+        )?;
+
+        allocated.num_of_additions_over_normal_form = BaseField::one();
+        allocated.is_in_the_normal_form = false;
+
+        Ok(allocated)
+    }
+
     #[inline]
     fn alloc_input<FN, T, CS: ConstraintSystem<BaseField>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
     where
@@ -556,6 +556,15 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocGadget<TargetField, Ba
         AllocatedNonNativeFieldVar::alloc(cs, value_gen).map(Self::Var)
     }
 
+    #[inline]
+    fn alloc_checked<FN, T, CS: ConstraintSystem<BaseField>>(cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TargetField>,
+    {
+        AllocatedNonNativeFieldVar::alloc_checked(cs, value_gen).map(Self::Var)
+    }
+
     #[inline]
     fn alloc_input<FN, T, CS: ConstraintSystem<BaseField>>(cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
     where
@@ -0,0 +1,369 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Program;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, Record, Request, Value},
+    types::U64,
+};
+
+/// The source of the built-in `credits` program.
+///
+/// Every function conserves the total balance of its records: the output balances are computed
+/// with checked `add` and `sub` instructions, which are enforced in the circuit of the function.
+/// The `fee` function outputs the fee as a public `u64`, alongside the remaining balance.
+/// Note: `mint` does not consume a record, so only the ledger may call it (see `Credits::check_caller`).
+const CREDITS_PROGRAM: &str = r"
+program credits;
+
+record credits:
+    owner as address.private;
+    balance as u64.private;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as credits.record;
+    output r2 as credits.record;
+
+function transfer:
+    input r0 as credits.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.balance r2 into r3;
+    cast r1 r2 into r4 as credits.record;
+    cast r0.owner r3 into r5 as credits.record;
+    output r4 as credits.record;
+    output r5 as credits.record;
+
+function split:
+    input r0 as credits.record;
+    input r1 as u64.private;
+    sub r0.balance r1 into r2;
+    cast r0.owner r1 into r3 as credits.record;
+    cast r0.owner r2 into r4 as credits.record;
+    output r3 as credits.record;
+    output r4 as credits.record;
+
+function join:
+    input r0 as credits.record;
+    input r1 as credits.record;
+    add r0.balance r1.balance into r2;
+    cast r0.owner r2 into r3 as credits.record;
+    output r3 as credits.record;
+
+function fee:
+    input r0 as credits.record;
+    input r1 as u64.public;
+    sub r0.balance r1 into r2;
+    cast r0.owner r2 into r3 as credits.record;
+    output r3 as credits.record;
+    output r1 as u64.public;
+";
+
+/// The built-in `credits` program, with helpers to sign requests for each of its functions.
+#[derive(Clone)]
+pub struct Credits<N: Network> {
+    /// The `credits` program.
+    program: Program<N>,
+}
+
+impl<N: Network> Credits<N> {
+    /// Initializes the built-in `credits` program.
+    pub fn load() -> Result<Self> {
+        Ok(Self { program: Program::from_str(CREDITS_PROGRAM)? })
+    }
+
+    /// Returns the `credits` program.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Ensures the caller of the given request may call its function, where `ledger` is the address of the ledger.
+    /// Note: `mint` issues new credits, so a request to `mint` is only valid if it is signed by the ledger
+    /// (e.g. for the coinbase record of a block). Every other function may be called by any record owner.
+    pub fn check_caller(&self, request: &Request<N>, ledger: &Address<N>) -> Result<()> {
+        // Ensure the request is for the `credits` program.
+        ensure!(request.program_id() == self.program.id(), "Expected a request for the 'credits' program");
+        // Ensure a request to `mint` is from the ledger.
+        if *request.function_name() == Identifier::from_str("mint")? {
+            ensure!(request.caller() == ledger, "Only the ledger may call 'mint', found '{}'", request.caller());
+        }
+        Ok(())
+    }
+
+    /// Returns a signed request to mint `amount` credits to the `recipient`.
+    /// Note: The request is only valid if the given private key is the ledger's (see `Credits::check_caller`).
+    pub fn mint<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        recipient: Address<N>,
+        amount: u64,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        self.sign(private_key, "mint", vec![Self::address(recipient), Self::amount(amount)], rng)
+    }
+
+    /// Returns a signed request to transfer `amount` credits from the given record to the `recipient`.
+    /// The remaining balance of the record is returned to its owner as a second record.
+    pub fn transfer<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        recipient: Address<N>,
+        amount: u64,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        // Ensure the record has a sufficient balance.
+        Self::ensure_balance(&record, amount)?;
+        // Sign the request.
+        let inputs = vec![Value::Record(record), Self::address(recipient), Self::amount(amount)];
+        self.sign(private_key, "transfer", inputs, rng)
+    }
+
+    /// Returns a signed request to split the given record into two records,
+    /// where the first record has a balance of `amount` credits.
+    pub fn split<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        amount: u64,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        // Ensure the record has a sufficient balance.
+        Self::ensure_balance(&record, amount)?;
+        // Sign the request.
+        self.sign(private_key, "split", vec![Value::Record(record), Self::amount(amount)], rng)
+    }
+
+    /// Returns a signed request to join the two given records into one record.
+    pub fn join<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        first: Record<N, Plaintext<N>>,
+        second: Record<N, Plaintext<N>>,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        // Ensure the combined balance does not overflow.
+        ensure!(
+            (***first.balance()).checked_add(***second.balance()).is_some(),
+            "Attempted to join records with an invalid combined balance"
+        );
+        // Sign the request.
+        self.sign(private_key, "join", vec![Value::Record(first), Value::Record(second)], rng)
+    }
+
+    /// Returns a signed request to pay a `fee` from the given record.
+    /// The remaining balance of the record is returned to its owner.
+    pub fn fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        fee: u64,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        // Ensure the record has a sufficient balance.
+        Self::ensure_balance(&record, fee)?;
+        // Sign the request.
+        self.sign(private_key, "fee", vec![Value::Record(record), Self::amount(fee)], rng)
+    }
+}
+
+impl<N: Network> Credits<N> {
+    /// Returns a signed request for the given function name and inputs.
+    fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: &str,
+        inputs: Vec<Value<N>>,
+        rng: &mut R,
+    ) -> Result<Request<N>> {
+        self.program.sign(private_key, Identifier::from_str(function_name)?, inputs, rng)
+    }
+
+    /// Ensures the given record has a balance of at least `amount` credits.
+    fn ensure_balance(record: &Record<N, Plaintext<N>>, amount: u64) -> Result<()> {
+        let balance = **record.balance();
+        ensure!(amount <= *balance, "Insufficient balance: requested {amount}, found {balance}");
+        Ok(())
+    }
+
+    /// Returns the given address as an input value.
+    fn address(address: Address<N>) -> Value<N> {
+        Value::Plaintext(Plaintext::from(Literal::Address(address)))
+    }
+
+    /// Returns the given amount as an input value.
+    fn amount(amount: u64) -> Value<N> {
+        Value::Plaintext(Plaintext::from(Literal::U64(U64::new(amount))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns a record with the given owner and balance.
+    fn sample_record(
+        owner: Address<CurrentNetwork>,
+        balance: u64,
+    ) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(&format!("{{ owner: {owner}.private, balance: {balance}u64.private }}")).unwrap()
+    }
+
+    /// Returns the balance of the given output record.
+    fn balance_of(value: &Value<CurrentNetwork>) -> u64 {
+        match value {
+            Value::Record(record) => ***record.balance(),
+            _ => panic!("Expected a record output"),
+        }
+    }
+
+    #[test]
+    fn test_credits_evaluate() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the caller and a recipient.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the credits program and process.
+        let credits = Credits::<CurrentNetwork>::load().unwrap();
+        let process = Process::<CurrentNetwork, CurrentAleo>::new(credits.program().clone()).unwrap();
+
+        // Mint.
+        let request = credits.mint(&caller_private_key, caller, 100, rng).unwrap();
+        let outputs = process.evaluate(&request).unwrap().outputs().to_vec();
+        assert_eq!(vec![100], outputs.iter().map(balance_of).collect::<Vec<_>>());
+
+        // Transfer.
+        let request = credits.transfer(&caller_private_key, sample_record(caller, 100), recipient, 30, rng).unwrap();
+        let outputs = process.evaluate(&request).unwrap().outputs().to_vec();
+        assert_eq!(vec![30, 70], outputs.iter().map(balance_of).collect::<Vec<_>>());
+
+        // Split.
+        let request = credits.split(&caller_private_key, sample_record(caller, 100), 40, rng).unwrap();
+        let outputs = process.evaluate(&request).unwrap().outputs().to_vec();
+        assert_eq!(vec![40, 60], outputs.iter().map(balance_of).collect::<Vec<_>>());
+
+        // Join.
+        let (first, second) = (sample_record(caller, 40), sample_record(caller, 60));
+        let request = credits.join(&caller_private_key, first, second, rng).unwrap();
+        let outputs = process.evaluate(&request).unwrap().outputs().to_vec();
+        assert_eq!(vec![100], outputs.iter().map(balance_of).collect::<Vec<_>>());
+
+        // Fee.
+        let request = credits.fee(&caller_private_key, sample_record(caller, 100), 5, rng).unwrap();
+        let outputs = process.evaluate(&request).unwrap().outputs().to_vec();
+        assert_eq!(2, outputs.len());
+        assert_eq!(95, balance_of(&outputs[0]));
+        assert_eq!(Value::Plaintext(Plaintext::from_str("5u64").unwrap()), outputs[1]);
+    }
+
+    #[test]
+    fn test_credits_insufficient_balance() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the credits program.
+        let credits = Credits::<CurrentNetwork>::load().unwrap();
+
+        // Ensure the helpers reject amounts that exceed the record balance.
+        assert!(credits.transfer(&caller_private_key, sample_record(caller, 10), caller, 11, rng).is_err());
+        assert!(credits.split(&caller_private_key, sample_record(caller, 10), 11, rng).is_err());
+        assert!(credits.fee(&caller_private_key, sample_record(caller, 10), 11, rng).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_credits_insufficient_balance_halts() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the credits program and process.
+        let credits = Credits::<CurrentNetwork>::load().unwrap();
+        let process = Process::<CurrentNetwork, CurrentAleo>::new(credits.program().clone()).unwrap();
+
+        // Ensure the program halts on a request that bypasses the helpers, as the balance underflows.
+        let inputs = vec![Value::Record(sample_record(caller, 10)), Credits::<CurrentNetwork>::amount(11)];
+        let request = credits.sign(&caller_private_key, "split", inputs, rng).unwrap();
+        let _ = process.evaluate(&request);
+    }
+
+    #[test]
+    fn test_credits_check_caller() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the ledger and a caller.
+        let ledger_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let ledger = Address::try_from(&ledger_private_key).unwrap();
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the credits program.
+        let credits = Credits::<CurrentNetwork>::load().unwrap();
+
+        // Ensure the ledger may mint credits.
+        let request = credits.mint(&ledger_private_key, caller, 100, rng).unwrap();
+        assert!(credits.check_caller(&request, &ledger).is_ok());
+
+        // Ensure any other caller may not mint credits.
+        let request = credits.mint(&caller_private_key, caller, 100, rng).unwrap();
+        assert!(credits.check_caller(&request, &ledger).is_err());
+
+        // Ensure any caller may spend their own records.
+        let request = credits.transfer(&caller_private_key, sample_record(caller, 100), ledger, 30, rng).unwrap();
+        assert!(credits.check_caller(&request, &ledger).is_ok());
+    }
+
+    #[test]
+    fn test_credits_execute() {
+        let rng = &mut test_crypto_rng();
+
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the credits program and process.
+        let credits = Credits::<CurrentNetwork>::load().unwrap();
+        let process = Process::<CurrentNetwork, CurrentAleo>::new(credits.program().clone()).unwrap();
+
+        // Execute a join.
+        // Note: The circuit key is synthesized from sampled inputs, which do not underflow for `join`.
+        let (first, second) = (sample_record(caller, 40), sample_record(caller, 60));
+        let request = credits.join(&caller_private_key, first, second, rng).unwrap();
+        let (response, transition) = process.execute(&request, rng).unwrap();
+        assert_eq!(vec![100], response.outputs().iter().map(balance_of).collect::<Vec<_>>());
+
+        let (_, verifying_key) = process.circuit_key(request.program_id(), request.function_name()).unwrap();
+        assert!(transition.verify(&verifying_key));
+    }
+}
@@ -23,6 +23,9 @@
 #[macro_use]
 extern crate tracing;
 
+mod credits;
+pub use credits::*;
+
 #[allow(dead_code, unused_imports)]
 mod ledger;
 pub use ledger::*;
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use console::{
-    account::{Address, ComputeKey, PrivateKey, ViewKey},
+    account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
     network::{prelude::*, Network},
     transition::{Record, State},
     types::{Field, Group, Scalar, U64},
@@ -243,6 +243,8 @@ fn tcm<A: circuit::Aleo, R: Rng + CryptoRng>(
 /// Transition: 0 -> 1
 fn mint<A: circuit::Aleo, R: Rng + CryptoRng>(
     caller: &Address<A::Network>,
+    caller_pk_tag: &Group<A::Network>,
+    caller_pk_tag_signature: &Signature<A::Network>,
     amount: u64,
     rng: &mut R,
 ) -> Result<Transaction<A::Network>>
@@ -271,6 +273,8 @@ where
 
     // Encrypt the state into a record.
     let record = state.encrypt(&randomizer)?;
+    // Compute the record tag as `Hash(randomizer * pk_tag)`.
+    let tag = GraphKey::derive_output_tag(*caller_pk_tag, randomizer)?;
 
     // Compute the record view key as `randomizer * address`.
     let record_view_key = (**caller * randomizer).to_x_coordinate();
@@ -280,8 +284,15 @@ where
     let (fcm, r_fcm) = fcm::<A>(&[], &[r_bcm])?;
 
     let process = std::panic::catch_unwind(|| {
-        let public = output::circuit::Public::<A>::from(output_index, record.clone(), fcm, tcm, tpk);
-        let private = output::circuit::Private::<A>::from(*caller, state, r_fcm, r_tcm);
+        let public = output::circuit::Public::<A>::from(output_index, record.clone(), tag, fcm, tcm, tpk);
+        let private = output::circuit::Private::<A>::from(
+            *caller,
+            state,
+            *caller_pk_tag,
+            *caller_pk_tag_signature,
+            r_fcm,
+            r_tcm,
+        );
         output::circuit::OutputCircuit::from(public, private)?.execute();
         println!("Is satisfied? {} ({} constraints)", A::is_satisfied(), A::num_constraints());
 
@@ -299,7 +310,7 @@ where
             program: Field::<A::Network>::zero(), // TODO: Hardcode this option in the Network trait.
             process: Field::<A::Network>::zero(), // TODO: Hardcode this option in the Network trait.
             inputs: vec![],
-            outputs: vec![output::Output::new(record)],
+            outputs: vec![output::Output::new(record, tag)],
             input_proofs: vec![],
             output_proofs: vec![proof],
             tcm,
@@ -422,14 +433,24 @@ fn main() -> Result<()> {
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<<circuit::AleoV0 as circuit::Environment>::Network>::new(&mut rng)?;
-    let _caller_view_key = ViewKey::try_from(&caller_private_key)?;
+    let caller_view_key = ViewKey::try_from(&caller_private_key)?;
+    let caller_graph_key = GraphKey::try_from(&caller_view_key)?;
     let caller_address = Address::try_from(&caller_private_key)?;
 
-    // Generate a coinbase transaction.
-    let transaction = mint::<circuit::AleoV0, ThreadRng>(&caller_address, 100u64, &mut rng)?;
-
-    // Retrieve the coinbase record.
-    let record = transaction.transitions()[0].outputs[0].record();
+    // Generate a coinbase transaction, tagged with the signed tag public key of the caller.
+    let (caller_pk_tag, caller_pk_tag_signature) = GraphKey::sign_pk_tag(&caller_private_key, &mut rng)?;
+    let transaction = mint::<circuit::AleoV0, ThreadRng>(
+        &caller_address,
+        &caller_pk_tag,
+        &caller_pk_tag_signature,
+        100u64,
+        &mut rng,
+    )?;
+
+    // Retrieve the coinbase record, which the graph key recognizes from its tag.
+    let output = &transaction.transitions()[0].outputs[0];
+    assert!(caller_graph_key.is_output_tag_owner(output.record().nonce(), output.tag()));
+    let record = output.record();
 
     // Spend the coinbase record.
     let _transaction = burn::<circuit::AleoV0, ThreadRng>(&caller_private_key, record, &mut rng)?;
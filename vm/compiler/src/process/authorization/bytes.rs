@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The version of the authorization byte format.
+const AUTHORIZATION_VERSION: u8 = 0;
+
+impl<N: Network> FromBytes for Authorization<N> {
+    /// Reads the authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != AUTHORIZATION_VERSION {
+            return Err(error(format!("Invalid authorization version: found {version}, expected 0")));
+        }
+        // Read the request.
+        let request = Request::read_le(&mut reader)?;
+        // Return the authorization, which ensures the request is valid.
+        Self::try_from(request).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for Authorization<N> {
+    /// Writes the authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        AUTHORIZATION_VERSION.write_le(&mut writer)?;
+        // Write the request.
+        self.request.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::authorization::test_helpers::sample_authorization;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new authorization.
+            let expected = sample_authorization(rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Authorization::read_le(&expected_bytes[..])?);
+            assert!(Authorization::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+            // Check an unknown version is rejected.
+            let mut candidate_bytes = expected_bytes.clone();
+            candidate_bytes[0] = 1;
+            assert!(Authorization::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+
+            // Check a tampered request is rejected.
+            let mut candidate_bytes = expected_bytes;
+            let last = candidate_bytes.len() - 1;
+            candidate_bytes[last] ^= 1;
+            assert!(Authorization::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Transition;
+use console::{
+    network::prelude::*,
+    program::{InputID, Request},
+    types::Field,
+};
+
+/// An authorization to execute a program function, which may be proven by an untrusted process.
+///
+/// The authorization is a signed request that contains everything needed to prove the function,
+/// and no private key. The prover learns the function inputs, but cannot alter them, as they are
+/// bound to the request signature of the caller.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Authorization<N: Network> {
+    /// The signed request.
+    request: Request<N>,
+}
+
+impl<N: Network> TryFrom<Request<N>> for Authorization<N> {
+    type Error = Error;
+
+    /// Initializes a new authorization from a signed request.
+    fn try_from(request: Request<N>) -> Result<Self> {
+        // Ensure the request is well-formed.
+        ensure!(request.verify(), "Failed to authorize an invalid request");
+        // Return the authorization.
+        Ok(Self { request })
+    }
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns the signed request.
+    pub const fn request(&self) -> &Request<N> {
+        &self.request
+    }
+
+    /// Returns `true` if the given transition executes this authorization,
+    /// i.e. it is for the same function, inputs, and transition public key.
+    pub fn matches(&self, transition: &Transition<N>) -> bool {
+        // Compute the expected transition input IDs.
+        let input_ids = self
+            .request
+            .input_ids()
+            .iter()
+            .map(|input_id| match input_id {
+                InputID::Constant(hash) | InputID::Public(hash) | InputID::Private(hash) => *hash,
+                InputID::Record(_, serial_number) => *serial_number,
+            })
+            .collect::<Vec<Field<N>>>();
+
+        self.request.program_id() == transition.program_id()
+            && self.request.function_name() == transition.function_name()
+            && input_ids == transition.input_ids()
+            && self.request.to_tpk() == *transition.tpk()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Program;
+    use console::{
+        account::PrivateKey,
+        network::Testnet3,
+        program::{Identifier, Plaintext, Value},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a sample program, with a function `compute` that takes a private and a public field.
+    pub(crate) fn sample_program() -> Program<CurrentNetwork> {
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program delegate;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    mul r0 r2 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        program
+    }
+
+    /// Returns a sample authorization of the `compute` function in the sample program.
+    pub(crate) fn sample_authorization<R: Rng + CryptoRng>(rng: &mut R) -> Result<Authorization<CurrentNetwork>> {
+        // Initialize a new caller account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        // Declare the inputs.
+        let inputs = vec![
+            Value::Plaintext(Plaintext::from_str(&format!("{}field", u64::rand(rng)))?),
+            Value::Plaintext(Plaintext::from_str(&format!("{}field", u64::rand(rng)))?),
+        ];
+        // Authorize the request.
+        sample_program().authorize(&private_key, Identifier::from_str("compute")?, inputs, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::sample_authorization, *};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Check a valid request is authorized.
+        let authorization = sample_authorization(rng)?;
+        let expected = authorization.request().clone();
+        assert_eq!(authorization, Authorization::try_from(expected.clone())?);
+
+        // Check a request with a modified input is not authorized.
+        let other = sample_authorization(rng)?.request().clone();
+        let (caller, network_id, program_id, function_name) =
+            (*expected.caller(), *expected.network_id(), *expected.program_id(), *expected.function_name());
+        let candidate = Request::<CurrentNetwork>::from((
+            caller,
+            network_id,
+            program_id,
+            function_name,
+            expected.input_ids().to_vec(),
+            other.inputs().to_vec(),
+            *expected.signature(),
+            *expected.tvk(),
+        ));
+        assert!(Authorization::try_from(candidate).is_err());
+        Ok(())
+    }
+}
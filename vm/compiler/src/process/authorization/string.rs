@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+// Note: Unlike the program types, an authorization is written as the hex encoding of its bytes.
+// An authorization is an opaque payload that a caller hands to a prover, and its request
+// (the input IDs, signature, and transition view key) has no human-readable syntax to reuse.
+// The hex string is the byte encoding, so it round-trips exactly, and JSON stores it as one string.
+
+impl<N: Network> FromStr for Authorization<N> {
+    type Err = Error;
+
+    /// Reads in an authorization from a hex string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_bytes_le(&hex::decode(s)?)?)
+    }
+}
+
+impl<N: Network> Display for Authorization<N> {
+    /// Writes the authorization as a hex string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.to_bytes_le().map_err(|_| fmt::Error)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::authorization::test_helpers::sample_authorization;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        // Sample a new authorization.
+        let expected = sample_authorization(&mut test_crypto_rng())?;
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!(expected, Authorization::from_str(&candidate)?);
+        assert!(Authorization::<CurrentNetwork>::from_str(&candidate[2..]).is_err());
+        assert!(Authorization::<CurrentNetwork>::from_str("authorization").is_err());
+        Ok(())
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod authorization;
+pub use authorization::*;
+
 mod stack;
 pub(crate) use stack::*;
 
@@ -190,6 +193,21 @@ impl<N: Network, A: circuit::Aleo<Network = N, BaseField = N::Field>> Process<N,
 
         Ok((response, transition))
     }
+
+    /// Executes a program function on the given authorization, which does not require the private key
+    /// of the caller. This allows an untrusted process to prove the transition on behalf of the caller.
+    #[inline]
+    pub fn execute_authorization<R: Rng + CryptoRng>(
+        &self,
+        authorization: &Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>)> {
+        // Execute the signed request.
+        let (response, transition) = self.execute(authorization.request(), rng)?;
+        // Ensure the transition executes the authorization.
+        ensure!(authorization.matches(&transition), "Transition does not match the authorization");
+        Ok((response, transition))
+    }
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N, BaseField = N::Field>> Process<N, A> {
@@ -384,4 +402,33 @@ function compute:
         assert_eq!(41700, CurrentAleo::num_constraints());
         assert_eq!(159187, CurrentAleo::num_gates());
     }
+
+    #[test]
+    fn test_process_execute_authorization() {
+        use authorization::test_helpers::{sample_authorization, sample_program};
+
+        // Initialize the RNG.
+        let rng = &mut test_crypto_rng();
+
+        // Authorize a request with the private key of the caller, and serialize the authorization.
+        let authorization = sample_authorization(rng).unwrap();
+        let authorization_string = authorization.to_string();
+
+        // Construct a separate process, which does not have the private key of the caller.
+        let process = Process::<CurrentNetwork, CurrentAleo>::new(sample_program()).unwrap();
+
+        // Execute the deserialized authorization.
+        let candidate = Authorization::from_str(&authorization_string).unwrap();
+        let (response, transition) = process.execute_authorization(&candidate, rng).unwrap();
+
+        // Check the transition is valid, and executes the authorization.
+        let request = authorization.request();
+        let (_, verifying_key) = process.circuit_key(request.program_id(), request.function_name()).unwrap();
+        assert!(transition.verify(&verifying_key));
+        assert!(authorization.matches(&transition));
+        assert!(!sample_authorization(rng).unwrap().matches(&transition));
+
+        // Check the response matches the evaluation of the request.
+        assert_eq!(process.evaluate(request).unwrap().outputs(), response.outputs());
+    }
 }
@@ -605,8 +605,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Stack<N, A> {
                 // }
             }
             Opcode::Hash(opcode) => {
-                // Ensure the opcode **is** a reserved opcode.
-                ensure!(Self::is_reserved_opcode(&Identifier::from_str(opcode)?), "'{opcode}' is not an opcode.");
+                // Ensure the root of the opcode (the first part, up to the first '.') **is** a reserved opcode.
+                let root = opcode.split('.').next().unwrap_or_default();
+                ensure!(Self::is_reserved_opcode(&Identifier::from_str(root)?), "'{opcode}' is not an opcode.");
                 // Ensure the instruction belongs to the defined set.
                 if ![
                     "hash.bhp256",
@@ -618,6 +619,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Stack<N, A> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.sha256",
+                    "hash.keccak256",
                 ]
                 .contains(&opcode)
                 {
@@ -631,6 +634,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Stack<N, A> {
                 //     ),
                 // }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the signature verification operation.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not the opcode 'sign.verify'."
+                );
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
         }
         Ok(())
     }
@@ -112,8 +112,19 @@ impl<N: Network> RegisterTypes<N> {
             }
         }
 
+        // Ensure the operands length matches the record entries, with an optional trailing memo.
+        let num_entries = record_type.entries().len();
+        ensure!(
+            operands.len() == 2 + num_entries || operands.len() == 3 + num_entries,
+            "Record '{}' expects {} or {} operands, found {} operands",
+            record_type.name(),
+            2 + num_entries,
+            3 + num_entries,
+            operands.len()
+        );
+
         // Ensure the operand types match the record entry types.
-        for (operand, (_, entry_type)) in operands.iter().skip(2).zip(record_type.entries()) {
+        for (operand, (_, entry_type)) in operands[2..2 + num_entries].iter().zip(record_type.entries()) {
             match entry_type {
                 EntryType::Constant(plaintext_type)
                 | EntryType::Public(plaintext_type)
@@ -147,6 +158,14 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
         }
+
+        // Ensure the memo, if it exists, is not a record.
+        if let Some(Operand::Register(register)) = operands.get(2 + num_entries) {
+            ensure!(
+                !matches!(self.get_type(program, register)?, RegisterType::Record(..)),
+                "Casting a record into a record memo is illegal"
+            );
+        }
         Ok(())
     }
 }
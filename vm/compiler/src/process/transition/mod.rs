@@ -178,6 +178,26 @@ impl<N: Network> Transition<N> {
         Ok(Self { program_id, function_name, inputs, outputs, proof, tpk, fee })
     }
 
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the IDs of the transition inputs.
+    pub fn input_ids(&self) -> Vec<Field<N>> {
+        self.inputs.iter().map(Input::id).collect()
+    }
+
+    /// Returns the transition public key.
+    pub const fn tpk(&self) -> &Group<N> {
+        &self.tpk
+    }
+
     /// Returns `true` if the transition is valid.
     pub fn verify(&self, verifying_key: &VerifyingKey<N>) -> bool {
         // Ensure each input is valid.
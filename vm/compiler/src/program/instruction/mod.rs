@@ -113,6 +113,10 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a SHA-256 hash on the input.
+    HashSHA256(HashSHA256<N>),
+    /// Performs a Keccak-256 hash on the input.
+    HashKeccak256(HashKeccak256<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -149,6 +153,8 @@ pub enum Instruction<N: Network> {
     Shr(Shr<N>),
     /// Shifts `first` right by `second` bits, continuing past the boundary of the type, storing the outcome in `destination`.
     ShrWrapped(ShrWrapped<N>),
+    /// Verifies the signature `first` from the address `second` on the message `third`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
@@ -219,6 +225,8 @@ macro_rules! instruction {
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashSHA256,
+            HashKeccak256,
             Inv,
             IsEqual,
             IsNotEqual,
@@ -237,6 +245,7 @@ macro_rules! instruction {
             ShlWrapped,
             Shr,
             ShrWrapped,
+            SignVerify,
             Square,
             SquareRoot,
             Sub,
@@ -383,7 +392,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            47,
+            50,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
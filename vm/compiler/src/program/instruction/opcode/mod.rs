@@ -29,6 +29,8 @@ pub enum Opcode {
     Commit(&'static str),
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
+    /// The opcode is for a signature operation (i.e. `sign.verify`).
+    Sign,
 }
 
 impl Deref for Opcode {
@@ -42,6 +44,7 @@ impl Deref for Opcode {
             Opcode::Cast => &"cast",
             Opcode::Commit(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
+            Opcode::Sign => &"sign.verify",
         }
     }
 }
@@ -63,6 +66,7 @@ impl Display for Opcode {
             Self::Cast => write!(f, "{}", self.deref()),
             Self::Commit(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
+            Self::Sign => write!(f, "{}", self.deref()),
         }
     }
 }
@@ -151,9 +151,21 @@ impl<N: Network> Cast<N> {
                     _ => bail!("Invalid record balance"),
                 };
 
+                // Ensure the operands length matches the record entries, with an optional trailing memo.
+                let num_entries = record_type.entries().len();
+                ensure!(
+                    inputs.len() == 2 + num_entries || inputs.len() == 3 + num_entries,
+                    "Casting to record '{record_name}' requires {} or {} operands",
+                    2 + num_entries,
+                    3 + num_entries
+                );
+
+                // Split the remaining operands into the entries and the optional memo.
+                let (entry_inputs, memo_input) = inputs[2..].split_at(num_entries);
+
                 // Initialize the record entries.
                 let mut entries = IndexMap::new();
-                for (entry, (entry_name, entry_type)) in inputs.iter().skip(2).zip_eq(record_type.entries()) {
+                for (entry, (entry_name, entry_type)) in entry_inputs.iter().zip_eq(record_type.entries()) {
                     // Compute the register type.
                     let register_type = RegisterType::from(ValueType::from(*entry_type));
                     // Retrieve the plaintext value from the entry.
@@ -176,7 +188,13 @@ impl<N: Network> Cast<N> {
                 }
 
                 // Construct the record.
-                let record = Record::from_plaintext(owner, balance, entries)?;
+                let mut record = Record::from_plaintext(owner, balance, entries)?;
+                // Attach the memo, if it exists.
+                match memo_input.first() {
+                    Some(Value::Plaintext(memo)) => record = record.with_memo(memo.clone())?,
+                    Some(Value::Record(..)) => bail!("Casting a record into a record memo is illegal"),
+                    None => (),
+                }
                 // Store the record.
                 stack.store(&self.destination, Value::Record(record))
             }
@@ -186,7 +204,7 @@ impl<N: Network> Cast<N> {
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(&self, stack: &mut Stack<N, A>) -> Result<()> {
-        use circuit::{Eject, Inject, ToBits};
+        use circuit::{Eject, Inject, ToBits, ToFields};
 
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| stack.load_circuit(operand)).try_collect()?;
@@ -274,9 +292,21 @@ impl<N: Network> Cast<N> {
                     _ => bail!("Invalid record balance"),
                 };
 
+                // Ensure the operands length matches the record entries, with an optional trailing memo.
+                let num_entries = record_type.entries().len();
+                ensure!(
+                    inputs.len() == 2 + num_entries || inputs.len() == 3 + num_entries,
+                    "Casting to record '{record_name}' requires {} or {} operands",
+                    2 + num_entries,
+                    3 + num_entries
+                );
+
+                // Split the remaining operands into the entries and the optional memo.
+                let (entry_inputs, memo_input) = inputs[2..].split_at(num_entries);
+
                 // Initialize the record entries.
                 let mut entries = IndexMap::new();
-                for (entry, (entry_name, entry_type)) in inputs.iter().skip(2).zip_eq(record_type.entries()) {
+                for (entry, (entry_name, entry_type)) in entry_inputs.iter().zip_eq(record_type.entries()) {
                     // Compute the register type.
                     let register_type = RegisterType::from(ValueType::from(*entry_type));
                     // Retrieve the plaintext value from the entry.
@@ -304,7 +334,24 @@ impl<N: Network> Cast<N> {
                 }
 
                 // Construct the record.
-                let record = circuit::program::Record::from_plaintext(owner, balance, entries)?;
+                let mut record = circuit::program::Record::from_plaintext(owner, balance, entries)?;
+                // Attach the memo, if it exists.
+                match memo_input.first() {
+                    Some(circuit::Value::Plaintext(memo)) => match memo.eject_mode() == circuit::Mode::Private {
+                        true => record = record.with_memo(memo.clone()),
+                        // If the memo is a constant or public, inject it as a private memo, as the memo
+                        // is always encrypted to the record owner, and ensure it matches the given memo.
+                        false => {
+                            let private_memo = circuit::Plaintext::new(circuit::Mode::Private, memo.eject_value());
+                            for (candidate, expected) in private_memo.to_fields().iter().zip_eq(&memo.to_fields()) {
+                                A::assert_eq(candidate, expected);
+                            }
+                            record = record.with_memo(private_memo)
+                        }
+                    },
+                    Some(circuit::Value::Record(..)) => bail!("Casting a record into a record memo is illegal"),
+                    None => (),
+                }
                 // Store the record.
                 stack.store_circuit(&self.destination, circuit::Value::Record(record))
             }
@@ -363,8 +410,20 @@ impl<N: Network> Cast<N> {
                     "Casting to a record requires the second operand to be a u64"
                 );
 
+                // Ensure the input types length matches the record entries, with an optional trailing memo.
+                let num_entries = record.entries().len();
+                ensure!(
+                    input_types.len() == 2 + num_entries || input_types.len() == 3 + num_entries,
+                    "Casting to record '{record_name}' requires {} or {} operands",
+                    2 + num_entries,
+                    3 + num_entries
+                );
+
+                // Split the remaining input types into the entries and the optional memo.
+                let (entry_types, memo_type) = input_types[2..].split_at(num_entries);
+
                 // Ensure the input types match the record.
-                for (input_type, (_, entry_type)) in input_types.iter().skip(2).zip_eq(record.entries()) {
+                for (input_type, (_, entry_type)) in entry_types.iter().zip_eq(record.entries()) {
                     match input_type {
                         // Ensure the plaintext type matches the entry type.
                         RegisterType::Plaintext(plaintext_type) => match entry_type {
@@ -383,6 +442,11 @@ impl<N: Network> Cast<N> {
                         ),
                     }
                 }
+
+                // Ensure the memo type, if it exists, is not a record.
+                if let Some(RegisterType::Record(memo_name)) = memo_type.first() {
+                    bail!("Record '{record_name}' memo type mismatch: expected a plaintext, found record '{memo_name}'")
+                }
             }
         }
 
@@ -558,4 +622,51 @@ mod tests {
             "The value type is incorrect"
         );
     }
+
+    #[test]
+    fn test_execute_with_memo() -> Result<()> {
+        use circuit::{Eject, Environment};
+
+        for mode in ["constant", "public", "private"] {
+            // Initialize a new program, with a memo operand in the given mode.
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program token_with_memo;
+
+record token:
+    owner as address.private;
+    balance as u64.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    input r1 as u32.{mode};
+    cast r0.owner r0.balance r0.token_amount r1 into r2 as token.record;
+    output r2 as token.record;"
+            ))?;
+
+            // Declare the function name.
+            let function_name = Identifier::from_str("compute")?;
+            // Declare the input values.
+            let r0 = Value::<CurrentNetwork>::Record(Record::from_str(
+                "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private, token_amount: 100u64.private }",
+            )?);
+            let r1 = Value::<CurrentNetwork>::from_str("7u32")?;
+
+            // Prepare the stack.
+            let mut stack = Stack::<CurrentNetwork, CurrentAleo>::new(program)?;
+
+            // Execute the function.
+            let candidate = stack.test_execute(&function_name, &[r0, r1])?;
+            assert_eq!(1, candidate.len());
+            // Ensure the memo is private, and matches the given memo.
+            assert_eq!(circuit::Mode::Private, candidate[0].eject_mode());
+            match candidate[0].eject_value() {
+                Value::Record(record) => assert_eq!(Some(&Plaintext::from_str("7u32")?), record.memo()),
+                _ => bail!("Expected a record"),
+            }
+            assert!(CurrentAleo::is_satisfied());
+        }
+        Ok(())
+    }
 }
@@ -18,6 +18,7 @@ use crate::{Opcode, Operand, Program, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U128,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -41,6 +42,11 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// SHA-256 is the SHA-2 hash function over the bytes of a literal, which outputs its 256-bit digest as two `u128`s.
+pub type HashSHA256<N> = HashInstruction<N, { Hasher::SHA256 as u8 }>;
+/// Keccak-256 is the Keccak hash function over the bytes of a literal, which outputs its 256-bit digest as two `u128`s.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +57,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    SHA256,
+    Keccak256,
 }
 
 /// Hashes the operand into the declared type.
@@ -58,8 +66,8 @@ enum Hasher {
 pub struct HashInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
     operands: Vec<Operand<N>>,
-    /// The destination register.
-    destination: Register<N>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -76,6 +84,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.sha256"),
+            10 => Opcode::Hash("hash.keccak256"),
             _ => panic!("Invalid hash instruction opcode"),
         }
     }
@@ -89,10 +99,39 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         &self.operands
     }
 
-    /// Returns the destination register.
+    /// Returns the destination registers.
     #[inline]
     pub fn destinations(&self) -> Vec<Register<N>> {
-        vec![self.destination.clone()]
+        self.destinations.clone()
+    }
+
+    /// Returns the number of destination registers.
+    #[inline]
+    const fn num_destinations() -> usize {
+        match VARIANT {
+            // The 256-bit digests of SHA-256 and Keccak-256 are output as two `u128`s.
+            9 | 10 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns `true` if the given literal type is encoded in whole bytes, as required by SHA-256 and Keccak-256.
+    #[inline]
+    fn is_byte_aligned(literal_type: &LiteralType) -> bool {
+        matches!(
+            literal_type,
+            LiteralType::I8
+                | LiteralType::I16
+                | LiteralType::I32
+                | LiteralType::I64
+                | LiteralType::I128
+                | LiteralType::U8
+                | LiteralType::U16
+                | LiteralType::U32
+                | LiteralType::U64
+                | LiteralType::U128
+                | LiteralType::String
+        )
     }
 }
 
@@ -107,22 +146,25 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = stack.load(&self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+        let outputs = match VARIANT {
+            0 => vec![Literal::Field(N::hash_bhp256(&input.to_bits_le())?)],
+            1 => vec![Literal::Field(N::hash_bhp512(&input.to_bits_le())?)],
+            2 => vec![Literal::Field(N::hash_bhp768(&input.to_bits_le())?)],
+            3 => vec![Literal::Field(N::hash_bhp1024(&input.to_bits_le())?)],
+            4 => vec![Literal::Field(N::hash_ped64(&input.to_bits_le())?)],
+            5 => vec![Literal::Field(N::hash_ped128(&input.to_bits_le())?)],
+            6 => vec![Literal::Field(N::hash_psd2(&input.to_fields()?)?)],
+            7 => vec![Literal::Field(N::hash_psd4(&input.to_fields()?)?)],
+            8 => vec![Literal::Field(N::hash_psd8(&input.to_fields()?)?)],
+            9 => Self::digest_to_u128s(&N::hash_sha256(&Self::literal_to_bits_le(&input)?)?)?,
+            10 => Self::digest_to_u128s(&N::hash_keccak256(&Self::literal_to_bits_le(&input)?)?)?,
             _ => bail!("Invalid hash variant: {VARIANT}"),
         };
-        // Convert the output to a stack value.
-        let output = Value::Plaintext(Plaintext::Literal(Literal::Field(output), Default::default()));
-        // Store the output.
-        stack.store(&self.destination, output)
+        // Store the outputs.
+        for (destination, output) in self.destinations.iter().zip_eq(outputs) {
+            stack.store(destination, Value::Plaintext(Plaintext::Literal(output, Default::default())))?;
+        }
+        Ok(())
     }
 
     /// Executes the instruction.
@@ -137,23 +179,26 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = stack.load_circuit(&self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+        let outputs = match VARIANT {
+            0 => vec![circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le()))],
+            1 => vec![circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le()))],
+            2 => vec![circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le()))],
+            3 => vec![circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le()))],
+            4 => vec![circuit::Literal::Field(A::hash_ped64(&input.to_bits_le()))],
+            5 => vec![circuit::Literal::Field(A::hash_ped128(&input.to_bits_le()))],
+            6 => vec![circuit::Literal::Field(A::hash_psd2(&input.to_fields()))],
+            7 => vec![circuit::Literal::Field(A::hash_psd4(&input.to_fields()))],
+            8 => vec![circuit::Literal::Field(A::hash_psd8(&input.to_fields()))],
+            9 => Self::digest_to_u128s_circuit(&A::hash_sha256(&Self::literal_to_bits_le_circuit(&input)?)),
+            10 => Self::digest_to_u128s_circuit(&A::hash_keccak256(&Self::literal_to_bits_le_circuit(&input)?)),
             _ => bail!("Invalid hash variant: {VARIANT}"),
         };
-        // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
-        // Store the output.
-        stack.store_circuit(&self.destination, output)
+        // Store the outputs.
+        for (destination, output) in self.destinations.iter().zip_eq(outputs) {
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+            stack.store_circuit(destination, output)?;
+        }
+        Ok(())
     }
 
     /// Returns the output type from the given program and input types.
@@ -172,11 +217,82 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
 
         match VARIANT {
             0 | 1 | 2 | 3 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
+            9 | 10 => match &input_types[0] {
+                // Ensure the input is a byte-aligned literal, as SHA-256 and Keccak-256 are defined over bytes.
+                RegisterType::Plaintext(PlaintextType::Literal(literal_type))
+                    if Self::is_byte_aligned(literal_type) =>
+                {
+                    Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U128)); 2])
+                }
+                input_type => {
+                    bail!("Instruction '{}' expects a byte-aligned literal, found '{input_type}'", Self::opcode())
+                }
+            },
             _ => bail!("Invalid hash variant: {VARIANT}"),
         }
     }
 }
 
+impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
+    /// Returns the bytes of the given literal, as little-endian bits per byte.
+    fn literal_to_bits_le(input: &Value<N>) -> Result<Vec<bool>> {
+        match input {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) if Self::is_byte_aligned(&literal.to_type()) => {
+                Ok(literal.to_bits_le())
+            }
+            _ => bail!("Instruction '{}' expects a byte-aligned literal, found '{input}'", Self::opcode()),
+        }
+    }
+
+    /// Returns the bytes of the given circuit literal, as little-endian bits per byte.
+    fn literal_to_bits_le_circuit<A: circuit::Aleo<Network = N>>(
+        input: &circuit::Value<A>,
+    ) -> Result<Vec<circuit::Boolean<A>>> {
+        use circuit::ToBits;
+
+        match input {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..))
+                if Self::is_byte_aligned(&literal.to_type()) =>
+            {
+                Ok(literal.to_bits_le())
+            }
+            _ => bail!("Instruction '{}' expects a byte-aligned literal", Self::opcode()),
+        }
+    }
+
+    /// Returns the given 256-bit digest, in little-endian bits per byte, as two `u128`s.
+    /// Each `u128` is the big-endian integer of 16 bytes of the digest, so that the
+    /// hexadecimal digest is the concatenation of the two `u128`s in hexadecimal.
+    fn digest_to_u128s(digest: &[bool]) -> Result<Vec<Literal<N>>> {
+        ensure!(digest.len() == 256, "Expected a 256-bit digest, found {} bits", digest.len());
+        digest
+            .chunks(128)
+            .map(|half| {
+                // Reverse the byte order, to interpret the bytes as a big-endian integer.
+                let bits_le = half.chunks(8).rev().flatten().copied().collect::<Vec<_>>();
+                Ok(Literal::U128(U128::from_bits_le(&bits_le)?))
+            })
+            .collect()
+    }
+
+    /// Returns the given 256-bit circuit digest, in little-endian bits per byte, as two `u128`s.
+    /// Each `u128` is the big-endian integer of 16 bytes of the digest.
+    fn digest_to_u128s_circuit<A: circuit::Aleo<Network = N>>(
+        digest: &[circuit::Boolean<A>],
+    ) -> Vec<circuit::Literal<A>> {
+        use circuit::FromBits;
+
+        digest
+            .chunks(128)
+            .map(|half| {
+                // Reverse the byte order, to interpret the bytes as a big-endian integer.
+                let bits_le = half.chunks(8).rev().flatten().cloned().collect::<Vec<_>>();
+                circuit::Literal::U128(circuit::U128::from_bits_le(&bits_le))
+            })
+            .collect()
+    }
+}
+
 impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
@@ -191,12 +307,19 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
         let (string, _) = tag("into")(string)?;
-        // Parse the whitespace from the string.
-        let (string, _) = Sanitizer::parse_whitespaces(string)?;
-        // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        // Parse the destination registers from the string.
+        let mut string = string;
+        let mut destinations = Vec::with_capacity(Self::num_destinations());
+        for _ in 0..Self::num_destinations() {
+            // Parse the whitespace from the string.
+            let (remainder, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination register from the string.
+            let (remainder, destination) = Register::parse(remainder)?;
+            destinations.push(destination);
+            string = remainder;
+        }
 
-        Ok((string, Self { operands: vec![operand], destination }))
+        Ok((string, Self { operands: vec![operand], destinations }))
     }
 }
 
@@ -236,7 +359,8 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         write!(f, "{} ", self.operands[0])?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into")?;
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))
     }
 }
 
@@ -245,10 +369,11 @@ impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the operand.
         let operands = vec![Operand::read_le(&mut reader)?];
-        // Read the destination register.
-        let destination = Register::read_le(&mut reader)?;
+        // Read the destination registers.
+        let destinations =
+            (0..Self::num_destinations()).map(|_| Register::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destinations })
     }
 }
 
@@ -259,10 +384,18 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         if self.operands.len() != 1 {
             return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
         }
+        // Ensure the number of destinations is correct.
+        if self.destinations.len() != Self::num_destinations() {
+            return Err(error(format!(
+                "The number of destinations must be {}, found {}",
+                Self::num_destinations(),
+                self.destinations.len()
+            )));
+        }
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
-        // Write the destination register.
-        self.destination.write_le(&mut writer)
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
     }
 }
 
@@ -270,7 +403,7 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
 mod tests {
     use super::*;
     use circuit::network::AleoV0;
-    use console::network::Testnet3;
+    use console::{network::Testnet3, program::Identifier};
 
     type CurrentNetwork = Testnet3;
     type CurrentAleo = AleoV0;
@@ -281,6 +414,90 @@ mod tests {
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
-        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destinations, vec![Register::Locator(1)], "The destination register is incorrect");
+
+        let (string, hash) = HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r0 into r1 r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(
+            hash.destinations,
+            vec![Register::Locator(1), Register::Locator(2)],
+            "The destination registers are incorrect"
+        );
+
+        // Ensure the 256-bit digest requires two destination registers.
+        assert!(HashSHA256::<CurrentNetwork>::from_str("hash.sha256 r0 into r1").is_err());
+    }
+
+    /// Returns a program that hashes its input with SHA-256 and Keccak-256.
+    fn sample_program(input_type: &str) -> Result<Program<CurrentNetwork>> {
+        Program::from_str(&format!(
+            r"
+program digest;
+
+function compute:
+    input r0 as {input_type}.private;
+    hash.sha256 r0 into r1 r2;
+    hash.keccak256 r0 into r3 r4;
+    output r1 as u128.private;
+    output r2 as u128.private;
+    output r3 as u128.private;
+    output r4 as u128.private;"
+        ))
+    }
+
+    #[test]
+    fn test_sha256_and_keccak256() -> Result<()> {
+        use circuit::Eject;
+
+        // Prepare the stack.
+        let mut stack = Stack::<CurrentNetwork, CurrentAleo>::new(sample_program("string")?)?;
+        let function_name = Identifier::from_str("compute")?;
+
+        // Hash the string "abc".
+        let inputs = [Value::from_str("\"abc\"")?];
+        // SHA-256("abc") = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad
+        // Keccak-256("abc") = 4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45
+        let expected = [
+            0xba7816bf8f01cfea414140de5dae2223u128,
+            0xb00361a396177a9cb410ff61f20015adu128,
+            0x4e03657aea45a94fc7d47ba826c8d667u128,
+            0xc0d1e6e33a64a036ec44f58fa12d6c45u128,
+        ]
+        .map(|digest| Value::<CurrentNetwork>::from_str(&format!("{digest}u128")).unwrap());
+
+        // Ensure the console output matches the test vectors.
+        let candidate = stack.test_evaluate(&function_name, &inputs)?;
+        assert_eq!(expected.to_vec(), candidate);
+
+        // Ensure the circuit output matches the test vectors.
+        let candidate = stack.test_execute(&function_name, &inputs)?;
+        assert_eq!(expected.to_vec(), candidate.iter().map(|output| output.eject_value()).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_types() -> Result<()> {
+        let program = sample_program("u8")?;
+        let hash = HashSHA256::<CurrentNetwork>::from_str("hash.sha256 r0 into r1 r2")?;
+
+        // Ensure byte-aligned literals are hashed into two `u128`s.
+        for literal_type in [LiteralType::U8, LiteralType::I64, LiteralType::U128, LiteralType::String] {
+            let input_types = [RegisterType::Plaintext(PlaintextType::Literal(literal_type))];
+            let expected = vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U128)); 2];
+            assert_eq!(expected, hash.output_types(&program, &input_types)?);
+        }
+        // Ensure literals that are not encoded in whole bytes are rejected.
+        for literal_type in [LiteralType::Field, LiteralType::Group, LiteralType::Scalar, LiteralType::Boolean] {
+            let input_types = [RegisterType::Plaintext(PlaintextType::Literal(literal_type))];
+            assert!(hash.output_types(&program, &input_types).is_err());
+        }
+
+        // Ensure a program that hashes a field element is rejected.
+        let mut stack = Stack::<CurrentNetwork, CurrentAleo>::new(sample_program("field")?)?;
+        let function_name = Identifier::from_str("compute")?;
+        assert!(stack.test_evaluate(&function_name, &[Value::from_str("1field")?]).is_err());
+        Ok(())
     }
 }
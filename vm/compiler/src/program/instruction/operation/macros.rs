@@ -357,6 +357,11 @@ mod tests {
                 console::program::Literal::U64(U64::rand($rng)),
                 console::program::Literal::U128(U128::rand($rng)),
                 console::program::Literal::Scalar(Scalar::rand($rng)),
+                console::program::Literal::Signature(Box::new(console::account::Signature::from((
+                    Scalar::rand($rng),
+                    Scalar::rand($rng),
+                    console::account::ComputeKey::try_from((Group::rand($rng), Group::rand($rng))).unwrap(),
+                )))),
                 console::program::Literal::String(StringType::rand($rng)),
             ]
         };
@@ -29,6 +29,9 @@ pub use hash::*;
 mod literals;
 pub use literals::*;
 
+mod sign_verify;
+pub use sign_verify::*;
+
 mod macros;
 
 use crate::Opcode;
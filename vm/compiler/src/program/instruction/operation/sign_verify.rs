@@ -0,0 +1,363 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Program, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Verifies the signature `first` from the address `second` on the message `third`,
+/// storing the outcome as a boolean in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands as `(signature, address, message)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Signature verification must have three operands");
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, stack: &mut Stack<N, A>) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| stack.load(operand)).try_collect()?;
+
+        // Retrieve the signature.
+        let signature = match &inputs[0] {
+            Value::Plaintext(Plaintext::Literal(Literal::Signature(signature), ..)) => signature,
+            _ => bail!("Expected the first operand of '{}' to be a signature.", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match &inputs[1] {
+            Value::Plaintext(Plaintext::Literal(Literal::Address(address), ..)) => address,
+            _ => bail!("Expected the second operand of '{}' to be an address.", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = inputs[2].to_fields()?;
+
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(signature.verify(address, &message)));
+        // Store the output.
+        stack.store(&self.destination, Value::Plaintext(Plaintext::Literal(output, Default::default())))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(&self, stack: &mut Stack<N, A>) -> Result<()> {
+        use circuit::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| stack.load_circuit(operand)).try_collect()?;
+
+        // Retrieve the signature.
+        let signature = match &inputs[0] {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Signature(signature), ..)) => {
+                signature
+            }
+            _ => bail!("Expected the first operand of '{}' to be a signature.", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match &inputs[1] {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), ..)) => address,
+            _ => bail!("Expected the second operand of '{}' to be an address.", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = inputs[2].to_fields();
+
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(signature.verify(address, &message));
+        // Store the output.
+        stack.store_circuit(
+            &self.destination,
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default())),
+        )
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _program: &Program<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input type is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!("Expected the first input type of '{}' to be a signature, found '{}'", Self::opcode(), input_types[0])
+        }
+        // Ensure the second input type is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!("Expected the second input type of '{}' to be an address, found '{}'", Self::opcode(), input_types[1])
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = (0..3).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::{
+        account::{Address, PrivateKey, Signature},
+        network::Testnet3,
+        program::Identifier,
+        types::Field,
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns the given literal as a plaintext value.
+    fn to_value(literal: Literal<CurrentNetwork>) -> Value<CurrentNetwork> {
+        Value::Plaintext(Plaintext::Literal(literal, Default::default()))
+    }
+
+    /// Returns a program that verifies a signature on a field element.
+    fn sample_program() -> Program<CurrentNetwork> {
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program signature;
+
+function verify:
+    input r0 as signature.private;
+    input r1 as address.private;
+    input r2 as field.private;
+    sign.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        program
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, sign) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sign.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(sign.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(sign.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(sign.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(sign.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3")?;
+        let candidate = SignVerify::read_le(&expected.to_bytes_le()?[..])?;
+        assert_eq!(expected, candidate);
+        assert_eq!("sign.verify r0 r1 r2 into r3", candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_and_execute() -> Result<()> {
+        use circuit::Eject;
+
+        let rng = &mut test_crypto_rng();
+
+        // Sample the signer and the message.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message = to_value(Literal::Field(Field::rand(rng)));
+        let signature = Signature::sign(&private_key, &message.to_fields()?, rng)?;
+
+        // Sample a different message and a different address.
+        let wrong_message = to_value(Literal::Field(Field::rand(rng)));
+        let wrong_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        let signature = to_value(Literal::Signature(Box::new(signature)));
+        let address = to_value(Literal::Address(address));
+        let wrong_address = to_value(Literal::Address(wrong_address));
+
+        // Prepare the stack.
+        let function_name = Identifier::from_str("verify")?;
+        let mut stack = Stack::<CurrentNetwork, CurrentAleo>::new(sample_program())?;
+
+        for (inputs, expected) in [
+            ([signature.clone(), address.clone(), message.clone()], true),
+            ([signature.clone(), address, wrong_message], false),
+            ([signature, wrong_address, message], false),
+        ] {
+            let expected = to_value(Literal::Boolean(Boolean::new(expected)));
+
+            // Ensure the console output is correct.
+            let candidate = stack.test_evaluate(&function_name, &inputs)?;
+            assert_eq!(1, candidate.len());
+            assert_eq!(expected, candidate[0]);
+
+            // Ensure the circuit output matches the console output.
+            let candidate = stack.test_execute(&function_name, &inputs)?;
+            assert_eq!(1, candidate.len());
+            assert_eq!(expected, candidate[0].eject_value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_types() -> Result<()> {
+        let program = sample_program();
+        let sign = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3")?;
+
+        let signature = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature));
+        let address = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+        let field = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field));
+
+        // Ensure a signature, address, and message are accepted.
+        let output_types = sign.output_types(&program, &[signature, address.clone(), field.clone()])?;
+        assert_eq!(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))], output_types);
+
+        // Ensure a non-signature operand is rejected.
+        assert!(sign.output_types(&program, &[field.clone(), address, field]).is_err());
+        Ok(())
+    }
+}
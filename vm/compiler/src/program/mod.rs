@@ -31,6 +31,7 @@ mod matches;
 mod parse;
 mod sample;
 
+use crate::Authorization;
 use console::{
     account::PrivateKey,
     network::prelude::*,
@@ -117,6 +118,19 @@ impl<N: Network> Program<N> {
         Request::sign(private_key, self.id, function_name, inputs, &function.input_types(), rng)
     }
 
+    /// Authorizes a request to execute a program function, which may be proven by another process.
+    #[inline]
+    pub fn authorize<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Compute the signed request, and authorize it.
+        Authorization::try_from(self.sign(private_key, function_name, inputs, rng)?)
+    }
+
     /// Returns the ID of the program.
     pub const fn id(&self) -> &ProgramID<N> {
         &self.id
@@ -828,4 +842,42 @@ function compute:
         assert_eq!(1, candidate.len());
         assert_eq!(expected, candidate[0]);
     }
+
+    #[test]
+    fn test_program_evaluate_cast_with_memo() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program token_with_memo;
+
+record token:
+    owner as address.private;
+    balance as u64.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    input r1 as u32.private;
+    cast r0.owner r0.balance r0.token_amount r1 into r2 as token.record;
+    output r2 as token.record;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::Record(Record::from_str("{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private, token_amount: 100u64.private }").unwrap());
+        let r1 = Value::<CurrentNetwork>::from_str("7u32").unwrap();
+        // Declare the expected output value.
+        let expected = Value::Record(Record::from_str("{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, balance: 5u64.private, token_amount: 100u64.private } memo 7u32").unwrap());
+
+        // Prepare the stack.
+        let mut stack = Stack::<CurrentNetwork, CurrentAleo>::new(program).unwrap();
+
+        // Compute the output value.
+        let candidate = stack.test_evaluate(&function_name, &[r0, r1]).unwrap();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
+    }
 }
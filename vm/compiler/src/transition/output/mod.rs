@@ -23,14 +23,16 @@ use console::{
 pub struct Output<N: Network> {
     /// The output record.
     record: Record<N>,
+    /// The tag of the output record (i.e. `tag := Hash(r * pk_tag)`).
+    tag: Field<N>,
     // /// The output program data.
     // data: Vec<Data<N>>,
 }
 
 impl<N: Network> Output<N> {
     /// Initializes a new `Output` for a transition.
-    pub const fn new(record: Record<N>) -> Self {
-        Self { record }
+    pub const fn new(record: Record<N>, tag: Field<N>) -> Self {
+        Self { record, tag }
     }
 
     /// Returns the output record.
@@ -38,6 +40,11 @@ impl<N: Network> Output<N> {
         &self.record
     }
 
+    /// Returns the tag of the output record.
+    pub const fn tag(&self) -> Field<N> {
+        self.tag
+    }
+
     /// Returns the balance commitment for the output record.
     pub const fn bcm(&self) -> Group<N> {
         self.record.bcm()
@@ -61,6 +68,7 @@ pub mod circuit {
         Inject,
         Mode,
         Scalar,
+        Signature,
         State,
         ToBits,
         ToField,
@@ -77,6 +85,8 @@ pub mod circuit {
         index: U16<A>,
         /// The output record.
         record: Record<A>,
+        /// The output record tag (i.e. `tag := Hash(r * pk_tag)`).
+        tag: Field<A>,
         /// The fee commitment (i.e. `fcm := Σ bcm_in - Σ bcm_out - Commit(fee, 0) = Commit(0, r_fcm)`).
         fcm: Group<A>,
         /// The transition view key commitment (i.e. `tcm := Hash(caller, tpk, tvk)`).
@@ -90,6 +100,7 @@ pub mod circuit {
         pub fn from(
             index: u16,
             record: console::transition::Record<A::Network>,
+            tag: console::types::Field<A::Network>,
             fcm: console::types::Group<A::Network>,
             tcm: console::types::Field<A::Network>,
             tpk: console::types::Group<A::Network>,
@@ -97,6 +108,7 @@ pub mod circuit {
             Self {
                 index: U16::new(Mode::Public, console::types::U16::new(index)),
                 record: Record::new(Mode::Public, record),
+                tag: Field::new(Mode::Public, tag),
                 fcm: Group::new(Mode::Public, fcm),
                 tcm: Field::new(Mode::Public, tcm),
                 tpk: Group::new(Mode::Public, tpk),
@@ -109,6 +121,10 @@ pub mod circuit {
         caller: Address<A>,
         /// The output state.
         state: State<A>,
+        /// The tag public key of the output record owner.
+        pk_tag: Group<A>,
+        /// The signature of the output record owner on its tag public key.
+        pk_tag_signature: Signature<A>,
         /// The fee randomizer (i.e. `r_fcm := Σ r_in - Σ r_out`).
         r_fcm: Scalar<A>,
         /// The transition view key commitment randomizer.
@@ -120,12 +136,16 @@ pub mod circuit {
         pub fn from(
             caller: console::account::Address<A::Network>,
             state: console::transition::State<A::Network>,
+            pk_tag: console::types::Group<A::Network>,
+            pk_tag_signature: console::account::Signature<A::Network>,
             r_fcm: console::types::Scalar<A::Network>,
             r_tcm: console::types::Field<A::Network>,
         ) -> Self {
             Self {
                 caller: Address::new(Mode::Private, caller),
                 state: State::new(Mode::Private, state),
+                pk_tag: Group::new(Mode::Private, pk_tag),
+                pk_tag_signature: Signature::new(Mode::Private, pk_tag_signature),
                 r_fcm: Scalar::new(Mode::Private, r_fcm),
                 r_tcm: Field::new(Mode::Private, r_tcm),
             }
@@ -138,17 +158,20 @@ pub mod circuit {
         /// Initializes the output circuit.
         pub fn from(public: Public<A>, private: Private<A>) -> Result<Self> {
             // Ensure all public members are public inputs.
-            let Public { index, record, fcm, tcm, tpk } = &public;
+            let Public { index, record, tag, fcm, tcm, tpk } = &public;
             ensure!(index.eject_mode().is_public(), "Output index must be public");
             ensure!(record.eject_mode().is_public(), "Output record must be public");
+            ensure!(tag.eject_mode().is_public(), "Output tag must be public");
             ensure!(fcm.eject_mode().is_public(), "Fee commitment must be public");
             ensure!(tcm.eject_mode().is_public(), "Transition view key commitment must be public");
             ensure!(tpk.eject_mode().is_public(), "Transition public key must be public");
 
             // Ensure all private members are private inputs.
-            let Private { caller, state, r_fcm, r_tcm } = &private;
+            let Private { caller, state, pk_tag, pk_tag_signature, r_fcm, r_tcm } = &private;
             ensure!(caller.eject_mode().is_private(), "Caller address must be private");
             ensure!(state.eject_mode().is_private(), "Output state must be private");
+            ensure!(pk_tag.eject_mode().is_private(), "Output tag public key must be private");
+            ensure!(pk_tag_signature.eject_mode().is_private(), "Output tag public key signature must be private");
             ensure!(r_fcm.eject_mode().is_private(), "Fee randomizer must be private");
             ensure!(r_tcm.eject_mode().is_private(), "Transition view key commitment randomizer must be private");
 
@@ -180,9 +203,151 @@ pub mod circuit {
             A::assert(public.record.is_equal(&record));
             println!("Is satisfied? {} ({} constraints)", A::is_satisfied(), A::num_constraints());
 
+            // Ensure the tag public key belongs to the record owner, who signed it with their private key.
+            let message = [A::graph_key_domain(), private.pk_tag.to_x_coordinate()];
+            A::assert(private.pk_tag_signature.verify(private.state.owner(), &message));
+            println!("Is satisfied? {} ({} constraints)", A::is_satisfied(), A::num_constraints());
+
+            // Ensure the tag matches the declared tag (i.e. `tag := Hash(r * pk_tag)`).
+            let shared_point = &private.pk_tag * &randomizer;
+            A::assert_eq(&public.tag, A::hash_psd2(&[A::graph_key_domain(), shared_point.to_x_coordinate()]));
+            println!("Is satisfied? {} ({} constraints)", A::is_satisfied(), A::num_constraints());
+
             // Ensure the fee commitment is correct.
             A::assert_eq(&public.fcm, A::commit_ped64(&U64::zero().to_bits_le(), &private.r_fcm));
             println!("Is satisfied? {} ({} constraints)", A::is_satisfied(), A::num_constraints());
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        circuit::{OutputCircuit, Private, Public},
+        *,
+    };
+    use ::circuit::{network::AleoV0, Environment};
+    use console::{
+        account::{Address, GraphKey, PrivateKey, Signature},
+        network::Testnet3,
+        transition::State,
+        types::Scalar,
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns the output at the given index, for a record of the given balance owned by the given recipient
+    /// and tagged with the given signed tag public key, and whether the output circuit is satisfied when
+    /// the given tag is declared instead (if any).
+    fn sample_output(
+        caller: Address<CurrentNetwork>,
+        recipient: Address<CurrentNetwork>,
+        (pk_tag, pk_tag_signature): (Group<CurrentNetwork>, Signature<CurrentNetwork>),
+        index: u16,
+        balance: u64,
+        declared_tag: Option<Field<CurrentNetwork>>,
+    ) -> Result<(Output<CurrentNetwork>, bool)> {
+        let rng = &mut test_crypto_rng();
+
+        // Compute the transition view key commitment.
+        let r_tcm = Uniform::rand(rng);
+        let tsk = CurrentNetwork::hash_to_scalar_psd2(&[r_tcm])?;
+        let tpk = CurrentNetwork::g_scalar_multiply(&tsk);
+        let tvk = *caller * tsk;
+        let tcm = CurrentNetwork::hash_psd4(&[*caller, tpk, tvk].map(|c| c.to_x_coordinate()))?;
+
+        // Encrypt the state into a record, and compute its tag.
+        let randomizer = CurrentNetwork::hash_to_scalar_psd2(&[tvk.to_x_coordinate(), Field::from_u16(index)])?;
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let state = State::new(recipient, balance, Field::zero(), nonce);
+        let record = state.encrypt(&randomizer)?;
+        let tag = GraphKey::derive_output_tag(pk_tag, randomizer)?;
+
+        // Compute the fee commitment.
+        let record_view_key = (*recipient * randomizer).to_x_coordinate();
+        let r_bcm = CurrentNetwork::hash_to_scalar_psd2(&[CurrentNetwork::bcm_domain(), record_view_key])?;
+        let r_fcm = Scalar::zero() - r_bcm;
+        let fcm = CurrentNetwork::commit_ped64(&0u64.to_bits_le(), &r_fcm)?;
+
+        // Execute the output circuit.
+        let public = Public::<CurrentAleo>::from(index, record.clone(), declared_tag.unwrap_or(tag), fcm, tcm, tpk);
+        let private = Private::<CurrentAleo>::from(caller, state, pk_tag, pk_tag_signature, r_fcm, r_tcm);
+        OutputCircuit::from(public, private)?.execute();
+        let is_satisfied = CurrentAleo::is_satisfied();
+        CurrentAleo::reset();
+
+        Ok((Output::new(record, tag), is_satisfied))
+    }
+
+    #[test]
+    fn test_scan_outputs_with_graph_key() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the caller and the recipient.
+        let caller = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let graph_key = GraphKey::try_from(&private_key)?;
+        let signed_pk_tag = GraphKey::sign_pk_tag(&private_key, rng)?;
+
+        // Sample another account.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let other_address = Address::try_from(&other_private_key)?;
+        let other_signed_pk_tag = GraphKey::sign_pk_tag(&other_private_key, rng)?;
+
+        // Construct the outputs of a transition, where only the second output belongs to the recipient.
+        let mut outputs = Vec::new();
+        for (index, (owner, signed_pk_tag)) in
+            [(other_address, other_signed_pk_tag), (address, signed_pk_tag), (other_address, other_signed_pk_tag)]
+                .into_iter()
+                .enumerate()
+        {
+            let (output, is_satisfied) = sample_output(caller, owner, signed_pk_tag, index as u16, 100, None)?;
+            assert!(is_satisfied);
+            outputs.push(output);
+        }
+
+        // Scan the public outputs with only the graph key.
+        let owned: Vec<_> = outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, output)| graph_key.is_output_tag_owner(output.record().nonce(), output.tag()))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(vec![1], owned);
+
+        // Ensure the output circuit rejects a tag that does not match the tag public key.
+        let (_, is_satisfied) = sample_output(caller, address, signed_pk_tag, 0, 100, Some(Uniform::rand(rng)))?;
+        assert!(!is_satisfied);
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_tag_is_bound_to_owner() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the caller and the recipient.
+        let caller = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let (pk_tag, pk_tag_signature) = GraphKey::sign_pk_tag(&private_key, rng)?;
+
+        // Sample another account, whose graph key would recognize records tagged with its tag public key.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let (other_pk_tag, other_pk_tag_signature) = GraphKey::sign_pk_tag(&other_private_key, rng)?;
+
+        // Ensure the output circuit rejects the signed tag public key of another account for the same owner.
+        let (_, is_satisfied) = sample_output(caller, address, (other_pk_tag, other_pk_tag_signature), 0, 100, None)?;
+        assert!(!is_satisfied);
+
+        // Ensure the output circuit rejects the tag public key of another account with the signature of the owner.
+        let (_, is_satisfied) = sample_output(caller, address, (other_pk_tag, pk_tag_signature), 0, 100, None)?;
+        assert!(!is_satisfied);
+
+        // Ensure the output circuit accepts the signed tag public key of the owner.
+        let (_, is_satisfied) = sample_output(caller, address, (pk_tag, pk_tag_signature), 0, 100, None)?;
+        assert!(is_satisfied);
+        Ok(())
+    }
+}